@@ -1,42 +1,313 @@
 // SPDX-License-Identifier: MIT
 // Compatible with OpenZeppelin Stellar Soroban Contracts ^0.3.0
 
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, String, Vec};
-use stellar_fungible::Base;
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal, Symbol, String, Val, Vec};
+use stellar_fungible::{emit_transfer, Base};
 use stellar_access_control as access_control;
 use stellar_pausable as pausable;
 
 // Import our modular components
-use crate::types::{StablecoinError, MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE, MINT_EVENT, BURN_EVENT, TRANSFER_EVENT, PAUSE_EVENT, UNPAUSE_EVENT};
+use crate::types::{
+    BalanceLock, EffectiveLimits, DECIMALS, NAME, SYMBOL, MintLogEntry, StablecoinError, MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE, COMPLIANCE_ROLE, MINT_EVENT, BURN_EVENT,
+    AccountCompliance, Escrow, FeeConfig, FeeDisposition, GenesisBacking, InitReport, LedgerFlow, SettlementConfig, StablecoinConfig, SystemAddresses, TokenStats, TtlMaintenance,
+    ENABLE_SUPPLY_LIMITS, ENABLE_OPERATION_LIMITS, ENABLE_BACKING, SUPPORTS_MUXED_ADDRESSES, STORAGE_VERSION, MAX_DECIMALS,
+    TRANSFER_EVENT, PAUSE_EVENT, UNPAUSE_EVENT, FREEZE_EVENT, UNFREEZE_EVENT, LOCK_EVENT,
+    DENYLIST_ADD_EVENT, DENYLIST_REMOVE_EVENT, ALLOWLIST_ADD_EVENT, ALLOWLIST_REMOVE_EVENT, RECOVERY_INITIATED_EVENT, RECOVERY_CANCELLED_EVENT,
+    RECOVERY_CLAIMED_EVENT, EMERGENCY_FREEZE_KIND, MINTER_ROTATION_KIND, RECOVERY_CLAIM_KIND, BURN_WITH_MEMO_EVENT, TRANSFER_WITH_MEMO_EVENT, CLAWBACK_EVENT, MINTER_ROTATED_EVENT,
+    ESCROW_DEPOSIT_EVENT, ESCROW_RELEASE_EVENT, ESCROW_REFUND_EVENT,
+    OpSummary, ScheduledAction, BalanceAlert, ADMIN_TRANSFER_STARTED_EVENT, ADMIN_TRANSFERRED_EVENT, TOKEN_RECEIVED_HOOK, ACTION_SCHEDULED_EVENT, ACTION_EXECUTED_EVENT, ACTION_CANCELLED_EVENT, BatchSummary, BATCH_MINTED_EVENT, BATCH_TRANSFERRED_EVENT, DepositMinted, DEPOSIT_MINTED_EVENT,
+    DuplicateSuppressed, DUPLICATE_SUPPRESSED_EVENT, AdminApproval, ADMIN_APPROVAL_EVENT, AccountRecovered, ACCOUNT_RECOVERED_EVENT, SPENDER_CONSENT_EVENT, RENT_RESERVE_WITHDRAWN_EVENT,
+};
+use crate::events;
+use crate::extensions::{pause_utils, PauseStatus};
+use crate::storage;
 use crate::utils::{
     initialize_token, 
     initialize_access_control,
+    validate_init_params,
     validate_mint_comprehensive,
     validate_transfer_comprehensive,
     validate_burn_comprehensive,
+    validate_batch_size,
+    require_admin,
+    update_max_batch_size,
+    add_pause_subscriber,
+    remove_pause_subscriber,
+    notify_pause_subscribers,
+    require_compliance,
+    add_lock,
+    locked_balance,
+    spendable_balance,
+    record_mint,
+    read_mint_log,
+    live_allowances,
+    reindex_allowances,
+    total_approved,
+    is_denylisted,
+    denylist_add,
+    denylist_remove,
+    read_denylist,
+    require_self_or_compliance,
+    require_self_or_admin,
+    add_allowed_counterparty,
+    remove_allowed_counterparty,
+    ensure_method_not_paused,
+    ensure_transfer_not_paused,
+    claim_pause_action,
+    pause_method,
+    unpause_method,
+    configure_recovery,
+    add_guardian,
+    remove_guardian,
+    initiate_recovery,
+    claim_recovered_admin,
+    validate_spend,
+    parse_currency_code,
+    validate_memo,
+    validate_token_uri,
+    validate_pull,
+    validate_spender_compliance,
+    validate_role_symbol,
+    validate_role_grantee,
+    validate_role_grants,
+    freeze,
+    unfreeze,
+    note_credit,
+    clawback_frozen,
+    supply_breakdown,
+    set_supply_excluded,
+    circulating_supply,
+    revoke_flagged_spender,
+    record_operation,
+    max_transferable_from,
+    settle_redemption,
+    configure_flow_caps,
+    current_ledger_flow,
+    current_transfer_volume,
+    consume_mint_flow,
+    consume_burn_flow,
+    write_allowance,
+    write_managed_allowance,
+    consume_minter_allowance,
+    rotate_minter,
+    note_debit,
+    note_mint,
+    note_burn,
+    note_allowance_spent,
+    token_stats,
+    preview_batch_transfer,
+    configure_fees,
+    configure_fee_tiers,
+    quote_fee,
+    charge_transfer_fee,
+    open_escrow,
+    release_escrow,
+    refund_escrow,
+    reconcile_supply,
+    update_allowance_grace,
+    transfer_within_grace,
+    backfill_holder_index,
+    ensure_reads_available,
+    admit_mint,
+    update_max_supply,
+    emit_config_changed,
+    display_amount,
+    update_display_decimals,
+    contract_age,
+    require_burn_policy,
+    account_compliance,
+    system_overview,
+    system_addresses,
+    roles_of,
+    require_feature,
+    deposit_backing,
+    withdraw_backing,
+    update_strict_backing,
+    apply_genesis_backing,
+    configure_min_reserve_ratio,
+    current_reserve_ratio_bps,
+    preview_mint,
+    apply_config,
+    current_config,
+    require_upgrader,
+    update_default_allowance_ttl,
+    update_max_allowance_ttl,
+    recommended_allowance_expiration,
+    apply_compliance_actions,
+    recover_account,
+    freeze_allowances,
+    restore_allowances,
+    account_storage_footprint,
+    mint_vested_treasury,
+    vested_amount,
+    quote_transfer,
+    consolidate_locks,
+    health,
+    lifecycle_state,
+    require_role_reader,
+    ensure_roles_public,
+    validate_amount_range,
+    migrate_storage,
+    ensure_not_paused_for_role,
+    update_pause_immune_roles,
+    record_recent_op,
+    recent_operations,
+    call_hook,
+    run_post_upgrade_hook,
+    record_upgrade_consent,
+    require_timelock,
+    schedule_action,
+    execute_action,
+    cancel_action,
+    action_hash,
+    accept_pending_admin,
+    nominate_admin,
+    is_admin,
+    configure_balance_alert,
+    publish_finalized_burn,
+    effective_limits,
+    operation_bounds,
+    update_ttl_maintenance,
+    validate_ttl_maintenance,
+    withdraw_rent_reserve,
+    revoke_role,
+    split_major_units,
+    emit_emergency_action,
+    validate_pair_cached,
+    validate_mint_recipient,
+    validate_supply_not_frozen,
+    validate_no_pending_recovery,
+    validate_address_comprehensive,
+    validate_not_denylisted,
+    validate_contract_initialized,
+    validate_precision,
+    validate_supply_limits,
+    validate_backing,
 };
 
 /// Main stablecoin contract
+///
+/// Implements the SEP-41 token interface (`allowance`, `approve`, `balance`, `transfer`,
+/// `transfer_from`, `burn`, `burn_from`, `decimals`, `name`, `symbol`) with the standard's
+/// argument order and authorization semantics. Methods that return `Result` are
+/// wire-compatible with the standard: `Ok(())` is returned as void, and an `Err` aborts the
+/// invocation with a contract error exactly as a panic would for a SEP-41 caller.
+///
+/// The `stellar_fungible::FungibleToken` trait is deliberately not implemented. Its methods
+/// return `()` and would be exported under the same names as the methods here, so adopting
+/// it would mean dropping the typed `StablecoinError` results every caller and test relies
+/// on. Conformance is instead checked through `soroban_sdk::token::Client`.
+///
+/// Muxed addresses are not supported: every method takes a plain `Address`, so a muxed
+/// (`M...`) recipient fails argument conversion in the host before any contract code runs,
+/// and no memo id is ever silently dropped. Integrators pass memo ids with the
+/// `*_with_memo` methods instead.
 #[contract]
 pub struct MyStablecoin;
 
 #[contractimpl]
 impl MyStablecoin {
-    /// Initialize the stablecoin contract
+    /// Initialize the stablecoin contract, returning a report of the applied configuration
     pub fn initialize(
         env: Env,
         admin: Address,
         pauser: Address,
         upgrader: Address,
         minter: Address,
-    ) -> Result<(), StablecoinError> {
+    ) -> Result<InitReport, StablecoinError> {
+        // Validate configuration before writing anything
+        validate_init_params(
+            &env,
+            &admin,
+            &pauser,
+            &upgrader,
+            &minter,
+            &String::from_str(&env, NAME),
+            &String::from_str(&env, SYMBOL),
+            DECIMALS,
+        )?;
+        
         // Initialize token metadata
         initialize_token(&env);
         
         // Initialize access control with all roles
         initialize_access_control(&env, &admin, &pauser, &upgrader, &minter);
         
-        Ok(())
+        // Remember when the deployment went live
+        storage::set_init_ledger(&env, env.ledger().sequence());
+        storage::set_storage_version(&env, STORAGE_VERSION);
+        
+        // Backing is denominated in the token's decimals until configured otherwise
+        storage::set_backing_decimals(&env, DECIMALS);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(InitReport {
+            admin,
+            pauser,
+            upgrader,
+            minter,
+            name: Base::name(&env),
+            symbol: Base::symbol(&env),
+            decimals: Base::decimals(&env),
+            currency: storage::get_currency(&env),
+            supply_limits: ENABLE_SUPPLY_LIMITS,
+            operation_limits: ENABLE_OPERATION_LIMITS,
+            strict_validation: storage::get_strict_validation(&env),
+        })
+    }
+
+    /// Initialize the contract and mint `amount` to `treasury` as its genesis supply. The
+    /// treasury's tokens unlock over time: none before `cliff_ledgers`, then linearly until
+    /// fully vested after `duration_ledgers`. Unvested tokens count as locked balance.
+    /// `backing`, if given, is recorded first and the genesis mint is checked against it,
+    /// so a strict deployment never starts unbacked; it needs the backing feature.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_with_treasury(
+        env: Env,
+        admin: Address,
+        pauser: Address,
+        upgrader: Address,
+        minter: Address,
+        treasury: Address,
+        amount: i128,
+        cliff_ledgers: u32,
+        duration_ledgers: u32,
+        backing: Option<GenesisBacking>,
+    ) -> Result<InitReport, StablecoinError> {
+        let report = Self::initialize(env.clone(), admin, pauser, upgrader, minter)?;
+        
+        // Record the genesis reserve and backing mode before anything is minted
+        if let Some(backing) = backing {
+            require_feature(ENABLE_BACKING)?;
+            apply_genesis_backing(&env, &backing)?;
+        }
+        
+        // Mint the genesis supply under its vesting schedule
+        mint_vested_treasury(&env, &treasury, amount, cliff_ledgers, duration_ledgers)?;
+        
+        Ok(report)
+    }
+
+    /// Get how much of an account's genesis treasury mint has vested
+    pub fn vested_amount(env: Env, treasury: Address) -> i128 {
+        vested_amount(&env, &treasury)
+    }
+
+    /// Dry-run the initialization validations for a deployment config without mutating storage
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_init_params(
+        env: Env,
+        admin: Address,
+        pauser: Address,
+        upgrader: Address,
+        minter: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+    ) -> Result<(), StablecoinError> {
+        validate_init_params(&env, &admin, &pauser, &upgrader, &minter, &name, &symbol, decimals)
     }
 
     /// Mint tokens to a specific address
@@ -46,6 +317,9 @@ impl MyStablecoin {
             return Err(StablecoinError::Paused);
         }
         
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "mint")?;
+        
         // Authenticate the caller
         caller.require_auth();
         
@@ -55,256 +329,3615 @@ impl MyStablecoin {
         // Comprehensive validation for mint operation
         validate_mint_comprehensive(&env, &to, amount)?;
         
+        // Count against the global per-ledger mint cap and the minter's allowance
+        consume_mint_flow(&env, amount)?;
+        consume_minter_allowance(&env, &caller, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
         // Mint tokens
         Base::mint(&env, &to, amount);
+        note_mint(&env, &to, amount);
+        record_mint(&env, &caller, &to, amount);
         
         // Emit mint event
-        env.events().publish(
+        events::publish(
+            &env,
             (Symbol::new(&env, MINT_EVENT), &to),
             amount
         );
         
         Ok(())
     }
-    
-    /// Transfer tokens between addresses
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+
+    /// Mint tokens guarded by an idempotency key so retried submissions never double-mint.
+    /// A replayed key is a successful no-op, or `DuplicateOperation` when duplicates are rejected.
+    /// A no-op replay publishes `DuplicateSuppressed` instead of a second mint event, so
+    /// indexers never double-count.
+    pub fn mint_idempotent(
+        env: Env,
+        caller: Address,
+        to: Address,
+        amount: i128,
+        idempotency_key: BytesN<32>,
+    ) -> Result<(), StablecoinError> {
         // Check if contract is paused
         if pausable::paused(&env) {
             return Err(StablecoinError::Paused);
         }
         
-        // Comprehensive validation for transfer operation
-        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "mint_idempotent")?;
         
-        // Transfer tokens
-        Base::transfer(&env, &from, &to, amount);
+        // Authenticate the caller
+        caller.require_auth();
         
-        // Emit transfer event
-        env.events().publish(
-            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
+        // Validate minter role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, MINTER_ROLE));
+        
+        // Short-circuit replays of an already processed key
+        if storage::has_mint_key(&env, &idempotency_key) {
+            if storage::get_reject_duplicate_mints(&env) {
+                return Err(StablecoinError::DuplicateOperation);
+            }
+            events::publish(
+                &env,
+                (Symbol::new(&env, DUPLICATE_SUPPRESSED_EVENT),),
+                DuplicateSuppressed { key: idempotency_key }
+            );
+            return Ok(());
+        }
+        
+        // Comprehensive validation for mint operation
+        validate_mint_comprehensive(&env, &to, amount)?;
+        
+        // Count against the global per-ledger mint cap and the minter's allowance
+        consume_mint_flow(&env, amount)?;
+        consume_minter_allowance(&env, &caller, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Mint tokens and remember the key
+        Base::mint(&env, &to, amount);
+        note_mint(&env, &to, amount);
+        record_mint(&env, &caller, &to, amount);
+        storage::set_mint_key(&env, &idempotency_key);
+        
+        // Emit mint event
+        events::publish(
+            &env,
+            (Symbol::new(&env, MINT_EVENT), &to),
             amount
         );
         
         Ok(())
     }
-    
-    /// Transfer tokens from one address to another with allowance
-    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+
+    /// Mint tokens for a bank deposit identified by `bank_ref`. Each reference mints at most
+    /// once, so a replayed deposit webhook fails with `DuplicateOperation` instead of
+    /// double-issuing.
+    pub fn mint_for_deposit(
+        env: Env,
+        minter: Address,
+        to: Address,
+        amount: i128,
+        bank_ref: BytesN<32>,
+    ) -> Result<(), StablecoinError> {
         // Check if contract is paused
         if pausable::paused(&env) {
             return Err(StablecoinError::Paused);
         }
         
-        // Comprehensive validation for transfer operation
-        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "mint_for_deposit")?;
         
-        // Transfer tokens with allowance
-        Base::transfer_from(&env, &spender, &from, &to, amount);
+        // Authenticate the caller
+        minter.require_auth();
         
-        // Emit transfer event
-        env.events().publish(
-            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
-            amount
+        // Validate minter role
+        access_control::ensure_role(&env, &minter, &Symbol::new(&env, MINTER_ROLE));
+        
+        // Refuse deposits that were already minted for
+        if storage::has_deposit_ref(&env, &bank_ref) {
+            return Err(StablecoinError::DuplicateOperation);
+        }
+        
+        // Comprehensive validation for mint operation
+        validate_mint_comprehensive(&env, &to, amount)?;
+        
+        // Count against the global per-ledger mint cap and the minter's allowance
+        consume_mint_flow(&env, amount)?;
+        consume_minter_allowance(&env, &minter, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Mint tokens and remember the deposit
+        Base::mint(&env, &to, amount);
+        note_mint(&env, &to, amount);
+        record_mint(&env, &minter, &to, amount);
+        storage::set_deposit_ref(&env, &bank_ref);
+        
+        // Emit deposit mint event
+        events::publish(
+            &env,
+            (Symbol::new(&env, DEPOSIT_MINTED_EVENT), &to),
+            DepositMinted { bank_ref, to: to.clone(), amount }
         );
         
         Ok(())
     }
-    
-    /// Burn tokens from a specific address
-    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), StablecoinError> {
-        // Check if contract is paused
-        if pausable::paused(&env) {
-            return Err(StablecoinError::Paused);
-        }
+
+    /// Check whether a bank deposit reference has already been minted for
+    pub fn deposit_processed(env: Env, bank_ref: BytesN<32>) -> bool {
+        storage::has_deposit_ref(&env, &bank_ref)
+    }
+
+    /// Choose whether replayed mint idempotency keys error instead of succeeding silently (admin only)
+    pub fn set_reject_duplicate_mints(env: Env, admin: Address, reject: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
         
-        // Comprehensive validation for burn operation
-        validate_burn_comprehensive(&env, &from, amount)?;
+        storage::set_reject_duplicate_mints(&env, reject);
         
-        // Burn tokens
-        Base::burn(&env, &from, amount);
+        // Stamp the operation sequence
+        record_operation(&env);
         
-        // Emit burn event
-        env.events().publish(
-            (Symbol::new(&env, BURN_EVENT), &from),
-            amount
-        );
+        // Record the configuration change
+        emit_config_changed(&env, "reject_duplicate_mints", &admin);
         
         Ok(())
     }
+
+    /// Check whether a mint idempotency key has already been processed
+    pub fn is_mint_key_processed(env: Env, idempotency_key: BytesN<32>) -> bool {
+        storage::has_mint_key(&env, &idempotency_key)
+    }
     
-    /// Burn tokens from a specific address by a burner
-    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) -> Result<(), StablecoinError> {
-        // Check if contract is paused
-        if pausable::paused(&env) {
-            return Err(StablecoinError::Paused);
-        }
+    /// Allow or forbid transfers where `from == to` (admin only)
+    pub fn set_allow_self_transfer(env: Env, admin: Address, allow: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
         
-        // Comprehensive validation for burn operation
-        validate_burn_comprehensive(&env, &from, amount)?;
+        storage::set_allow_self_transfer(&env, allow);
         
-        // Burn tokens with allowance
-        Base::burn_from(&env, &spender, &from, amount);
+        // Stamp the operation sequence
+        record_operation(&env);
         
-        // Emit burn event
-        env.events().publish(
-            (Symbol::new(&env, BURN_EVENT), &from),
-            amount
-        );
+        // Record the configuration change
+        emit_config_changed(&env, "allow_self_transfer", &admin);
         
         Ok(())
     }
 
-    /// Get token information including metadata and current state
-    pub fn get_token_info(env: Env) -> (String, String, u32, i128, bool) {
-        (
-            Base::name(&env),
-            Base::symbol(&env),
-            Base::decimals(&env),
-            Base::total_supply(&env),
-            pausable::paused(&env),
-        )
+    /// Check whether transfers where `from == to` are allowed
+    pub fn allows_self_transfer(env: Env) -> bool {
+        storage::get_allow_self_transfer(&env)
     }
 
-    /// Batch mint tokens to multiple addresses
-    pub fn batch_mint(env: Env, caller: Address, recipients: Vec<(Address, i128)>) -> Result<(), StablecoinError> {
-        // Check if contract is paused
-        if pausable::paused(&env) {
-            return Err(StablecoinError::Paused);
-        }
+    /// Require transfer, mint and burn amounts to be multiples of `step`, e.g.
+    /// 10^(decimals - 2) for cent granularity; 0 disables the check (admin only)
+    pub fn set_precision_step(env: Env, admin: Address, step: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
         
-        // Authenticate the caller
-        caller.require_auth();
+        if step < 0 {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        storage::set_precision_step(&env, step);
         
-        // Validate minter role
-        access_control::ensure_role(&env, &caller, &Symbol::new(&env, MINTER_ROLE));
+        // Stamp the operation sequence
+        record_operation(&env);
         
-        // Validate and mint to each recipient
-        for (account, amount) in recipients.iter() {
-            // Validate mint operation (address and amount)
-            validate_mint_comprehensive(&env, &account, amount)?;
-            
-            // Perform the mint
-            Base::mint(&env, &account, amount);
-            
-            // Emit mint event for each recipient
-            env.events().publish(
-                (Symbol::new(&env, MINT_EVENT), &account),
-                amount
-            );
-        }
+        // Record the configuration change
+        emit_config_changed(&env, "precision_step", &admin);
         
         Ok(())
     }
-    
-    /// Pause the contract (only pauser role)
-    pub fn pause(env: Env, caller: Address) -> Result<(), StablecoinError> {
-        // Authenticate the caller
-        caller.require_auth();
+
+    /// Get the granularity amounts must be a multiple of (0 when disabled)
+    pub fn get_precision_step(env: Env) -> i128 {
+        storage::get_precision_step(&env)
+    }
+
+    /// Set the policy for minting to frozen accounts (admin only). By default such mints
+    /// are allowed so balances can be corrected; stricter deployments can reject them.
+    pub fn set_block_mint_to_frozen(env: Env, admin: Address, block: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
         
-        // Validate pauser role
-        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        storage::set_block_mint_to_frozen(&env, block);
         
-        // Pause the contract
-        pausable::pause(&env);
+        // Stamp the operation sequence
+        record_operation(&env);
         
-        // Emit pause event
-        env.events().publish(
-            (Symbol::new(&env, PAUSE_EVENT),),
-            ()
-        );
+        // Record the configuration change
+        emit_config_changed(&env, "block_mint_to_frozen", &admin);
         
         Ok(())
     }
-    
-    /// Unpause the contract (only pauser role)
-    pub fn unpause(env: Env, caller: Address) -> Result<(), StablecoinError> {
-        // Authenticate the caller
-        caller.require_auth();
+
+    /// Check whether mints to frozen accounts are rejected
+    pub fn blocks_mint_to_frozen(env: Env) -> bool {
+        storage::get_block_mint_to_frozen(&env)
+    }
+
+    /// Enable or disable strict address validation (admin only). In strict mode the
+    /// contract's own address is rejected as a sender, recipient or mint target.
+    pub fn set_strict_validation(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
         
-        // Validate pauser role
-        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        storage::set_strict_validation(&env, enabled);
         
-        // Unpause the contract
-        pausable::unpause(&env);
+        // Stamp the operation sequence
+        record_operation(&env);
         
-        // Emit unpause event
-        env.events().publish(
-            (Symbol::new(&env, UNPAUSE_EVENT),),
-            ()
-        );
+        // Record the configuration change
+        emit_config_changed(&env, "strict_validation", &admin);
         
         Ok(())
     }
 
-    /// Get balance of an address
-    pub fn balance(env: Env, address: Address) -> i128 {
-        Base::balance(&env, &address)
+    /// Check whether strict address validation is enforced
+    pub fn is_strict_validation(env: Env) -> bool {
+        storage::get_strict_validation(&env)
     }
 
-    /// Get allowance between two addresses
-    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
-        Base::allowance(&env, &from, &spender)
+    /// Enable or disable allowances (admin only). While disabled, `approve`, `approve_for`,
+    /// `ensure_allowance`, `transfer_from` and `burn_from` return `FeatureDisabled`; direct
+    /// transfers and burns are unaffected.
+    pub fn set_allowances_enabled(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_allowances_enabled(&env, enabled);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "allowances_enabled", &admin);
+        
+        Ok(())
     }
 
-    /// Approve spending allowance
-    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
-        // Check if contract is paused
-        if pausable::paused(&env) {
-            return Err(StablecoinError::Paused);
-        }
+    /// Check whether allowances and delegated spending are enabled
+    pub fn allowances_enabled(env: Env) -> bool {
+        storage::get_allowances_enabled(&env)
+    }
+
+    /// Allow or forbid the admin and compliance role to set allowances for managed accounts
+    /// with `admin_approve`; off by default (admin only)
+    pub fn set_managed_approvals(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
         
-        // Approve allowance
-        Base::approve(&env, &from, &spender, amount, expiration_ledger);
+        storage::set_managed_approvals(&env, enabled);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "managed_approvals", &admin);
         
         Ok(())
     }
 
-    /// Get token name
-    pub fn name(env: Env) -> String {
-        Base::name(&env)
+    /// Check whether the admin and compliance role may set allowances for managed accounts
+    pub fn managed_approvals_enabled(env: Env) -> bool {
+        storage::get_managed_approvals(&env)
     }
 
-    /// Get token symbol
-    pub fn symbol(env: Env) -> String {
-        Base::symbol(&env)
+    /// Register `account` as managed, opening it to `admin_approve`, or unregister it. The
+    /// account opts itself in or out, or the admin registers custodial accounts.
+    pub fn set_managed_account(env: Env, caller: Address, account: Address, managed: bool) -> Result<(), StablecoinError> {
+        // Validate the account itself or the admin
+        require_self_or_admin(&env, &caller, &account)?;
+        
+        storage::set_managed_account(&env, &account, managed);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "managed_account", &caller);
+        
+        Ok(())
     }
 
-    /// Get token decimals
-    pub fn decimals(env: Env) -> u32 {
-        Base::decimals(&env)
+    /// Check whether an account is registered as managed
+    pub fn is_managed_account(env: Env, account: Address) -> bool {
+        storage::is_managed_account(&env, &account)
     }
 
-    /// Get total supply
-    pub fn total_supply(env: Env) -> i128 {
-        Base::total_supply(&env)
+    /// Freeze total supply for an attestation window: every mint and burn (including
+    /// clawbacks) fails with `SupplyFrozen` while transfers continue (admin only)
+    pub fn freeze_supply(env: Env, admin: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        if storage::get_supply_frozen(&env) {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        storage::set_supply_frozen(&env, true);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "supply_frozen", &admin);
+        
+        Ok(())
     }
 
-    /// Check if contract is paused
-    pub fn is_paused(env: Env) -> bool {
-        pausable::paused(&env)
+    /// Lift a supply freeze (admin only)
+    pub fn thaw_supply(env: Env, admin: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        if !storage::get_supply_frozen(&env) {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        storage::set_supply_frozen(&env, false);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "supply_frozen", &admin);
+        
+        Ok(())
     }
 
-    /// Check if address has a specific role
-    pub fn has_role_minter(env: Env, address: Address) -> bool {
-        let role_symbol = Symbol::new(&env, MINTER_ROLE);
-        access_control::has_role(&env, &address, &role_symbol).is_some()
+    /// Check whether total supply is frozen
+    pub fn is_supply_frozen(env: Env) -> bool {
+        storage::get_supply_frozen(&env)
     }
 
-    /// Check if address has pauser role
-    pub fn has_role_pauser(env: Env, address: Address) -> bool {
-        let role_symbol = Symbol::new(&env, PAUSER_ROLE);
-        access_control::has_role(&env, &address, &role_symbol).is_some()
+    /// Get up to `limit` mint log entries starting at log sequence number `start`
+    pub fn get_mint_log(env: Env, start: u32, limit: u32) -> Vec<MintLogEntry> {
+        read_mint_log(&env, start, limit)
     }
 
-    /// Check if address has upgrader role
-    pub fn has_role_upgrader(env: Env, address: Address) -> bool {
-        let role_symbol = Symbol::new(&env, UPGRADER_ROLE);
-        access_control::has_role(&env, &address, &role_symbol).is_some()
+    /// Get up to `n` of the most recent mints, burns and transfers, newest first, for
+    /// clients without an indexer. At most RECENT_OPS_CAPACITY are retained.
+    pub fn recent_operations(env: Env, n: u32) -> Vec<OpSummary> {
+        recent_operations(&env, n)
     }
 
-    /// Get admin address
-    pub fn get_admin(env: Env) -> Option<Address> {
-        access_control::get_admin(&env)
+    /// Get the total number of mints ever recorded in the mint log
+    pub fn get_mint_log_count(env: Env) -> u32 {
+        storage::get_mint_log_count(&env)
+    }
+    
+    /// Transfer tokens between addresses
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused, letting migration transfers to the rescue address through
+        ensure_transfer_not_paused(&env, &to)?;
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "transfer")?;
+        
+        // Comprehensive validation for transfer operation
+        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Transfer tokens
+        Base::transfer(&env, &from, &to, amount);
+        let net = charge_transfer_fee(&env, &from, &to, amount);
+        note_credit(&env, &to, net);
+        note_debit(&env, &from, amount);
+        record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
+            amount
+        );
+        
+        Ok(())
+    }
+    
+    /// Transfer tokens to a contract and invoke its `on_token_transfer(from, amount, data)`
+    /// in the same transaction, with `amount` being what the receiver was credited after
+    /// fees. If the callback fails the whole transfer reverts with `HookFailed`.
+    pub fn transfer_and_call(env: Env, from: Address, to: Address, amount: i128, data: Bytes) -> Result<(), StablecoinError> {
+        // Check if contract is paused, letting migration transfers to the rescue address through
+        ensure_transfer_not_paused(&env, &to)?;
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "transfer_and_call")?;
+        
+        // Comprehensive validation for transfer operation
+        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Transfer tokens
+        Base::transfer(&env, &from, &to, amount);
+        let net = charge_transfer_fee(&env, &from, &to, amount);
+        note_credit(&env, &to, net);
+        note_debit(&env, &from, amount);
+        record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
+            amount
+        );
+        
+        // Notify the receiver; a failed callback reverts the transfer
+        call_hook(
+            &env,
+            &to,
+            TOKEN_RECEIVED_HOOK,
+            vec![&env, from.into_val(&env), net.into_val(&env), data.into_val(&env)],
+            true,
+        )
+    }
+
+    /// Transfer as much of `requested` as `from` can spend, returning the amount actually
+    /// moved. With nothing spendable this is a no-op returning 0. Only available once
+    /// partial transfers are enabled; `transfer` always moves the full amount or fails.
+    pub fn transfer_partial(env: Env, from: Address, to: Address, requested: i128) -> Result<i128, StablecoinError> {
+        // Check if contract is paused, letting migration transfers to the rescue address through
+        ensure_transfer_not_paused(&env, &to)?;
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "transfer_partial")?;
+        
+        // Partial fills are opt-in
+        require_feature(storage::get_partial_transfers(&env))?;
+        
+        // Reject non-positive requests before sizing the fill
+        validate_amount_range(requested)?;
+        
+        // Fill what is available
+        let amount = requested.min(spendable_balance(&env, &from));
+        if amount <= 0 {
+            return Ok(0);
+        }
+        
+        // Comprehensive validation for transfer operation
+        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Transfer tokens
+        Base::transfer(&env, &from, &to, amount);
+        let net = charge_transfer_fee(&env, &from, &to, amount);
+        note_credit(&env, &to, net);
+        note_debit(&env, &from, amount);
+        record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
+            amount
+        );
+        
+        Ok(amount)
+    }
+
+    /// Enable or disable `transfer_partial` (admin only)
+    pub fn set_partial_transfers(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_partial_transfers(&env, enabled);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "partial_transfers", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether `transfer_partial` is enabled
+    pub fn partial_transfers_enabled(env: Env) -> bool {
+        storage::get_partial_transfers(&env)
+    }
+
+    /// Transfer tokens carrying a memo (e.g. an invoice or payment reference); the event
+    /// also carries the amount formatted for display in the token's currency
+    pub fn transfer_with_memo(env: Env, from: Address, to: Address, amount: i128, memo: String) -> Result<(), StablecoinError> {
+        // Check if contract is paused, letting migration transfers to the rescue address through
+        ensure_transfer_not_paused(&env, &to)?;
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "transfer_with_memo")?;
+        
+        // Validate memo before touching balances
+        validate_memo(&memo)?;
+        
+        // Comprehensive validation for transfer operation
+        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Transfer tokens
+        Base::transfer(&env, &from, &to, amount);
+        let net = charge_transfer_fee(&env, &from, &to, amount);
+        note_credit(&env, &to, net);
+        note_debit(&env, &from, amount);
+        record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+        
+        // Emit transfer-with-memo event
+        events::publish(
+            &env,
+            (Symbol::new(&env, TRANSFER_WITH_MEMO_EVENT), &from, &to),
+            (amount, memo, display_amount(&env, amount))
+        );
+        
+        Ok(())
+    }
+    
+    /// Transfer tokens from one address to another with allowance.
+    /// The spender's allowance is debited by exactly `amount` (once, inside `Base::transfer_from`);
+    /// any transfer fee is carved out of `amount` and never consumes additional allowance.
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "transfer_from")?;
+        
+        // Delegated spending can be switched off per deployment
+        require_feature(storage::get_allowances_enabled(&env))?;
+        
+        // Comprehensive validation for delegated transfer (parties, balance and allowance)
+        validate_spend(&env, &spender, &from, &to, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Transfer tokens with allowance, falling back to the grace window for a lapsed one.
+        // `Base::transfer_from` authenticates the spender itself; the grace path does it here.
+        if Base::allowance(&env, &from, &spender) >= amount {
+            Base::transfer_from(&env, &spender, &from, &to, amount);
+        } else {
+            spender.require_auth();
+            transfer_within_grace(&env, &from, &to, amount);
+        }
+        let net = charge_transfer_fee(&env, &from, &to, amount);
+        note_credit(&env, &to, net);
+        note_debit(&env, &from, amount);
+        record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+        note_allowance_spent(&env, &from, &spender, amount);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
+            amount
+        );
+        
+        Ok(())
+    }
+    
+    /// Consent to `spender` pulling tokens from `from` without a per-transfer allowance.
+    /// Only contracts holding the spender role can use the consent.
+    pub fn opt_in_spender(env: Env, from: Address, spender: Address) -> Result<(), StablecoinError> {
+        // Authenticate the account
+        from.require_auth();
+        
+        if from == spender {
+            return Err(StablecoinError::SelfTransfer);
+        }
+        storage::set_spender_opt_in(&env, &from, &spender, true);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit consent event
+        events::publish(
+            &env,
+            (Symbol::new(&env, SPENDER_CONSENT_EVENT), &from, &spender),
+            true
+        );
+        
+        Ok(())
+    }
+
+    /// Withdraw consent given with `opt_in_spender`
+    pub fn opt_out_spender(env: Env, from: Address, spender: Address) -> Result<(), StablecoinError> {
+        // Authenticate the account
+        from.require_auth();
+        
+        storage::set_spender_opt_in(&env, &from, &spender, false);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit consent event
+        events::publish(
+            &env,
+            (Symbol::new(&env, SPENDER_CONSENT_EVENT), &from, &spender),
+            false
+        );
+        
+        Ok(())
+    }
+
+    /// Check whether `from` has consented to pulls by `spender`
+    pub fn is_opted_in_spender(env: Env, from: Address, spender: Address) -> bool {
+        storage::is_spender_opted_in(&env, &from, &spender)
+    }
+
+    /// Approval-less transfer for trusted protocol contracts: a contract holding the spender
+    /// role moves tokens from an account that has opted in to it, with no allowance
+    pub fn pull(env: Env, spender: Address, from: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "pull")?;
+        
+        // The spender contract must sign, hold the spender role and have the account's consent
+        spender.require_auth();
+        validate_pull(&env, &spender, &from)?;
+        validate_spender_compliance(&env, &spender)?;
+        
+        // Comprehensive validation for transfer operation
+        validate_transfer_comprehensive(&env, &from, &to, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Transfer tokens, emitting the standard transfer event `Base::transfer` would
+        Base::update(&env, Some(&from), Some(&to), amount);
+        emit_transfer(&env, &from, &to, amount);
+        let net = charge_transfer_fee(&env, &from, &to, amount);
+        note_credit(&env, &to, net);
+        note_debit(&env, &from, amount);
+        record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, TRANSFER_EVENT), &from, &to),
+            amount
+        );
+        
+        Ok(())
+    }
+    
+    /// Burn tokens from a specific address
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "burn")?;
+        
+        // Apply the burn policy; `Base::burn` authenticates the holder
+        require_burn_policy(&env, &from)?;
+        
+        // Comprehensive validation for burn operation
+        validate_burn_comprehensive(&env, &from, amount)?;
+        
+        // Count against the global per-ledger burn cap
+        consume_burn_flow(&env, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Burn tokens
+        Base::burn(&env, &from, amount);
+        note_burn(&env, &from, amount);
+        
+        // Hand the redemption to the settlement contract, if configured
+        settle_redemption(&env, &from, amount)?;
+        
+        // Emit burn event
+        events::publish(
+            &env,
+            (Symbol::new(&env, BURN_EVENT), &from),
+            amount
+        );
+        
+        // Emit the finalized burn event by origin
+        publish_finalized_burn(&env, &from, &from, amount);
+        
+        Ok(())
+    }
+    
+    /// Burn tokens for an off-chain redemption, carrying a memo (e.g. a bank
+    /// reference) so the banking side can match the payout to the burn
+    pub fn burn_with_memo(env: Env, from: Address, amount: i128, memo: String) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "burn_with_memo")?;
+        
+        // Apply the burn policy; `Base::burn` authenticates the holder
+        require_burn_policy(&env, &from)?;
+        
+        // Validate memo before touching balances
+        validate_memo(&memo)?;
+        
+        // Comprehensive validation for burn operation
+        validate_burn_comprehensive(&env, &from, amount)?;
+        
+        // Count against the global per-ledger burn cap
+        consume_burn_flow(&env, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Burn tokens
+        Base::burn(&env, &from, amount);
+        note_burn(&env, &from, amount);
+        
+        // Hand the redemption to the settlement contract, if configured
+        settle_redemption(&env, &from, amount)?;
+        
+        // Emit burn-with-memo event
+        events::publish(
+            &env,
+            (Symbol::new(&env, BURN_WITH_MEMO_EVENT), &from),
+            (amount, memo, display_amount(&env, amount))
+        );
+        
+        // Emit the finalized burn event by origin
+        publish_finalized_burn(&env, &from, &from, amount);
+        
+        Ok(())
+    }
+    
+    /// Burn tokens with governance oversight. When symmetric authorization is enabled,
+    /// burns above the configured threshold also require the admin's signature.
+    pub fn governed_burn(env: Env, from: Address, admin: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "governed_burn")?;
+        
+        // Apply the burn policy; `Base::burn` authenticates the holder
+        require_burn_policy(&env, &from)?;
+        
+        // Comprehensive validation for burn operation
+        validate_burn_comprehensive(&env, &from, amount)?;
+        
+        // Count against the global per-ledger burn cap
+        consume_burn_flow(&env, amount)?;
+        
+        // Large burns need the admin co-signature in symmetric mode
+        if storage::get_require_symmetric_authorization(&env)
+            && amount > storage::get_governed_burn_threshold(&env)
+        {
+            require_admin(&env, &admin)?;
+        }
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Burn tokens (requires the holder's authorization)
+        Base::burn(&env, &from, amount);
+        note_burn(&env, &from, amount);
+        
+        // Hand the redemption to the settlement contract, if configured
+        settle_redemption(&env, &from, amount)?;
+        
+        // Emit burn event
+        events::publish(
+            &env,
+            (Symbol::new(&env, BURN_EVENT), &from),
+            amount
+        );
+        
+        // Emit the finalized burn event by origin
+        publish_finalized_burn(&env, &from, &from, amount);
+        
+        Ok(())
+    }
+
+    /// Choose the burn policy: self-serve (the default), where any holder may burn their
+    /// own tokens, or role-gated, where only burner role holders may (admin only)
+    pub fn set_role_gated_burns(env: Env, admin: Address, gated: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_role_gated_burns(&env, gated);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "role_gated_burns", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether burning is restricted to holders of the burner role
+    pub fn is_role_gated_burns(env: Env) -> bool {
+        storage::get_role_gated_burns(&env)
+    }
+
+    /// Configure the admin co-signature requirement for large governed burns (admin only)
+    pub fn set_symmetric_authorization(env: Env, admin: Address, required: bool, threshold: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        if threshold < 0 {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        
+        storage::set_require_symmetric_authorization(&env, required);
+        storage::set_governed_burn_threshold(&env, threshold);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "symmetric_authorization", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the symmetric authorization mode and its burn threshold
+    pub fn get_symmetric_authorization(env: Env) -> (bool, i128) {
+        (
+            storage::get_require_symmetric_authorization(&env),
+            storage::get_governed_burn_threshold(&env),
+        )
+    }
+    
+    /// Burn tokens from a specific address by a burner. Holders burning their own tokens
+    /// must use `burn`, so `spender == from` is rejected with `SelfTransfer`.
+    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "burn_from")?;
+        
+        // Delegated spending can be switched off per deployment
+        require_feature(storage::get_allowances_enabled(&env))?;
+        
+        // Self burns go through `burn` and its authorization model
+        if spender == from {
+            return Err(StablecoinError::SelfTransfer);
+        }
+        
+        // Comprehensive validation for burn operation
+        validate_burn_comprehensive(&env, &from, amount)?;
+        
+        // Count against the global per-ledger burn cap
+        consume_burn_flow(&env, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Burn tokens with allowance
+        Base::burn_from(&env, &spender, &from, amount);
+        note_burn(&env, &from, amount);
+        note_allowance_spent(&env, &from, &spender, amount);
+        
+        // Hand the redemption to the settlement contract, if configured
+        settle_redemption(&env, &from, amount)?;
+        
+        // Emit burn event
+        events::publish(
+            &env,
+            (Symbol::new(&env, BURN_EVENT), &from),
+            amount
+        );
+        
+        // Emit the finalized burn event by origin
+        publish_finalized_burn(&env, &from, &spender, amount);
+        
+        Ok(())
+    }
+
+    /// Get token information including metadata and current state
+    pub fn get_token_info(env: Env) -> (String, String, u32, i128, bool) {
+        (
+            Base::name(&env),
+            Base::symbol(&env),
+            Base::decimals(&env),
+            Base::total_supply(&env),
+            pausable::paused(&env),
+        )
+    }
+
+    /// Batch mint tokens to multiple addresses
+    pub fn batch_mint(env: Env, caller: Address, recipients: Vec<(Address, i128)>) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "batch_mint")?;
+        
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate minter role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, MINTER_ROLE));
+        
+        // Reject oversized batches before doing any work
+        validate_batch_size(&env, recipients.len())?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let aggregate = storage::get_batch_events(&env);
+        let mut summary = BatchSummary { count: 0, total: 0 };
+        
+        // Validate and mint to each recipient
+        for (account, amount) in recipients.iter() {
+            // Validate mint operation (address and amount)
+            validate_mint_comprehensive(&env, &account, amount)?;
+            
+            // Count against the global per-ledger mint cap and the minter's allowance
+            consume_mint_flow(&env, amount)?;
+            consume_minter_allowance(&env, &caller, amount)?;
+            
+            // Perform the mint
+            Base::mint(&env, &account, amount);
+            note_mint(&env, &account, amount);
+            record_mint(&env, &caller, &account, amount);
+            
+            summary.count += 1;
+            summary.total += amount;
+            
+            // Emit mint event for each recipient, unless aggregating
+            if !aggregate {
+                events::publish(
+                    &env,
+                    (Symbol::new(&env, MINT_EVENT), &account),
+                    amount
+                );
+            }
+        }
+        
+        // Emit a single aggregate event in batch-events mode
+        if aggregate {
+            events::publish(&env, (Symbol::new(&env, BATCH_MINTED_EVENT), &caller), summary);
+        }
+        
+        Ok(())
+    }
+
+    /// Mint the same amount to many recipients. The aggregate is checked once against the
+    /// supply cap, the per-ledger mint cap and the minter's allowance, so an airdrop either
+    /// fits as a whole or mints nothing.
+    pub fn airdrop(env: Env, minter: Address, amount_each: i128, recipients: Vec<Address>) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "airdrop")?;
+        
+        // Authenticate the caller
+        minter.require_auth();
+        
+        // Validate minter role
+        access_control::ensure_role(&env, &minter, &Symbol::new(&env, MINTER_ROLE));
+        
+        // Validate the batch and the uniform amount once
+        validate_contract_initialized(&env)?;
+        validate_batch_size(&env, recipients.len())?;
+        validate_amount_range(amount_each)?;
+        validate_precision(&env, amount_each)?;
+        
+        // Check and count the aggregate against supply, the per-ledger cap and the allowance
+        let total = amount_each
+            .checked_mul(recipients.len() as i128)
+            .ok_or(StablecoinError::AmountTooLarge)?;
+        validate_supply_not_frozen(&env)?;
+        validate_no_pending_recovery(&env)?;
+        validate_supply_limits(&env, total)?;
+        validate_backing(&env, total)?;
+        consume_mint_flow(&env, total)?;
+        consume_minter_allowance(&env, &minter, total)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let aggregate = storage::get_batch_events(&env);
+        for account in recipients.iter() {
+            // Validate the recipient
+            validate_address_comprehensive(&env, &account)?;
+            validate_mint_recipient(&env, &account)?;
+            
+            // Perform the mint
+            Base::mint(&env, &account, amount_each);
+            note_mint(&env, &account, amount_each);
+            record_mint(&env, &minter, &account, amount_each);
+            
+            // Emit mint event for each recipient, unless aggregating
+            if !aggregate {
+                events::publish(
+                    &env,
+                    (Symbol::new(&env, MINT_EVENT), &account),
+                    amount_each
+                );
+            }
+        }
+        
+        // Emit a single aggregate event in batch-events mode
+        if aggregate {
+            events::publish(
+                &env,
+                (Symbol::new(&env, BATCH_MINTED_EVENT), &minter),
+                BatchSummary { count: recipients.len(), total }
+            );
+        }
+        
+        Ok(())
+    }
+
+    /// Best-effort batch mint: mints every valid entry and skips the rest, returning the
+    /// index and reason of each skipped entry. Supply only reflects the successful mints.
+    pub fn batch_mint_lenient(env: Env, caller: Address, recipients: Vec<(Address, i128)>) -> Result<Vec<(u32, StablecoinError)>, StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "batch_mint_lenient")?;
+        
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate minter role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, MINTER_ROLE));
+        
+        // Reject oversized batches before doing any work
+        validate_batch_size(&env, recipients.len())?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let aggregate = storage::get_batch_events(&env);
+        let mut summary = BatchSummary { count: 0, total: 0 };
+        
+        let mut skipped = Vec::new(&env);
+        for (index, (account, amount)) in recipients.iter().enumerate() {
+            // Skip entries that fail any mint check, leaving no trace of them
+            if let Err(error) = admit_mint(&env, &caller, &account, amount) {
+                skipped.push_back((index as u32, error));
+                continue;
+            }
+            
+            // Perform the mint
+            Base::mint(&env, &account, amount);
+            note_mint(&env, &account, amount);
+            record_mint(&env, &caller, &account, amount);
+            
+            summary.count += 1;
+            summary.total += amount;
+            
+            // Emit mint event for each recipient, unless aggregating
+            if !aggregate {
+                events::publish(
+                    &env,
+                    (Symbol::new(&env, MINT_EVENT), &account),
+                    amount
+                );
+            }
+        }
+        
+        // Emit a single aggregate event in batch-events mode
+        if aggregate {
+            events::publish(&env, (Symbol::new(&env, BATCH_MINTED_EVENT), &caller), summary);
+        }
+        
+        Ok(skipped)
+    }
+
+    /// Batch transfer tokens from one address to multiple recipients
+    pub fn batch_transfer(env: Env, from: Address, recipients: Vec<(Address, i128)>) -> Result<(), StablecoinError> {
+        // Check if contract is paused; each recipient is checked below, where migration
+        // transfers to the rescue address are let through
+        if pausable::paused(&env) && recipients.is_empty() {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "batch_transfer")?;
+        
+        // Reject oversized batches before doing any work
+        validate_batch_size(&env, recipients.len())?;
+        
+        // Authenticate the sender once for the whole batch
+        from.require_auth();
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let aggregate = storage::get_batch_events(&env);
+        let mut summary = BatchSummary { count: 0, total: 0 };
+        
+        // Validate and transfer to each recipient
+        for (to, amount) in recipients.iter() {
+            // Check if contract is paused, letting migration transfers to the rescue address through
+            ensure_transfer_not_paused(&env, &to)?;
+            
+            // Validate transfer operation (addresses, amount and balance)
+            validate_transfer_comprehensive(&env, &from, &to, amount)?;
+            
+            // Perform the transfer
+            Base::update(&env, Some(&from), Some(&to), amount);
+            let net = charge_transfer_fee(&env, &from, &to, amount);
+            note_credit(&env, &to, net);
+            note_debit(&env, &from, amount);
+            record_recent_op(&env, TRANSFER_EVENT, Some(&from), Some(&to), amount);
+            
+            summary.count += 1;
+            summary.total += amount;
+            
+            // Emit the standard transfer event for each recipient, unless aggregating
+            if !aggregate {
+                emit_transfer(&env, &from, &to, amount);
+            }
+        }
+        
+        // Emit a single aggregate event in batch-events mode
+        if aggregate {
+            events::publish(&env, (Symbol::new(&env, BATCH_TRANSFERRED_EVENT), &from), summary);
+        }
+        
+        Ok(())
+    }
+
+    /// Dry-run a mint, returning the total supply that would result or the reason it would fail
+    pub fn preview_mint(env: Env, to: Address, amount: i128) -> Result<i128, StablecoinError> {
+        preview_mint(&env, &to, amount)
+    }
+
+    /// Pre-flight a batch transfer without executing it, returning the first reason it would fail
+    pub fn preview_batch_transfer(env: Env, from: Address, recipients: Vec<(Address, i128)>) -> Result<(), StablecoinError> {
+        preview_batch_transfer(&env, &from, &recipients)
+    }
+
+    /// Set the maximum number of entries accepted by batch methods (admin only)
+    pub fn set_max_batch_size(env: Env, admin: Address, max_batch_size: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "max_batch_size", &admin);
+        
+        update_max_batch_size(&env, max_batch_size)
+    }
+
+    /// Get the maximum number of entries accepted by batch methods
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        storage::get_max_batch_size(&env)
+    }
+
+    /// Switch bulk operations between one event per entry (the default) and a single
+    /// aggregate `batch_minted` / `batch_transferred` event carrying the count and total,
+    /// for large migrations (admin only)
+    pub fn set_batch_events(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "batch_events", &admin);
+        
+        storage::set_batch_events(&env, enabled);
+        Ok(())
+    }
+
+    /// Check whether bulk operations emit a single aggregate event
+    pub fn is_batch_events(env: Env) -> bool {
+        storage::get_batch_events(&env)
+    }
+    
+    /// Pause the contract (only pauser role)
+    pub fn pause(env: Env, caller: Address) -> Result<(), StablecoinError> {
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate pauser role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        
+        // At most one pause action per ledger, when enforced
+        claim_pause_action(&env)?;
+        
+        // Pause the contract
+        pausable::pause(&env);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit pause event
+        events::publish(
+            &env,
+            (Symbol::new(&env, PAUSE_EVENT),),
+            ()
+        );
+        
+        // Notify dependent contracts (best-effort)
+        notify_pause_subscribers(&env, true);
+        
+        Ok(())
+    }
+    
+    /// Unpause the contract (only pauser role)
+    pub fn unpause(env: Env, caller: Address) -> Result<(), StablecoinError> {
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate pauser role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        
+        // At most one pause action per ledger, when enforced
+        claim_pause_action(&env)?;
+        
+        // Unpause the contract
+        pausable::unpause(&env);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit unpause event
+        events::publish(
+            &env,
+            (Symbol::new(&env, UNPAUSE_EVENT),),
+            ()
+        );
+        
+        // Notify dependent contracts (best-effort)
+        notify_pause_subscribers(&env, false);
+        
+        Ok(())
+    }
+
+    /// Allow at most one pause action (pause, unpause or a method pause change) per ledger,
+    /// so conflicting actions by several pausers cannot interleave (admin only)
+    pub fn set_single_pause_action(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_single_pause_action(&env, enabled);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "single_pause_action", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether at most one pause action may be applied per ledger
+    pub fn single_pause_action(env: Env) -> bool {
+        storage::get_single_pause_action(&env)
+    }
+
+    /// Turn on migration mode (admin only): while the contract is paused, direct transfers
+    /// to `rescue` (`transfer`, `transfer_with_memo`, `transfer_partial`, `transfer_and_call`
+    /// and `batch_transfer` when every recipient is `rescue`) are still allowed so users can
+    /// move their funds off voluntarily. Every other operation stays halted.
+    pub fn migration_mode(env: Env, admin: Address, rescue: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Validate the rescue address
+        validate_address_comprehensive(&env, &rescue)?;
+        validate_not_denylisted(&env, &rescue)?;
+        
+        storage::set_migration_rescue(&env, Some(&rescue));
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "migration_rescue", &admin);
+        
+        Ok(())
+    }
+
+    /// Turn off migration mode (admin only)
+    pub fn end_migration_mode(env: Env, admin: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_migration_rescue(&env, None);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "migration_rescue", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the migration rescue address, if migration mode is on
+    pub fn migration_rescue(env: Env) -> Option<Address> {
+        storage::get_migration_rescue(&env)
+    }
+
+    /// Pause the contract and zero live allowances, snapshotting them so
+    /// `unpause_restore_allowances` can bring them back. The pause always takes effect;
+    /// at most MAX_ALLOWANCE_SNAPSHOT allowances are zeroed. Returns how many were zeroed
+    /// (only pauser role).
+    pub fn pause_with_allowance_freeze(env: Env, caller: Address) -> Result<u32, StablecoinError> {
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate pauser role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        
+        // At most one pause action per ledger, when enforced
+        claim_pause_action(&env)?;
+        
+        // Pause the contract and zero allowances
+        pausable::pause(&env);
+        let frozen = freeze_allowances(&env);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit pause event
+        events::publish(
+            &env,
+            (Symbol::new(&env, PAUSE_EVENT),),
+            ()
+        );
+        emit_emergency_action(&env, EMERGENCY_FREEZE_KIND, &caller);
+        
+        // Notify dependent contracts (best-effort)
+        notify_pause_subscribers(&env, true);
+        
+        Ok(frozen)
+    }
+    
+    /// Unpause the contract and restore the allowances zeroed by
+    /// `pause_with_allowance_freeze`. Returns how many were restored (only pauser role).
+    pub fn unpause_restore_allowances(env: Env, caller: Address) -> Result<u32, StablecoinError> {
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate pauser role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        
+        // At most one pause action per ledger, when enforced
+        claim_pause_action(&env)?;
+        
+        // Unpause the contract and restore allowances
+        pausable::unpause(&env);
+        let restored = restore_allowances(&env)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit unpause event
+        events::publish(
+            &env,
+            (Symbol::new(&env, UNPAUSE_EVENT),),
+            ()
+        );
+        
+        // Notify dependent contracts (best-effort)
+        notify_pause_subscribers(&env, false);
+        
+        Ok(restored)
+    }
+
+    /// Pause a single method by name, leaving the rest of the contract running (only pauser role).
+    /// The name must be one of PAUSABLE_METHODS; every method checks its own name only.
+    pub fn pause_method(env: Env, caller: Address, method: Symbol) -> Result<(), StablecoinError> {
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate pauser role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        
+        // At most one pause action per ledger, when enforced
+        claim_pause_action(&env)?;
+        
+        pause_method(&env, &method)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit pause event naming the method
+        events::publish(
+            &env,
+            (Symbol::new(&env, PAUSE_EVENT), &method),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Unpause a single method by name (only pauser role)
+    pub fn unpause_method(env: Env, caller: Address, method: Symbol) -> Result<(), StablecoinError> {
+        // Authenticate the caller
+        caller.require_auth();
+        
+        // Validate pauser role
+        access_control::ensure_role(&env, &caller, &Symbol::new(&env, PAUSER_ROLE));
+        
+        // At most one pause action per ledger, when enforced
+        claim_pause_action(&env)?;
+        
+        unpause_method(&env, &method)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit unpause event naming the method
+        events::publish(
+            &env,
+            (Symbol::new(&env, UNPAUSE_EVENT), &method),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Get the methods that are individually paused
+    pub fn paused_methods(env: Env) -> Vec<Symbol> {
+        storage::get_paused_methods(&env)
+    }
+
+    /// Register a contract to receive `on_pause_changed(paused)` calls (admin only)
+    pub fn add_pause_subscriber(env: Env, admin: Address, subscriber: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        add_pause_subscriber(&env, &subscriber)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "pause_subscribers", &admin);
+        
+        Ok(())
+    }
+
+    /// Unregister a pause subscriber (admin only)
+    pub fn remove_pause_subscriber(env: Env, admin: Address, subscriber: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        remove_pause_subscriber(&env, &subscriber)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "pause_subscribers", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the contracts notified of pause state changes
+    pub fn get_pause_subscribers(env: Env) -> Vec<Address> {
+        storage::get_pause_subscribers(&env)
+    }
+
+    /// Get balance of an address
+    pub fn balance(env: Env, id: Address) -> Result<i128, StablecoinError> {
+        // Refuse possibly stale reads during a lockdown
+        ensure_reads_available(&env)?;
+        
+        Ok(Base::balance(&env, &id))
+    }
+
+    /// ERC20-style alias of `balance` for tooling that expects `balanceOf`
+    pub fn balance_of(env: Env, address: Address) -> Result<i128, StablecoinError> {
+        Self::balance(env, address)
+    }
+
+    /// Get the amount an address can actually send right now, after freezes and locks
+    pub fn spendable_balance(env: Env, account: Address) -> Result<i128, StablecoinError> {
+        // Refuse possibly stale reads during a lockdown
+        ensure_reads_available(&env)?;
+        
+        Ok(spendable_balance(&env, &account))
+    }
+
+    /// Freeze an account so it can no longer send or burn tokens (admin or compliance role)
+    pub fn freeze_account(env: Env, caller: Address, account: Address) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        freeze(&env, &account);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit freeze event
+        events::publish(
+            &env,
+            (Symbol::new(&env, FREEZE_EVENT), &account),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Unfreeze a previously frozen account (admin or compliance role)
+    pub fn unfreeze_account(env: Env, caller: Address, account: Address) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        unfreeze(&env, &account);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit unfreeze event
+        events::publish(
+            &env,
+            (Symbol::new(&env, UNFREEZE_EVENT), &account),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Apply freeze, unfreeze, denylist and allow (denylist removal) actions to many
+    /// accounts in one transaction, emitting one event per action (admin or compliance role)
+    pub fn batch_compliance(env: Env, caller: Address, actions: Vec<(Address, Symbol)>) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        // Reject oversized batches before doing any work
+        validate_batch_size(&env, actions.len())?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        apply_compliance_actions(&env, &actions)
+    }
+
+    /// Check if an account is frozen
+    pub fn is_frozen(env: Env, account: Address) -> bool {
+        storage::is_frozen(&env, &account)
+    }
+
+    /// Claw back tokens from a frozen account by burning them (admin or compliance role)
+    pub fn clawback(env: Env, caller: Address, from: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused, unless compliance is pause-immune
+        ensure_not_paused_for_role(&env, COMPLIANCE_ROLE)?;
+        
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        clawback_frozen(&env, &caller, &from, amount)?;
+        
+        // Emit clawback event
+        events::publish(
+            &env,
+            (Symbol::new(&env, CLAWBACK_EVENT), &from),
+            amount
+        );
+        
+        // Emit the finalized burn event by origin
+        publish_finalized_burn(&env, &from, &caller, amount);
+        
+        Ok(())
+    }
+
+    /// Report how supply is split between active and frozen holders as `(active, frozen)`
+    pub fn supply_breakdown(env: Env) -> (i128, i128) {
+        supply_breakdown(&env)
+    }
+
+    /// Exclude an account (treasury, sink, escrow) from the circulating supply or include
+    /// it again (admin only)
+    pub fn set_supply_excluded(env: Env, admin: Address, account: Address, excluded: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        set_supply_excluded(&env, &account, excluded);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "supply_excluded", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether an account is excluded from the circulating supply
+    pub fn is_supply_excluded(env: Env, account: Address) -> bool {
+        storage::is_supply_excluded(&env, &account)
+    }
+
+    /// Get the total supply less the balances of excluded accounts
+    pub fn circulating_supply(env: Env) -> i128 {
+        circulating_supply(&env)
+    }
+
+    /// Add an account to the denylist, blocking it from sending or receiving (admin or compliance role)
+    pub fn add_to_denylist(env: Env, caller: Address, account: Address) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        denylist_add(&env, &account)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit denylist event
+        events::publish(
+            &env,
+            (Symbol::new(&env, DENYLIST_ADD_EVENT), &account),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Migrate a holder who lost their keys to a new address (admin or compliance role).
+    /// The balance, locks and live allowances granted by `old` move to `new`, which must be
+    /// unused and free of compliance flags, and `old` is denylisted. Allowances granted to
+    /// `old` by others are left for their owners to re-grant.
+    pub fn recover_account(env: Env, caller: Address, old: Address, new: Address) -> Result<i128, StablecoinError> {
+        // Check if contract is paused, unless compliance is pause-immune
+        ensure_not_paused_for_role(&env, COMPLIANCE_ROLE)?;
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "recover_account")?;
+        
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let moved = recover_account(&env, &old, &new)?;
+        
+        // Emit recovery event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ACCOUNT_RECOVERED_EVENT), &old),
+            AccountRecovered { old: old.clone(), new }
+        );
+        
+        Ok(moved)
+    }
+
+    /// Remove an account from the denylist (admin or compliance role)
+    pub fn remove_from_denylist(env: Env, caller: Address, account: Address) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        denylist_remove(&env, &account)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit denylist event
+        events::publish(
+            &env,
+            (Symbol::new(&env, DENYLIST_REMOVE_EVENT), &account),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Require every mint and transfer recipient, including each batch and airdrop
+    /// recipient, to be allowlisted (admin only)
+    pub fn set_allowlist_mode(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_allowlist_mode(&env, enabled);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "allowlist_mode", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether recipients must be allowlisted
+    pub fn is_allowlist_mode(env: Env) -> bool {
+        storage::get_allowlist_mode(&env)
+    }
+
+    /// Add an account to the allowlist (admin or compliance role)
+    pub fn allowlist_add(env: Env, caller: Address, account: Address) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        storage::set_allowlisted(&env, &account, true);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit allowlist event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ALLOWLIST_ADD_EVENT), &account),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Remove an account from the allowlist (admin or compliance role)
+    pub fn allowlist_remove(env: Env, caller: Address, account: Address) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        storage::set_allowlisted(&env, &account, false);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit allowlist event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ALLOWLIST_REMOVE_EVENT), &account),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Check whether an account is allowlisted
+    pub fn is_allowlisted(env: Env, account: Address) -> bool {
+        storage::is_allowlisted(&env, &account)
+    }
+
+    /// Check if an account is denylisted, locally or by the sanctions oracle
+    pub fn is_denylisted(env: Env, account: Address) -> bool {
+        is_denylisted(&env, &account)
+    }
+
+    /// Configure a shared sanctions registry whose `is_sanctioned(address)` is consulted
+    /// alongside the local denylist, or clear it with `None` (admin only). Answers are
+    /// cached for a short TTL and an unreachable oracle sanctions the account.
+    pub fn set_denylist_oracle(env: Env, admin: Address, oracle: Option<Address>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_denylist_oracle(&env, oracle.as_ref());
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "denylist_oracle", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the configured sanctions oracle, if any
+    pub fn get_denylist_oracle(env: Env) -> Option<Address> {
+        storage::get_denylist_oracle(&env)
+    }
+
+    /// Get the number of denylisted accounts
+    pub fn denylist_count(env: Env) -> u32 {
+        storage::get_denylist_count(&env)
+    }
+
+    /// Export up to `limit` denylisted accounts starting at index `start` (admin or compliance role)
+    pub fn export_denylist(env: Env, caller: Address, start: u32, limit: u32) -> Result<Vec<Address>, StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        Ok(read_denylist(&env, start, limit))
+    }
+
+    /// Restrict `account` to paying approved counterparties, adding `counterparty` to its list.
+    /// Callable by the account itself or by compliance. An empty list means unrestricted.
+    pub fn add_allowed_counterparty(env: Env, caller: Address, account: Address, counterparty: Address) -> Result<(), StablecoinError> {
+        // Validate the account owner or compliance permissions
+        require_self_or_compliance(&env, &caller, &account)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        add_allowed_counterparty(&env, &account, &counterparty)
+    }
+
+    /// Remove `counterparty` from the account's allowed list (account itself or compliance)
+    pub fn remove_allowed_counterparty(env: Env, caller: Address, account: Address, counterparty: Address) -> Result<(), StablecoinError> {
+        // Validate the account owner or compliance permissions
+        require_self_or_compliance(&env, &caller, &account)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        remove_allowed_counterparty(&env, &account, &counterparty)
+    }
+
+    /// Emit a `low_balance` event when `account`'s balance drops below `threshold`, at most
+    /// once per crossing; 0 removes the alert. Callable by the account itself or by compliance.
+    pub fn set_balance_alert(env: Env, caller: Address, account: Address, threshold: i128) -> Result<(), StablecoinError> {
+        // Validate the account owner or compliance permissions
+        require_self_or_compliance(&env, &caller, &account)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        configure_balance_alert(&env, &account, threshold)
+    }
+
+    /// Get the low balance alert configured for an account
+    pub fn get_balance_alert(env: Env, account: Address) -> Option<BalanceAlert> {
+        storage::get_balance_alert(&env, &account)
+    }
+
+    /// Get the counterparties an account is restricted to (empty means unrestricted)
+    pub fn get_allowed_counterparties(env: Env, account: Address) -> Vec<Address> {
+        storage::get_allowed_counterparties(&env, &account)
+    }
+
+    /// Lock part of an account's balance until `release_ledger` (admin or compliance role)
+    pub fn lock_balance(
+        env: Env,
+        caller: Address,
+        account: Address,
+        amount: i128,
+        release_ledger: u32,
+    ) -> Result<(), StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        add_lock(&env, &account, amount, release_ledger)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit lock event
+        events::publish(
+            &env,
+            (Symbol::new(&env, LOCK_EVENT), &account),
+            (amount, release_ledger)
+        );
+        
+        Ok(())
+    }
+
+    /// Get the sum of all balance locks. Released locks stay counted until consolidated;
+    /// unvested genesis treasury amounts are not included.
+    pub fn total_locked(env: Env) -> i128 {
+        storage::get_total_locked(&env)
+    }
+
+    /// Drop an account's released locks, freeing slots under the per-account lock cap;
+    /// returns how many were removed. Anyone may call this, as it never unlocks funds.
+    pub fn consolidate_locks(env: Env, account: Address) -> u32 {
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        consolidate_locks(&env, &account)
+    }
+
+    /// Set the maximum number of lock entries stored per account (admin only).
+    /// Released locks count until consolidated.
+    pub fn set_max_locks_per_account(env: Env, admin: Address, max_locks: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Validate parameters
+        if max_locks == 0 {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "max_locks_per_account", &admin);
+        
+        storage::set_max_locks_per_account(&env, max_locks);
+        Ok(())
+    }
+
+    /// Get the maximum number of lock entries stored per account
+    pub fn get_max_locks_per_account(env: Env) -> u32 {
+        storage::get_max_locks_per_account(&env)
+    }
+
+    /// Get all balance locks recorded for an account
+    pub fn get_locks(env: Env, account: Address) -> Vec<BalanceLock> {
+        storage::get_locks(&env, &account)
+    }
+
+    /// Get the currently locked (unreleased) portion of an account's balance
+    pub fn locked_balance(env: Env, account: Address) -> i128 {
+        locked_balance(&env, &account)
+    }
+
+    /// Diagnostic: estimate the number of storage entries kept for an account (balance,
+    /// flags, locks, allowance index, ...) for rent and TTL capacity planning
+    pub fn account_storage_footprint(env: Env, account: Address) -> u32 {
+        account_storage_footprint(&env, &account)
+    }
+
+    /// Get every compliance restriction on an account in one read
+    pub fn account_compliance(env: Env, account: Address) -> AccountCompliance {
+        account_compliance(&env, &account)
+    }
+
+    /// Check whether `a` could transfer to `b` right now as far as compliance goes (denylist,
+    /// freeze and counterparty restrictions), ignoring balances and amounts. Sanctions oracle
+    /// answers are taken from the cache only; an account the oracle has not yet been asked
+    /// about is reported as not flagged.
+    pub fn can_transact(env: Env, a: Address, b: Address) -> bool {
+        validate_pair_cached(&env, &a, &b).is_ok()
+    }
+
+    /// Get allowance between two addresses
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Base::allowance(&env, &from, &spender)
+    }
+
+    /// Approve spending allowance. Owners cannot approve themselves; they can transfer directly.
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "approve")?;
+        
+        // Delegated spending can be switched off per deployment
+        require_feature(storage::get_allowances_enabled(&env))?;
+        
+        // Approve allowance
+        write_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Set an allowance on behalf of a managed (custodial) account without its signature
+    /// (admin or compliance role). Returns `FeatureDisabled` unless managed approvals are
+    /// on; every such approval publishes an `AdminApproval` event for audit.
+    pub fn admin_approve(
+        env: Env,
+        caller: Address,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "admin_approve")?;
+        
+        // Delegated spending and managed approvals can be switched off per deployment
+        require_feature(storage::get_allowances_enabled(&env))?;
+        require_feature(storage::get_managed_approvals(&env))?;
+        
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        // Approve allowance
+        write_managed_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit managed approval event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ADMIN_APPROVAL_EVENT), &from, &spender),
+            AdminApproval { by: caller, from: from.clone(), spender: spender.clone(), amount, expiration_ledger }
+        );
+        
+        Ok(())
+    }
+
+    /// Approve `spender` for `amount` with the recommended expiration, sparing callers
+    /// from picking an expiration ledger themselves
+    pub fn approve_for(env: Env, from: Address, spender: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "approve_for")?;
+        
+        // Delegated spending can be switched off per deployment
+        require_feature(storage::get_allowances_enabled(&env))?;
+        
+        // Approve allowance
+        let expiration_ledger = recommended_allowance_expiration(&env);
+        write_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Get the expiration ledger an approval made now should use: the current ledger
+    /// plus the default allowance TTL
+    pub fn recommended_allowance_expiration(env: Env) -> u32 {
+        recommended_allowance_expiration(&env)
+    }
+
+    /// Set how many ledgers allowances written by `approve_for` stay live (admin only)
+    pub fn set_default_allowance_ttl(env: Env, admin: Address, ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "default_allowance_ttl", &admin);
+        
+        update_default_allowance_ttl(&env, ledgers)
+    }
+
+    /// Get how many ledgers allowances written by `approve_for` stay live
+    pub fn get_default_allowance_ttl(env: Env) -> u32 {
+        storage::get_default_allowance_ttl(&env)
+    }
+
+    /// Set how many ledgers ahead an allowance may expire, rejecting longer approvals with
+    /// `InvalidParameters`; 0 disables the limit (admin only)
+    pub fn set_max_allowance_ttl(env: Env, admin: Address, ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "max_allowance_ttl", &admin);
+        
+        update_max_allowance_ttl(&env, ledgers)
+    }
+
+    /// Get how many ledgers ahead an allowance may expire; 0 means no limit
+    pub fn get_max_allowance_ttl(env: Env) -> u32 {
+        storage::get_max_allowance_ttl(&env)
+    }
+
+    /// Top up `spender`'s allowance to `min_amount` only if the current live allowance is
+    /// below it. Returns whether the allowance was written.
+    pub fn ensure_allowance(env: Env, from: Address, spender: Address, min_amount: i128, expiration_ledger: u32) -> Result<bool, StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "ensure_allowance")?;
+        
+        // Delegated spending can be switched off per deployment
+        require_feature(storage::get_allowances_enabled(&env))?;
+        
+        if min_amount < 0 {
+            return Err(StablecoinError::InvalidAmount);
+        }
+        
+        // Nothing to do if the current allowance already covers it (still owner-authorized)
+        if Base::allowance(&env, &from, &spender) >= min_amount {
+            from.require_auth();
+            return Ok(false);
+        }
+        
+        // Top up the allowance (requires the owner's authorization)
+        write_allowance(&env, &from, &spender, min_amount, expiration_ledger)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(true)
+    }
+
+    /// Revoke the allowances a denylisted or frozen spender holds from the given owners
+    /// (admin or compliance role). Returns the number of allowances revoked.
+    pub fn revoke_spender_allowances(env: Env, caller: Address, spender: Address, owners: Vec<Address>) -> Result<u32, StablecoinError> {
+        // Validate compliance permissions
+        require_compliance(&env, &caller)?;
+        
+        // Bound the work done in one call
+        validate_batch_size(&env, owners.len())?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        revoke_flagged_spender(&env, &spender, &owners)
+    }
+
+    /// Set how many ledgers past its expiration an allowance remains usable by
+    /// `transfer_from`, to absorb races at the boundary (admin only). Zero, the default,
+    /// disables the grace window.
+    pub fn set_allowance_grace(env: Env, admin: Address, ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "allowance_grace", &admin);
+        
+        update_allowance_grace(&env, ledgers)
+    }
+
+    /// Get the allowance grace window in ledgers
+    pub fn get_allowance_grace(env: Env) -> u32 {
+        storage::get_allowance_grace_ledgers(&env)
+    }
+
+    /// Pre-validate a `transfer_from` without executing it, returning the first reason it would fail
+    pub fn can_spend(env: Env, spender: Address, from: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+        validate_spend(&env, &spender, &from, &to, amount)
+    }
+
+    /// Get the largest amount `spender` could move from `from` via `transfer_from` right now,
+    /// accounting for the live allowance as well as the owner's freezes and locks
+    pub fn max_transferable_from(env: Env, spender: Address, from: Address) -> i128 {
+        max_transferable_from(&env, &spender, &from)
+    }
+
+    /// Get every live allowance an owner has granted, as (spender, amount) pairs
+    pub fn get_allowances(env: Env, owner: Address) -> Vec<(Address, i128)> {
+        live_allowances(&env, &owner)
+    }
+
+    /// Get the sum of all live allowances an owner has granted
+    pub fn total_approved(env: Env, owner: Address) -> i128 {
+        total_approved(&env, &owner)
+    }
+
+    /// Rebuild the spender index from stored allowances for a page of (owner, spender)
+    /// pairs, so `get_allowances` and `total_approved` cover allowances written before the
+    /// index existed (admin only). Idempotent; returns how many pairs hold a live allowance.
+    pub fn reindex_allowances(env: Env, admin: Address, entries: Vec<(Address, Address)>) -> Result<u32, StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        let live = reindex_allowances(&env, &entries)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(live)
+    }
+
+    /// Get token name
+    pub fn name(env: Env) -> String {
+        Base::name(&env)
+    }
+
+    /// Get token symbol
+    pub fn symbol(env: Env) -> String {
+        Base::symbol(&env)
+    }
+
+    /// Get token decimals
+    pub fn decimals(env: Env) -> u32 {
+        Base::decimals(&env)
+    }
+
+    /// Whether muxed (`M...`) addresses are accepted. Always false: they are rejected by
+    /// the host rather than accepted with their memo id dropped.
+    pub fn supports_muxed(_env: Env) -> bool {
+        SUPPORTS_MUXED_ADDRESSES
+    }
+
+    /// Get the total supply as `(whole major units, remaining base units)` under the token
+    /// decimals, e.g. `(1234, 56)` for 1234.56 at 2 decimals
+    pub fn total_supply_major(env: Env) -> (i128, u64) {
+        split_major_units(Base::total_supply(&env), Base::decimals(&env))
+    }
+
+    /// Get the ISO-4217-style currency code wallets should display (e.g. `CRC`)
+    pub fn currency(env: Env) -> Symbol {
+        storage::get_currency(&env)
+    }
+
+    /// Set the decimals amounts are floored to in memo display strings, e.g. 2 to show
+    /// a 7-decimal token in cents; cannot exceed the token decimals (admin only)
+    pub fn set_display_decimals(env: Env, admin: Address, decimals: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "display_decimals", &admin);
+        
+        update_display_decimals(&env, decimals)
+    }
+
+    /// Get the decimals shown in display amounts
+    pub fn get_display_decimals(env: Env) -> u32 {
+        storage::get_display_decimals(&env).unwrap_or(Base::decimals(&env))
+    }
+
+    /// Set the display currency code; must be 3-4 uppercase letters (admin only)
+    pub fn set_currency(env: Env, admin: Address, currency: String) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        let currency = parse_currency_code(&env, &currency)?;
+        storage::set_currency(&env, &currency);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "currency", &admin);
+        
+        Ok(())
+    }
+
+    /// Point wallets at the off-chain token metadata JSON (logo, description); must be
+    /// non-empty and at most `MAX_TOKEN_URI_LENGTH` bytes (admin only)
+    pub fn set_token_uri(env: Env, admin: Address, uri: String) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        validate_token_uri(&uri)?;
+        storage::set_token_uri(&env, &uri);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "token_uri", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the token metadata URI, empty if none has been set
+    pub fn token_uri(env: Env) -> String {
+        storage::get_token_uri(&env)
+    }
+
+    /// Get total supply
+    pub fn total_supply(env: Env) -> Result<i128, StablecoinError> {
+        // Refuse possibly stale reads during a lockdown
+        ensure_reads_available(&env)?;
+        
+        Ok(Base::total_supply(&env))
+    }
+
+    /// Check if contract is paused
+    pub fn is_paused(env: Env) -> bool {
+        pausable::paused(&env)
+    }
+
+    /// Get supply, mint/burn totals, active holders and outstanding allowances for monitoring.
+    /// In private stats mode `caller` must be the admin or hold the compliance role.
+    pub fn get_stats(env: Env, caller: Address) -> Result<TokenStats, StablecoinError> {
+        // Validate compliance permissions when stats are private
+        if storage::get_private_stats(&env) {
+            require_compliance(&env, &caller)?;
+        }
+        
+        Ok(token_stats(&env))
+    }
+
+    /// Restrict detailed token stats to the admin and compliance role, or make them
+    /// public again; `total_supply` is always public (admin only)
+    pub fn set_private_stats(env: Env, admin: Address, private: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_private_stats(&env, private);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "private_stats", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether detailed token stats are private
+    pub fn is_private_stats(env: Env) -> bool {
+        storage::get_private_stats(&env)
+    }
+
+    /// Migration helper: count accounts that held a balance before holder tracking was
+    /// introduced. Accounts already counted or with no balance are skipped, so repeated
+    /// calls are safe. Returns how many accounts were newly counted (admin only).
+    pub fn backfill_holders(env: Env, admin: Address, accounts: Vec<Address>) -> Result<u32, StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Bound the work done in one call
+        validate_batch_size(&env, accounts.len())?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(backfill_holder_index(&env, &accounts))
+    }
+
+    /// Verify that total supply equals the tracked total minted less total burned,
+    /// failing with `SupplyMismatch` if the accounting has drifted
+    pub fn reconcile_supply(env: Env) -> Result<bool, StablecoinError> {
+        reconcile_supply(&env)
+    }
+
+    /// Full-lockdown switch for extreme incidents (admin only). While enabled, `balance`,
+    /// `balance_of`, `spendable_balance` and `total_supply` fail with `Paused` instead of
+    /// returning data that may be stale, e.g. mid-migration. Off by default.
+    pub fn set_read_lockdown(env: Env, admin: Address, enabled: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_read_lockdown(&env, enabled);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "read_lockdown", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether a read lockdown is active
+    pub fn is_read_lockdown(env: Env) -> bool {
+        storage::get_read_lockdown(&env)
+    }
+
+    /// Single cheap health check for monitoring: "ok", "paused", "emergency" (paused with
+    /// allowances frozen) or "uninitialized"
+    pub fn health(env: Env) -> Symbol {
+        health(&env)
+    }
+
+    /// Single authoritative lifecycle position, the first that applies of "uninitialized",
+    /// "emergency", "paused", "supply_frozen", "recovery_pending", "upgrade_locked" and
+    /// "active"
+    pub fn lifecycle_state(env: Env) -> Symbol {
+        lifecycle_state(&env)
+    }
+
+    /// Set the roles whose methods (clawback, recovery and other compliance actions) keep
+    /// working during a general pause; minting always stops, and nothing is immune to an
+    /// emergency freeze (admin only)
+    pub fn set_pause_immune_roles(env: Env, admin: Address, roles: Vec<Symbol>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "pause_immune_roles", &admin);
+        
+        update_pause_immune_roles(&env, &roles)
+    }
+
+    /// Get the roles whose methods keep working during a general pause
+    pub fn pause_immune_roles(env: Env) -> Vec<Symbol> {
+        storage::get_pause_immune_roles(&env)
+    }
+
+    /// Get the contract's pause status
+    pub fn pause_status(env: Env) -> PauseStatus {
+        pause_utils::get_pause_status(&env)
+    }
+
+    /// List the admin, the fee collector and every role member with their roles,
+    /// for a one-call deployment audit. Fails in private roles mode; see `system_overview_as`.
+    pub fn system_overview(env: Env) -> Vec<(Address, Vec<Symbol>)> {
+        ensure_roles_public(&env);
+        system_overview(&env)
+    }
+
+    /// `system_overview` for a named reader, who in private roles mode must be the admin
+    /// or hold the compliance role
+    pub fn system_overview_as(env: Env, caller: Address) -> Result<Vec<(Address, Vec<Symbol>)>, StablecoinError> {
+        // Validate read permissions when roles are private
+        require_role_reader(&env, &caller)?;
+        
+        Ok(system_overview(&env))
+    }
+
+    /// Get the caller's own address and the labels it holds (admin and roles) for permission
+    /// UIs. Soroban does not expose the invoker, so the caller passes its address and proves
+    /// it by authorizing the call; this also lets it read its own roles in private roles mode.
+    pub fn whoami(env: Env, caller: Address) -> (Address, Vec<Symbol>) {
+        caller.require_auth();
+        
+        let roles = roles_of(&env, &caller);
+        (caller, roles)
+    }
+
+    /// Get every configured privileged and integration address in one read: the admin,
+    /// a pending admin nominee, the recovery address, the fee collector and the settlement
+    /// contract. In private roles mode `caller` must be the admin or hold the compliance role.
+    pub fn system_addresses(env: Env, caller: Address) -> Result<SystemAddresses, StablecoinError> {
+        // Validate read permissions when roles are private
+        require_role_reader(&env, &caller)?;
+        
+        Ok(system_addresses(&env))
+    }
+
+    /// Check if address has a specific role
+    pub fn has_role_minter(env: Env, address: Address) -> bool {
+        ensure_roles_public(&env);
+        let role_symbol = Symbol::new(&env, MINTER_ROLE);
+        access_control::has_role(&env, &address, &role_symbol).is_some()
+    }
+
+    /// Check whether `address` holds `role` on behalf of a named reader, who in private
+    /// roles mode must be the admin or hold the compliance role
+    pub fn has_role_as(env: Env, caller: Address, address: Address, role: Symbol) -> Result<bool, StablecoinError> {
+        // Validate read permissions when roles are private
+        require_role_reader(&env, &caller)?;
+        
+        Ok(access_control::has_role(&env, &address, &role).is_some())
+    }
+
+    /// Check if address is the admin
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        ensure_roles_public(&env);
+        is_admin(&env, &address)
+    }
+
+    /// Check if address has pauser role
+    pub fn has_role_pauser(env: Env, address: Address) -> bool {
+        ensure_roles_public(&env);
+        let role_symbol = Symbol::new(&env, PAUSER_ROLE);
+        access_control::has_role(&env, &address, &role_symbol).is_some()
+    }
+
+    /// Check if address has upgrader role
+    pub fn has_role_upgrader(env: Env, address: Address) -> bool {
+        ensure_roles_public(&env);
+        let role_symbol = Symbol::new(&env, UPGRADER_ROLE);
+        access_control::has_role(&env, &address, &role_symbol).is_some()
+    }
+
+    /// Restrict admin and role membership reads to the admin and compliance role, or make
+    /// them public again (admin only)
+    pub fn set_private_roles(env: Env, admin: Address, private: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_private_roles(&env, private);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "private_roles", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether admin and role membership reads are private
+    pub fn is_private_roles(env: Env) -> bool {
+        storage::get_private_roles(&env)
+    }
+
+    /// Replace the contract code with the uploaded wasm `new_wasm_hash` (upgrader role).
+    /// Refused for everyone once upgrades are locked.
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), StablecoinError> {
+        // Validate upgrader role and the upgrade lock
+        require_upgrader(&env, &caller)?;
+        
+        // Honor the admin action timelock, if enabled
+        require_timelock(&env, "upgrade", vec![&env, new_wasm_hash.into_val(&env)])?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        
+        // Run the post-upgrade migration; a failure reverts the upgrade
+        run_post_upgrade_hook(&env, &new_wasm_hash)?;
+        
+        Ok(())
+    }
+
+    /// Configure the contract whose `on_upgrade(new_wasm_hash)` is called at the end of every
+    /// upgrade, or clear it with `None` (admin only). A failing hook reverts the upgrade.
+    pub fn set_post_upgrade_hook(env: Env, admin: Address, hook: Option<Address>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Honor the admin action timelock, if enabled; the hook runs with every upgrade
+        require_timelock(&env, "set_post_upgrade_hook", vec![&env, hook.clone().into_val(&env)])?;
+        
+        storage::set_post_upgrade_hook(&env, hook.as_ref());
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "post_upgrade_hook", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the configured post-upgrade hook, if any
+    pub fn get_post_upgrade_hook(env: Env) -> Option<Address> {
+        storage::get_post_upgrade_hook(&env)
+    }
+
+    /// Compute the hash identifying a timelocked call: `action` is the method name
+    /// (`set_max_supply`, `set_fee_config`, `set_fee_tiers`, `set_config`, `set_timelock_delay`,
+    /// `set_post_upgrade_hook` or `upgrade`) and `args` its arguments after the caller
+    pub fn action_hash(env: Env, action: Symbol, args: Vec<Val>) -> BytesN<32> {
+        action_hash(&env, &action, args)
+    }
+
+    /// Schedule a timelocked admin action for `eta`, at least the timelock delay from now,
+    /// giving users advance notice of the change (admin only)
+    pub fn schedule_action(env: Env, admin: Address, action_hash: BytesN<32>, eta: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        schedule_action(&env, &action_hash, eta)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit scheduling event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ACTION_SCHEDULED_EVENT), &action_hash),
+            eta
+        );
+        
+        Ok(())
+    }
+
+    /// Execute a scheduled action once its eta has passed, allowing exactly one matching
+    /// call to the guarded method (admin only)
+    pub fn execute_action(env: Env, admin: Address, action_hash: BytesN<32>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        execute_action(&env, &action_hash)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit execution event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ACTION_EXECUTED_EVENT), &action_hash),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Cancel a scheduled action (admin only)
+    pub fn cancel_action(env: Env, admin: Address, action_hash: BytesN<32>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        cancel_action(&env, &action_hash)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit cancellation event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ACTION_CANCELLED_EVENT), &action_hash),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Get a scheduled timelocked action
+    pub fn get_scheduled_action(env: Env, action_hash: BytesN<32>) -> Option<ScheduledAction> {
+        storage::get_scheduled_action(&env, &action_hash)
+    }
+
+    /// Set the minimum ledgers between scheduling a timelocked action and its eta (admin only)
+    pub fn set_timelock_delay(env: Env, admin: Address, ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Honor the admin action timelock, if enabled, so the delay cannot be waived in one call
+        require_timelock(&env, "set_timelock_delay", vec![&env, ledgers.into_val(&env)])?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "timelock_delay", &admin);
+        
+        storage::set_timelock_delay(&env, ledgers);
+        Ok(())
+    }
+
+    /// Get the minimum ledgers between scheduling a timelocked action and its eta
+    pub fn get_timelock_delay(env: Env) -> u32 {
+        storage::get_timelock_delay(&env)
+    }
+
+    /// Permanently disable `upgrade`, committing to the current code. This cannot be undone (admin only).
+    pub fn lock_upgrades(env: Env, admin: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::lock_upgrades(&env);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "upgrades_locked", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether upgrades have been permanently disabled
+    pub fn upgrades_locked(env: Env) -> bool {
+        storage::get_upgrades_locked(&env)
+    }
+
+    /// Signal whether a holder consents to future upgrades. Purely informational for
+    /// governance transparency: upgrades are never blocked by dissent.
+    pub fn set_upgrade_consent(env: Env, holder: Address, consent: bool) -> Result<(), StablecoinError> {
+        // Authenticate the holder
+        holder.require_auth();
+        
+        record_upgrade_consent(&env, &holder, consent)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Get a holder's upgrade consent signal, if they have given one
+    pub fn upgrade_consent(env: Env, holder: Address) -> Option<bool> {
+        storage::get_upgrade_consent(&env, &holder)
+    }
+
+    /// Get how many holders signal `(consenting, dissenting)` to future upgrades
+    pub fn upgrade_consent_tally(env: Env) -> (u32, u32) {
+        storage::get_upgrade_consent_tally(&env)
+    }
+
+    /// Get admin address. Fails in private roles mode; see `get_admin_as`.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ensure_roles_public(&env);
+        access_control::get_admin(&env)
+    }
+
+    /// `get_admin` for a named reader, who in private roles mode must be the admin or hold
+    /// the compliance role
+    pub fn get_admin_as(env: Env, caller: Address) -> Result<Option<Address>, StablecoinError> {
+        // Validate read permissions when roles are private
+        require_role_reader(&env, &caller)?;
+        
+        Ok(access_control::get_admin(&env))
+    }
+
+    /// Grant a role to an account (admin only). Frozen or denylisted accounts
+    /// cannot be granted roles.
+    pub fn grant_role(env: Env, admin: Address, account: Address, role: Symbol) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        validate_no_pending_recovery(&env)?;
+        
+        // Validate role and grantee
+        validate_role_symbol(&env, &role)?;
+        validate_role_grantee(&env, &account)?;
+        
+        access_control::grant_role_no_auth(&env, &admin, &account, &role);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Grant several roles in one call (admin only). The whole batch is validated first, so
+    /// an unknown role, a duplicate grant or a compliance-flagged grantee rejects it entirely.
+    pub fn batch_grant_roles(env: Env, admin: Address, grants: Vec<(Address, Symbol)>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        validate_no_pending_recovery(&env)?;
+        
+        // Validate every grant before applying any
+        validate_role_grants(&env, &grants)?;
+        
+        for (account, role) in grants.iter() {
+            access_control::grant_role_no_auth(&env, &admin, &account, &role);
+        }
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Revoke a role from an account (admin only). Revoking the last minter, pauser or
+    /// upgrader is rejected unless `force` is set, to avoid locking those functions out.
+    pub fn revoke_role(env: Env, admin: Address, account: Address, role: Symbol, force: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        validate_no_pending_recovery(&env)?;
+        
+        revoke_role(&env, &admin, &account, &role, force)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Cap the remaining amount a minter may mint, or clear the cap with `None` (admin only)
+    pub fn set_minter_allowance(env: Env, admin: Address, minter: Address, allowance: Option<i128>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        if allowance.is_some_and(|amount| amount < 0) {
+            return Err(StablecoinError::InvalidAmount);
+        }
+        storage::set_minter_allowance(&env, &minter, allowance);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "minter_allowance", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the remaining amount a minter may mint, or `None` if unlimited
+    pub fn minter_allowance(env: Env, minter: Address) -> Option<i128> {
+        storage::get_minter_allowance(&env, &minter)
+    }
+
+    /// Get the limits that apply to `caller` right now, to explain why an operation
+    /// might be rejected
+    pub fn effective_limits(env: Env, caller: Address) -> EffectiveLimits {
+        effective_limits(&env, &caller)
+    }
+
+    /// Get the `(min, max)` amounts `caller` can pass to `op` ("mint", "transfer" or "burn")
+    /// right now, so clients can clamp inputs. A `max` below `min` means the operation
+    /// cannot currently succeed; other operations return `InvalidParameters`.
+    pub fn operation_bounds(env: Env, op: Symbol, caller: Address) -> Result<(i128, i128), StablecoinError> {
+        operation_bounds(&env, &op, &caller)
+    }
+
+    /// Key-compromise response: atomically revoke the compromised minter's role, zero its
+    /// allowance and denylist it, then grant the role and remaining allowance to `replacement`
+    /// (admin only)
+    pub fn emergency_rotate_minter(env: Env, admin: Address, compromised: Address, replacement: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        rotate_minter(&env, &admin, &compromised, &replacement)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit rotation event
+        events::publish(
+            &env,
+            (Symbol::new(&env, MINTER_ROTATED_EVENT), &compromised, &replacement),
+            ()
+        );
+        emit_emergency_action(&env, MINTER_ROTATION_KIND, &admin);
+        
+        Ok(())
+    }
+
+    /// Configure the fallback recovery address and its timelock (admin only)
+    pub fn set_recovery_config(env: Env, admin: Address, recovery: Address, delay_ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "recovery_config", &admin);
+        
+        configure_recovery(&env, &recovery, delay_ledgers)
+    }
+
+    /// Get the configured recovery address and timelock in ledgers
+    pub fn get_recovery_config(env: Env) -> Option<(Address, u32)> {
+        storage::get_recovery_config(&env)
+    }
+
+    /// Block minting, role changes and admin transfers with `RecoveryPending` while a
+    /// recovery is pending, until it is cancelled or claimed (admin only)
+    pub fn set_restrict_during_recovery(env: Env, admin: Address, restrict: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_restrict_during_recovery(&env, restrict);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "restrict_during_recovery", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether privileged operations are restricted while a recovery is pending
+    pub fn restrict_during_recovery(env: Env) -> bool {
+        storage::get_restrict_during_recovery(&env)
+    }
+
+    /// Add a guardian allowed to initiate recovery (admin only)
+    pub fn add_guardian(env: Env, admin: Address, guardian: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        add_guardian(&env, &guardian)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "guardians", &admin);
+        
+        Ok(())
+    }
+
+    /// Remove a recovery guardian (admin only)
+    pub fn remove_guardian(env: Env, admin: Address, guardian: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        remove_guardian(&env, &guardian)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "guardians", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the recovery guardians
+    pub fn get_guardians(env: Env) -> Vec<Address> {
+        storage::get_guardians(&env)
+    }
+
+    /// Start the admin recovery timelock (guardian only). Returns the ledger at which
+    /// the recovery address may claim the admin role.
+    pub fn initiate_recovery(env: Env, guardian: Address) -> Result<u32, StablecoinError> {
+        let claimable_at = initiate_recovery(&env, &guardian)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit recovery event
+        events::publish(
+            &env,
+            (Symbol::new(&env, RECOVERY_INITIATED_EVENT), &guardian),
+            claimable_at
+        );
+        
+        Ok(claimable_at)
+    }
+
+    /// Cancel a pending recovery at any time before it is claimed (admin only)
+    pub fn cancel_recovery(env: Env, admin: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        if storage::get_recovery_claimable_at(&env).is_none() {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        storage::set_recovery_claimable_at(&env, None);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit recovery event
+        events::publish(
+            &env,
+            (Symbol::new(&env, RECOVERY_CANCELLED_EVENT), &admin),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Claim the admin role once the recovery timelock has expired (recovery address only)
+    pub fn claim_admin(env: Env, recovery: Address) -> Result<(), StablecoinError> {
+        claim_recovered_admin(&env, &recovery)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit recovery event
+        events::publish(
+            &env,
+            (Symbol::new(&env, RECOVERY_CLAIMED_EVENT), &recovery),
+            ()
+        );
+        emit_emergency_action(&env, RECOVERY_CLAIM_KIND, &recovery);
+        
+        Ok(())
+    }
+
+    /// Start a two-step admin transfer by nominating `new_admin`, who must accept it with
+    /// `accept_admin` (admin only). A new nomination replaces any pending one.
+    pub fn transfer_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        validate_no_pending_recovery(&env)?;
+        
+        nominate_admin(&env, &new_admin);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ADMIN_TRANSFER_STARTED_EVENT), &admin),
+            new_admin
+        );
+        
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer (nominated admin only)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), StablecoinError> {
+        accept_pending_admin(&env, &new_admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Emit transfer event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ADMIN_TRANSFERRED_EVENT), &new_admin),
+            ()
+        );
+        
+        Ok(())
+    }
+
+    /// Get the admin nominated by a pending two-step admin transfer
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        storage::get_pending_admin(&env)
+    }
+
+    /// Set protocol-wide caps on the total minted and total burned within a single ledger;
+    /// 0 leaves the corresponding flow uncapped (admin only)
+    pub fn set_global_flow_caps(env: Env, admin: Address, mint_cap: i128, burn_cap: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "flow_caps", &admin);
+        
+        configure_flow_caps(&env, mint_cap, burn_cap)
+    }
+
+    /// Apply every tunable setting at once, so a reconfiguration never passes through
+    /// inconsistent intermediate states. An invalid config is rejected wholesale (admin only).
+    pub fn set_config(env: Env, admin: Address, config: StablecoinConfig) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Honor the admin action timelock, if enabled
+        require_timelock(&env, "set_config", vec![&env, config.clone().into_val(&env)])?;
+        
+        apply_config(&env, &config)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change as a single batch
+        emit_config_changed(&env, "config", &admin);
+        
+        Ok(())
+    }
+
+    /// Get every tunable setting
+    pub fn get_config(env: Env) -> StablecoinConfig {
+        current_config(&env)
+    }
+
+    /// Set the maximum total supply; it cannot be below the current supply
+    /// or above the compiled-in MAX_SUPPLY (admin only)
+    pub fn set_max_supply(env: Env, admin: Address, max_supply: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Honor the admin action timelock, if enabled
+        require_timelock(&env, "set_max_supply", vec![&env, max_supply.into_val(&env)])?;
+        
+        update_max_supply(&env, max_supply)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "max_supply", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the maximum total supply
+    pub fn get_max_supply(env: Env) -> i128 {
+        storage::get_max_supply(&env)
+    }
+
+    /// Set the supply off-chain keepers steer toward by minting or burning. This is
+    /// monitoring metadata only; the contract never acts on it (admin only)
+    pub fn set_target_supply(env: Env, admin: Address, target: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        if target < 0 {
+            return Err(StablecoinError::InvalidAmount);
+        }
+        storage::set_target_supply(&env, target);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "target_supply", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the target supply
+    pub fn get_target_supply(env: Env) -> i128 {
+        storage::get_target_supply(&env)
+    }
+
+    /// Get `total_supply - target_supply`: positive when over target, negative when under
+    pub fn supply_deviation(env: Env) -> Result<i128, StablecoinError> {
+        // Refuse possibly stale reads during a lockdown
+        ensure_reads_available(&env)?;
+        
+        Ok(Base::total_supply(&env) - storage::get_target_supply(&env))
+    }
+
+    /// Record an attested deposit into the fiat reserves backing the token (admin only).
+    /// Returns `FeatureDisabled` unless backing is enabled.
+    pub fn deposit(env: Env, admin: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check the backing feature is enabled
+        require_feature(ENABLE_BACKING)?;
+        
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        deposit_backing(&env, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Record an attested withdrawal from the fiat reserves (admin only).
+    /// Returns `FeatureDisabled` unless backing is enabled.
+    pub fn withdraw(env: Env, admin: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Check the backing feature is enabled
+        require_feature(ENABLE_BACKING)?;
+        
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        withdraw_backing(&env, amount)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        Ok(())
+    }
+
+    /// Set the decimals deposits and withdrawals are denominated in when the backing asset
+    /// differs from the token (admin only). Returns `FeatureDisabled` unless backing is enabled.
+    pub fn set_backing_decimals(env: Env, admin: Address, decimals: u32) -> Result<(), StablecoinError> {
+        // Check the backing feature is enabled
+        require_feature(ENABLE_BACKING)?;
+        
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Validate parameters
+        if decimals > MAX_DECIMALS {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "backing_decimals", &admin);
+        
+        storage::set_backing_decimals(&env, decimals);
+        Ok(())
+    }
+
+    /// Get the decimals deposits and withdrawals are denominated in
+    pub fn backing_decimals(env: Env) -> u32 {
+        storage::get_backing_decimals(&env)
+    }
+
+    /// Get the attested fiat reserves. Returns `FeatureDisabled` unless backing is enabled.
+    pub fn backing_reserve(env: Env) -> Result<i128, StablecoinError> {
+        // Check the backing feature is enabled
+        require_feature(ENABLE_BACKING)?;
+        
+        Ok(storage::get_backing_reserve(&env))
+    }
+
+    /// Require every mint, batch and airdrop included, to be covered by attested reserves,
+    /// or return to permissive mode where treasury pre-minting is allowed (admin only).
+    /// Strict mode can only be enabled while the reserves cover the supply, and then also
+    /// blocks withdrawals below it. Returns `FeatureDisabled` unless backing is enabled.
+    pub fn set_strict_backing(env: Env, admin: Address, strict: bool) -> Result<(), StablecoinError> {
+        // Check the backing feature is enabled
+        require_feature(ENABLE_BACKING)?;
+        
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        update_strict_backing(&env, strict)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "strict_backing", &admin);
+        
+        Ok(())
+    }
+
+    /// Check whether every mint must be covered by attested reserves
+    pub fn is_strict_backing(env: Env) -> bool {
+        storage::get_strict_backing(&env)
+    }
+
+    /// Block mints, and reserve withdrawals, that would leave the attested reserves below
+    /// `ratio_bps` of the supply; 0 stops enforcing a ratio and 10000 matches strict backing
+    /// (admin only). Returns `FeatureDisabled` unless backing is enabled.
+    pub fn set_min_reserve_ratio(env: Env, admin: Address, ratio_bps: u32) -> Result<(), StablecoinError> {
+        // Check the backing feature is enabled
+        require_feature(ENABLE_BACKING)?;
+        
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        configure_min_reserve_ratio(&env, ratio_bps)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "min_reserve_ratio", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the minimum reserve ratio in basis points, 0 when not enforced
+    pub fn min_reserve_ratio_bps(env: Env) -> u32 {
+        storage::get_min_reserve_ratio(&env)
+    }
+
+    /// Get the attested reserves as a share of total supply in basis points
+    /// (`i128::MAX` while there is no supply)
+    pub fn current_reserve_ratio_bps(env: Env) -> i128 {
+        current_reserve_ratio_bps(&env)
+    }
+
+    /// Set how many ledgers must pass between mints to the same recipient;
+    /// 0 disables the cooldown (admin only)
+    pub fn set_mint_cooldown(env: Env, admin: Address, ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "mint_cooldown", &admin);
+        
+        storage::set_mint_cooldown_ledgers(&env, ledgers);
+        Ok(())
+    }
+
+    /// Get the per-recipient mint cooldown in ledgers
+    pub fn get_mint_cooldown(env: Env) -> u32 {
+        storage::get_mint_cooldown_ledgers(&env)
+    }
+
+    /// Set how many ledgers an account must hold received tokens before they can be
+    /// transferred; 0 disables the holding period (admin only). Each receipt is held as a
+    /// balance lock on the amount received, so earlier holdings stay movable.
+    pub fn set_hold_period(env: Env, admin: Address, ledgers: u32) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "hold_period", &admin);
+        
+        storage::set_hold_period_ledgers(&env, ledgers);
+        Ok(())
+    }
+
+    /// Get the holding period in ledgers
+    pub fn get_hold_period(env: Env) -> u32 {
+        storage::get_hold_period_ledgers(&env)
+    }
+
+    /// Get the protocol-wide per-ledger (mint_cap, burn_cap)
+    pub fn get_global_flow_caps(env: Env) -> (i128, i128) {
+        storage::get_flow_caps(&env)
+    }
+
+    /// Get the amounts minted and burned so far in the current ledger
+    pub fn get_ledger_flow(env: Env) -> LedgerFlow {
+        current_ledger_flow(&env)
+    }
+
+    /// Get the gross transfer volume in the current monitoring window and the ledger the
+    /// window started at. Windows are `VOLUME_WINDOW_LEDGERS` long and reset at each boundary.
+    pub fn transfer_volume_window(env: Env) -> (i128, u32) {
+        current_transfer_volume(&env)
+    }
+
+    /// Move tokens into contract-held escrow for `beneficiary`, releasable once
+    /// `release_ledger` has passed. Returns the escrow id.
+    pub fn escrow_deposit(env: Env, from: Address, beneficiary: Address, amount: i128, release_ledger: u32) -> Result<u64, StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "escrow_deposit")?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Validate and move the tokens into escrow (requires the depositor's authorization)
+        let id = open_escrow(&env, &from, &beneficiary, amount, release_ledger)?;
+        
+        // Emit escrow event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ESCROW_DEPOSIT_EVENT), &from, &beneficiary),
+            (id, amount, release_ledger)
+        );
+        
+        Ok(id)
+    }
+
+    /// Pay an escrow out to its beneficiary once its release ledger has passed (anyone may call)
+    pub fn escrow_release(env: Env, id: u64) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "escrow_release")?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let escrow = release_escrow(&env, id)?;
+        
+        // Emit escrow event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ESCROW_RELEASE_EVENT), &escrow.beneficiary),
+            (id, escrow.amount)
+        );
+        
+        Ok(())
+    }
+
+    /// Return an escrow to its depositor before its release ledger (depositor only)
+    pub fn escrow_refund(env: Env, id: u64) -> Result<(), StablecoinError> {
+        // Check if contract is paused
+        if pausable::paused(&env) {
+            return Err(StablecoinError::Paused);
+        }
+        
+        // Check if this method is individually paused
+        ensure_method_not_paused(&env, "escrow_refund")?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        let escrow = refund_escrow(&env, id)?;
+        
+        // Emit escrow event
+        events::publish(
+            &env,
+            (Symbol::new(&env, ESCROW_REFUND_EVENT), &escrow.depositor),
+            (id, escrow.amount)
+        );
+        
+        Ok(())
+    }
+
+    /// Get an open escrow by id
+    pub fn get_escrow(env: Env, id: u64) -> Option<Escrow> {
+        storage::get_escrow(&env, id)
+    }
+
+    /// Get the sum of all open escrows
+    pub fn total_escrowed(env: Env) -> i128 {
+        storage::get_total_escrowed(&env)
+    }
+
+    /// Configure the percentage transfer fee (at most `MAX_FEE_BPS`), its collector and an
+    /// absolute cap per transfer; `bps == 0` disables fees and `max_fee_absolute == 0` means
+    /// no cap (admin only). The fee is carved out of the transferred amount, so the
+    /// recipient's net plus the fee always equals the amount sent.
+    pub fn set_fee_config(env: Env, admin: Address, bps: u32, collector: Address, max_fee_absolute: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Honor the admin action timelock, if enabled
+        require_timelock(
+            &env,
+            "set_fee_config",
+            vec![&env, bps.into_val(&env), collector.into_val(&env), max_fee_absolute.into_val(&env)],
+        )?;
+        
+        configure_fees(&env, bps, &collector, max_fee_absolute)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "fee_config", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the transfer fee configuration, if fees are enabled
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        storage::get_fee_config(&env)
+    }
+
+    /// Set a tiered fee schedule of ascending `(threshold, bps)` bands: a transfer pays the
+    /// rate of the highest threshold it reaches, and the flat `bps` of the fee config below
+    /// the first one. An empty schedule returns to the flat rate. The collector and
+    /// absolute cap still come from the fee config (admin only).
+    pub fn set_fee_tiers(env: Env, admin: Address, tiers: Vec<(i128, u32)>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Honor the admin action timelock, if enabled
+        require_timelock(&env, "set_fee_tiers", vec![&env, tiers.into_val(&env)])?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "fee_tiers", &admin);
+        
+        configure_fee_tiers(&env, &tiers)
+    }
+
+    /// Get the tiered fee schedule; empty when the flat rate applies
+    pub fn get_fee_tiers(env: Env) -> Vec<(i128, u32)> {
+        storage::get_fee_tiers(&env)
+    }
+
+    /// Choose whether transfer fees are routed to the collector or burned, reducing supply
+    /// (admin only). Burned fees count toward `total_burned`; during a supply freeze they
+    /// are routed instead.
+    pub fn set_fee_disposition(env: Env, admin: Address, disposition: FeeDisposition) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        storage::set_fee_disposition(&env, disposition);
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "fee_disposition", &admin);
+        
+        Ok(())
+    }
+
+    /// Get whether transfer fees are routed to the collector or burned
+    pub fn get_fee_disposition(env: Env) -> FeeDisposition {
+        storage::get_fee_disposition(&env)
+    }
+
+    /// Configure automatic TTL maintenance, or disable it with `None` (admin only).
+    /// Extensions happen every `every_ops` operations; `fee_share_bps` of each transfer
+    /// fee is set aside in the contract's rent reserve instead of going to the collector.
+    pub fn set_ttl_maintenance(env: Env, admin: Address, config: Option<TtlMaintenance>) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Validate before stamping; the config is stored after the stamp so the
+        // extension interval starts from this operation
+        validate_ttl_maintenance(&env, config.as_ref())?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "ttl_maintenance", &admin);
+        
+        update_ttl_maintenance(&env, config)
+    }
+
+    /// Get the automatic TTL maintenance configuration, if enabled
+    pub fn get_ttl_maintenance(env: Env) -> Option<TtlMaintenance> {
+        storage::get_ttl_maintenance(&env)
+    }
+
+    /// Get the transfer fees set aside for rent and held by the contract
+    pub fn rent_reserve(env: Env) -> i128 {
+        storage::get_rent_reserve(&env)
+    }
+
+    /// Pay out of the rent reserve, e.g. to reimburse rent paid on the contract's behalf (admin only)
+    pub fn withdraw_rent_reserve(env: Env, admin: Address, to: Address, amount: i128) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        withdraw_rent_reserve(&env, &to, amount)?;
+        
+        // Emit withdrawal event
+        events::publish(
+            &env,
+            (Symbol::new(&env, RENT_RESERVE_WITHDRAWN_EVENT), &to),
+            amount
+        );
+        
+        Ok(())
+    }
+
+    /// Split a transfer amount into `(net, fee)` under the current fee configuration
+    pub fn quote_transfer_fee(env: Env, amount: i128) -> (i128, i128) {
+        quote_fee(&env, amount)
+    }
+
+    /// Quote `(net, fee)` for a transfer of `amount` from `from` to `to`: what the recipient
+    /// and the fee collector would receive under the current fee config, exemptions and cap
+    pub fn quote_transfer(env: Env, from: Address, to: Address, amount: i128) -> (i128, i128) {
+        quote_transfer(&env, &from, &to, amount)
+    }
+
+    /// Configure the settlement contract whose `settle_redemption(from, amount)` is called
+    /// after every burn, or clear it with `None` (admin only). In strict mode a failed
+    /// settlement reverts the burn; otherwise failures are ignored.
+    pub fn set_settlement_hook(env: Env, admin: Address, settlement: Option<Address>, strict: bool) -> Result<(), StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        let config = settlement.map(|contract| SettlementConfig { contract, strict });
+        storage::set_settlement(&env, config.as_ref());
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "settlement_hook", &admin);
+        
+        Ok(())
+    }
+
+    /// Get the configured settlement contract and mode, if any
+    pub fn get_settlement_hook(env: Env) -> Option<SettlementConfig> {
+        storage::get_settlement(&env)
+    }
+
+    /// Get the storage layout version of this deployment
+    pub fn storage_version(env: Env) -> u32 {
+        storage::get_storage_version(&env)
+    }
+
+    /// Apply the known storage migrations from `from_version`, which must be the stored
+    /// version, up to the current layout. Run after upgrading the wasm (admin only).
+    pub fn migrate_storage(env: Env, admin: Address, from_version: u32) -> Result<u32, StablecoinError> {
+        // Validate admin
+        require_admin(&env, &admin)?;
+        
+        // Stamp the operation sequence
+        record_operation(&env);
+        
+        // Record the configuration change
+        emit_config_changed(&env, "storage_version", &admin);
+        
+        migrate_storage(&env, from_version)
+    }
+
+    /// Get `(ledgers_since_init, ledgers_since_last_activity)`, for spotting dormant
+    /// deployments whose storage TTLs may need extending
+    pub fn contract_age(env: Env) -> (u32, u32) {
+        contract_age(&env)
+    }
+
+    /// Get the sequence number of the last state-changing operation. Every event
+    /// carries the sequence of the call that emitted it as the first field of its data.
+    pub fn current_op_seq(env: Env) -> u64 {
+        storage::get_op_seq(&env)
+    }
+
+    /// Get the ledger at which a pending recovery becomes claimable, if one is pending
+    pub fn recovery_pending_until(env: Env) -> Option<u32> {
+        storage::get_recovery_claimable_at(&env)
     }
 }
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Compatible with OpenZeppelin Stellar Soroban Contracts ^0.3.0
+
+//! Event publishing for the stablecoin. Every event carries the operation
+//! sequence number of the call that emitted it as the first field of its
+//! data, so indexers can establish a total order across calls and detect
+//! missed events, including across upgrades.
+
+use soroban_sdk::{Env, IntoVal, Topics, Val};
+use crate::storage;
+
+/// Publish an event stamped with the current operation sequence: the event
+/// data is `(op_seq, data)`
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    env.events().publish(topics, (storage::get_op_seq(env), data));
+}
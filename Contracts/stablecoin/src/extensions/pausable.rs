@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Compatible with OpenZeppelin Stellar Soroban Contracts ^0.3.0
 
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, Address, Env};
 use stellar_pausable::{self as pausable};
 use stellar_access_control_macros::only_role;
+use crate::utils::is_emergency_frozen;
 
 /// Pausable extension for the stablecoin
 pub struct StablecoinPausable;
@@ -59,7 +60,9 @@ pub mod pause_utils {
 
     /// Get pause status with additional context
     pub fn get_pause_status(env: &Env) -> PauseStatus {
-        if pausable::paused(env) {
+        if is_emergency_frozen(env) {
+            PauseStatus::EmergencyFrozen
+        } else if pausable::paused(env) {
             PauseStatus::Paused
         } else {
             PauseStatus::NotPaused
@@ -67,9 +70,11 @@ pub mod pause_utils {
     }
 }
 
-/// Enum representing pause status
+/// Enum representing pause status; `EmergencyFrozen` is a pause taken with allowances frozen
+#[contracttype]
 #[derive(Debug, Clone, PartialEq)]
 pub enum PauseStatus {
     Paused,
     NotPaused,
+    EmergencyFrozen,
 } 
\ No newline at end of file
@@ -2,7 +2,9 @@
 #![allow(dead_code)]
 
 pub mod contract;
+pub mod events;
 pub mod extensions;
+pub mod storage;
 pub mod types;
 pub mod utils;
 
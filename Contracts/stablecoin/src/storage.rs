@@ -0,0 +1,1787 @@
+// SPDX-License-Identifier: MIT
+// Compatible with OpenZeppelin Stellar Soroban Contracts ^0.3.0
+
+//! Storage layout for every custom entry this contract keeps alongside the
+//! OpenZeppelin `Base` token storage. All custom reads and writes go through
+//! the typed accessors below so keys are namespaced under the key enums and
+//! can never collide with `stellar_fungible` internals.
+//!
+//! The keys are split across several enums because a contract spec union is
+//! capped at 50 cases; keep each enum below that when adding keys. An enum
+//! key is encoded as its variant name plus fields, so variant names must stay
+//! unique across all the key enums.
+
+use soroban_sdk::{contracttype, vec, Address, BytesN, Env, String, Symbol, Vec};
+use crate::types::{AllowanceSnapshotEntry, BalanceAlert, BalanceLock, Escrow, FeeConfig, FeeDisposition, LedgerFlow, MintLogEntry, OpSummary, ScheduledAction, SettlementConfig, TtlMaintenance, VestingSchedule, COMPLIANCE_ROLE, CURRENCY_CODE, DECIMALS, DEFAULT_ALLOWANCE_TTL_LEDGERS, DEFAULT_MAX_BATCH_SIZE, DEFAULT_MAX_LOCKS_PER_ACCOUNT, DEFAULT_TIMELOCK_DELAY_LEDGERS, ENABLE_ALLOWANCES, ENABLE_STRICT_VALIDATION, LEGACY_STORAGE_VERSION, MAX_SUPPLY, IDEMPOTENCY_KEY_TTL_LEDGERS, SANCTIONS_CACHE_TTL_LEDGERS, MINT_LOG_CAPACITY, RECENT_OPS_CAPACITY};
+
+/// Instance keys for admin-configured settings, limits and feature flags
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfigKey {
+    /// Instance: contract called with the new wasm hash after every upgrade (Address)
+    PostUpgradeHook,
+    /// Instance: ISO-4217-style display currency code (Symbol)
+    Currency,
+    /// Instance: URI of the off-chain token metadata JSON (String)
+    TokenUri,
+    /// Instance: maximum total supply, at most MAX_SUPPLY (i128)
+    MaxSupply,
+    /// Instance: supply keepers steer toward for peg management; monitoring only (i128)
+    TargetSupply,
+    /// Instance: decimals of the backing asset deposits and withdrawals are denominated in (u32)
+    BackingDecimals,
+    /// Instance: whether every mint must be covered by attested reserves (bool)
+    StrictBacking,
+    /// Instance: minimum reserve-to-supply ratio mints must keep, in basis points; 0 is off (u32)
+    MinReserveRatio,
+    /// Instance: decimals shown in display amounts, at most the token decimals (u32)
+    DisplayDecimals,
+    /// Instance: maximum entries per batch call (u32)
+    MaxBatchSize,
+    /// Instance: whether bulk operations emit one aggregate event instead of one per entry (bool)
+    BatchEvents,
+    /// Instance: whether replayed mint idempotency keys error (bool)
+    RejectDuplicateMints,
+    /// Instance: ledgers past expiration during which transfer_from still honors an allowance (u32)
+    AllowanceGraceLedgers,
+    /// Instance: ledgers an allowance written by approve_for stays live (u32)
+    DefaultAllowanceTtl,
+    /// Instance: furthest ahead an allowance may expire, in ledgers; 0 disables the limit (u32)
+    MaxAllowanceTtl,
+    /// Instance: protocol-wide per-ledger (mint_cap, burn_cap), 0 meaning uncapped ((i128, i128))
+    FlowCaps,
+    /// Instance: ledgers that must pass between mints to the same recipient, 0 disabling it (u32)
+    MintCooldownLedgers,
+    /// Instance: ledgers an account must hold received tokens before transferring; 0 disables (u32)
+    HoldPeriodLedgers,
+    /// Instance: transfer fee configuration (FeeConfig)
+    FeeConfig,
+    /// Instance: tiered fee schedule of ascending (threshold, bps) bands; empty means flat rate (Vec<(i128, u32)>)
+    FeeTiers,
+    /// Instance: whether transfer fees are routed to the collector or burned (FeeDisposition)
+    FeeDisposition,
+    /// Instance: automatic TTL maintenance configuration (TtlMaintenance)
+    TtlMaintenance,
+    /// Instance: settlement contract called after burns (SettlementConfig)
+    Settlement,
+    /// Instance: contracts notified on pause changes (Vec<Address>)
+    PauseSubscribers,
+    /// Instance: rescue address transfers may still reach while paused (Address)
+    MigrationRescue,
+    /// Instance: whether at most one pause action may be applied per ledger (bool)
+    SinglePauseAction,
+    /// Instance: whether balance and supply reads are refused during a full lockdown (bool)
+    ReadLockdown,
+    /// Instance: whether detailed token stats are restricted to admin and compliance (bool)
+    PrivateStats,
+    /// Instance: whether admin and role membership reads are restricted to admin and compliance (bool)
+    PrivateRoles,
+    /// Instance: roles whose methods keep working during a general pause (Vec<Symbol>)
+    PauseImmuneRoles,
+    /// Instance: individually paused method names (Vec<Symbol>)
+    PausedMethods,
+    /// Instance: whether mints to frozen accounts are rejected (bool)
+    BlockMintToFrozen,
+    /// Instance: address allowed to claim the admin role after recovery (Address)
+    RecoveryAddress,
+    /// Instance: ledgers between recovery initiation and claim (u32)
+    RecoveryDelay,
+    /// Instance: addresses allowed to initiate recovery (Vec<Address>)
+    Guardians,
+    /// Instance: minimum ledgers between scheduling a timelocked action and its eta (u32)
+    TimelockDelay,
+    /// Instance: whether minting and role changes are blocked while a recovery is pending (bool)
+    RestrictDuringRecovery,
+    /// Instance: maximum lock entries stored per account (u32)
+    MaxLocksPerAccount,
+    /// Instance: shared sanctions registry consulted alongside the denylist (Address)
+    DenylistOracle,
+    /// Instance: whether every mint and transfer recipient must be allowlisted (bool)
+    AllowlistMode,
+    /// Instance: whether transfers with from == to are permitted (bool)
+    AllowSelfTransfer,
+    /// Instance: whether `transfer_partial` is enabled (bool)
+    PartialTransfers,
+    /// Instance: granularity every transfer, mint and burn amount must be a multiple of, 0 disabling it (i128)
+    PrecisionStep,
+    /// Instance: whether strict address validation is enforced (bool)
+    StrictValidation,
+    /// Instance: whether allowances and delegated spending are enabled (bool)
+    AllowancesEnabled,
+    /// Instance: whether the admin or compliance role may set allowances for managed accounts (bool)
+    ManagedApprovals,
+    /// Instance: whether large burns need an admin co-signature (bool)
+    RequireSymmetricAuthorization,
+    /// Instance: burn amount above which the admin co-signature is required (i128)
+    GovernedBurnThreshold,
+    /// Instance: whether burning is restricted to holders of the burner role (bool)
+    RoleGatedBurns,
+}
+
+/// Instance keys for counters, running totals and other state the contract maintains itself
+#[contracttype]
+#[derive(Clone)]
+pub enum StateKey {
+    /// Instance: sequence number of the last state-changing operation (u64)
+    OpSeq,
+    /// Instance: ledger the contract was initialized in (u32)
+    InitLedger,
+    /// Instance: storage layout version (u32)
+    StorageVersion,
+    /// Instance: set once upgrades are permanently disabled (bool)
+    UpgradesLocked,
+    /// Instance: holders signalling consent and dissent to upgrades (u32, u32)
+    UpgradeConsentTally,
+    /// Instance: ledger of the last state-changing operation (u32)
+    LastActivityLedger,
+    /// Instance: attested fiat reserves backing the supply (i128)
+    BackingReserve,
+    /// Instance: cumulative amount ever minted (i128)
+    TotalMinted,
+    /// Instance: cumulative amount ever burned (i128)
+    TotalBurned,
+    /// Instance: number of accounts with a non-zero balance (u32)
+    ActiveHolders,
+    /// Instance: sum of all allowances as written, less amounts spent (i128)
+    OutstandingAllowances,
+    /// Instance: amounts minted and burned in the current ledger (LedgerFlow)
+    LedgerFlow,
+    /// Instance: transfer volume and the start ledger of its window ((i128, u32))
+    TransferVolume,
+    /// Instance: id assigned to the next escrow (u64)
+    NextEscrowId,
+    /// Instance: sum of all open escrows (i128)
+    TotalEscrowed,
+    /// Instance: operation sequence at the last automatic TTL extension (u64)
+    LastTtlExtensionOp,
+    /// Instance: transfer fees set aside for rent and held by the contract (i128)
+    RentReserve,
+    /// Instance: ledger of the latest pause action while the single-action rule is on (u32)
+    LastPauseActionLedger,
+    /// Instance: running total of balances held by frozen accounts (i128)
+    FrozenSupply,
+    /// Instance: running total of balances held by supply-excluded accounts (i128)
+    ExcludedSupply,
+    /// Instance: ledger at which a pending recovery becomes claimable (u32)
+    RecoveryClaimableAt,
+    /// Instance: admin nominated by a two-step admin transfer (Address)
+    PendingAdmin,
+    /// Instance: sum of all recorded balance locks not yet consolidated (i128)
+    TotalLocked,
+    /// Instance: number of denylisted accounts (u32)
+    DenylistCount,
+    /// Instance: whether total supply is frozen, blocking every mint and burn (bool)
+    SupplyFrozen,
+    /// Instance: number of owners with indexed spenders (u32)
+    AllowanceOwnerCount,
+    /// Instance: total mint log entries ever appended (u32)
+    MintLogCount,
+    /// Instance: total operation summaries ever appended (u32)
+    RecentOpCount,
+}
+
+/// Persistent and temporary keys scoped to one account or an (owner, spender) pair
+#[contracttype]
+#[derive(Clone)]
+pub enum AccountKey {
+    /// Persistent: a holder's informational upgrade consent signal (bool)
+    UpgradeConsent(Address),
+    /// Persistent: marks an account counted in ActiveHolders (bool)
+    Holder(Address),
+    /// Persistent: allowance amount currently counted in the outstanding total (i128)
+    CountedAllowance(Address, Address),
+    /// Persistent: remaining amount a minter may mint; absent means unlimited (i128)
+    MinterAllowance(Address),
+    /// Persistent: ledger of the last mint to a recipient (u32)
+    LastMintLedger(Address),
+    /// Persistent: vesting schedule of a genesis treasury mint (VestingSchedule)
+    Vesting(Address),
+    /// Persistent: low balance alert configured for an account (BalanceAlert)
+    BalanceAlert(Address),
+    /// Persistent: account freeze flag (bool)
+    Frozen(Address),
+    /// Persistent: whether an account's balance is excluded from circulating supply (bool)
+    SupplyExcluded(Address),
+    /// Persistent: balance locks held by an account (Vec<BalanceLock>)
+    Locks(Address),
+    /// Persistent: position of a denylisted account in the denylist index (u32)
+    Denylisted(Address),
+    /// Temporary: cached oracle answer for (oracle, account) with the ledger it is fresh until (bool, u32)
+    SanctionsCache(Address, Address),
+    /// Persistent: account allowlist flag (bool)
+    Allowlisted(Address),
+    /// Persistent: account registered as managed, open to `admin_approve` (bool)
+    ManagedAccount(Address),
+    /// Persistent: counterparties an account may transfer to; empty means unrestricted (Vec<Address>)
+    AllowedCounterparties(Address),
+    /// Persistent: spenders an owner has granted allowances to (Vec<Address>)
+    Spenders(Address),
+    /// Persistent: expiration ledger of an (owner, spender) allowance (u32)
+    AllowanceExpiration(Address, Address),
+    /// Persistent: position of an owner with indexed spenders in the owner index (u32)
+    AllowanceOwner(Address),
+    /// Persistent: an account's consent to pulls by a spender-role contract, keyed (account, spender) (bool)
+    SpenderOptIn(Address, Address),
+}
+
+/// Persistent and temporary keys for records addressed by id, hash or index position
+#[contracttype]
+#[derive(Clone)]
+pub enum RecordKey {
+    /// Temporary: processed mint idempotency key (bool)
+    MintIdempotencyKey(BytesN<32>),
+    /// Persistent: bank deposit reference already minted for (bool)
+    DepositRef(BytesN<32>),
+    /// Persistent: an open escrow by id (Escrow)
+    Escrow(u64),
+    /// Persistent: a scheduled timelocked admin action by hash (ScheduledAction)
+    ScheduledAction(BytesN<32>),
+    /// Persistent: denylisted account at a dense index position (Address)
+    DenylistEntry(u32),
+    /// Persistent: owner with indexed spenders at a dense index position (Address)
+    AllowanceOwnerEntry(u32),
+    /// Persistent: allowances zeroed by a freezing pause (Vec<AllowanceSnapshotEntry>)
+    AllowanceSnapshot,
+    /// Persistent: mint log entry stored in a rolling slot (MintLogEntry)
+    MintLogSlot(u32),
+    /// Persistent: operation summary stored in a rolling slot (OpSummary)
+    RecentOpSlot(u32),
+}
+
+/// ==================== METADATA ====================
+
+/// Get the display currency code
+pub fn get_currency(env: &Env) -> Symbol {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::Currency)
+        .unwrap_or(Symbol::new(env, CURRENCY_CODE))
+}
+
+/// Set the display currency code
+pub fn set_currency(env: &Env, currency: &Symbol) {
+    env.storage().instance().set(&ConfigKey::Currency, currency);
+}
+
+/// Get the token metadata URI, empty if unset
+pub fn get_token_uri(env: &Env) -> String {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::TokenUri)
+        .unwrap_or(String::from_str(env, ""))
+}
+
+/// Set the token metadata URI
+pub fn set_token_uri(env: &Env, uri: &String) {
+    env.storage().instance().set(&ConfigKey::TokenUri, uri);
+}
+
+/// Get the configured display decimals, if a denomination floor has been set
+pub fn get_display_decimals(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&ConfigKey::DisplayDecimals)
+}
+
+/// Set the display decimals used to floor amounts in display strings
+pub fn set_display_decimals(env: &Env, decimals: u32) {
+    env.storage().instance().set(&ConfigKey::DisplayDecimals, &decimals);
+}
+
+/// ==================== UPGRADES ====================
+
+/// Check whether upgrades have been permanently disabled
+pub fn get_upgrades_locked(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&StateKey::UpgradesLocked)
+        .unwrap_or(false)
+}
+
+/// Permanently disable upgrades; there is deliberately no way to clear the flag
+pub fn lock_upgrades(env: &Env) {
+    env.storage().instance().set(&StateKey::UpgradesLocked, &true);
+}
+
+/// Get the contract called after every upgrade, if one is configured
+pub fn get_post_upgrade_hook(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::PostUpgradeHook)
+}
+
+/// Get a holder's upgrade consent signal, if they have given one
+pub fn get_upgrade_consent(env: &Env, holder: &Address) -> Option<bool> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::UpgradeConsent(holder.clone()))
+}
+
+/// Record a holder's upgrade consent signal
+pub fn set_upgrade_consent(env: &Env, holder: &Address, consent: bool) {
+    env.storage()
+        .persistent()
+        .set(&AccountKey::UpgradeConsent(holder.clone()), &consent);
+}
+
+/// Get how many holders signal (consent, dissent) to upgrades
+pub fn get_upgrade_consent_tally(env: &Env) -> (u32, u32) {
+    env.storage()
+        .instance()
+        .get(&StateKey::UpgradeConsentTally)
+        .unwrap_or((0, 0))
+}
+
+/// Set how many holders signal (consent, dissent) to upgrades
+pub fn set_upgrade_consent_tally(env: &Env, tally: (u32, u32)) {
+    env.storage().instance().set(&StateKey::UpgradeConsentTally, &tally);
+}
+
+/// Set or clear the contract called after every upgrade
+pub fn set_post_upgrade_hook(env: &Env, hook: Option<&Address>) {
+    match hook {
+        Some(hook) => env.storage().instance().set(&ConfigKey::PostUpgradeHook, hook),
+        None => env.storage().instance().remove(&ConfigKey::PostUpgradeHook),
+    }
+}
+
+/// ==================== SUPPLY ====================
+
+/// Get the maximum total supply, defaulting to MAX_SUPPLY
+pub fn get_max_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxSupply)
+        .unwrap_or(MAX_SUPPLY)
+}
+
+/// Set the maximum total supply
+pub fn set_max_supply(env: &Env, max_supply: i128) {
+    env.storage().instance().set(&ConfigKey::MaxSupply, &max_supply);
+}
+
+/// Get the target supply keepers steer toward, defaulting to 0
+pub fn get_target_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::TargetSupply)
+        .unwrap_or(0)
+}
+
+/// Set the target supply keepers steer toward
+pub fn set_target_supply(env: &Env, target: i128) {
+    env.storage().instance().set(&ConfigKey::TargetSupply, &target);
+}
+
+/// Get the attested fiat reserves backing the supply
+pub fn get_backing_reserve(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::BackingReserve)
+        .unwrap_or(0)
+}
+
+/// Set the attested fiat reserves backing the supply
+pub fn set_backing_reserve(env: &Env, reserve: i128) {
+    env.storage().instance().set(&StateKey::BackingReserve, &reserve);
+}
+
+/// Get the decimals of the backing asset, defaulting to the token's own
+pub fn get_backing_decimals(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::BackingDecimals)
+        .unwrap_or(DECIMALS)
+}
+
+/// Set the decimals of the backing asset
+pub fn set_backing_decimals(env: &Env, decimals: u32) {
+    env.storage().instance().set(&ConfigKey::BackingDecimals, &decimals);
+}
+
+/// Check whether every mint must be covered by attested reserves
+pub fn get_strict_backing(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::StrictBacking)
+        .unwrap_or(false)
+}
+
+/// Set whether every mint must be covered by attested reserves
+pub fn set_strict_backing(env: &Env, strict: bool) {
+    env.storage().instance().set(&ConfigKey::StrictBacking, &strict);
+}
+
+/// Get the minimum reserve ratio in basis points, 0 when not enforced
+pub fn get_min_reserve_ratio(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MinReserveRatio)
+        .unwrap_or(0)
+}
+
+/// Set the minimum reserve ratio in basis points
+pub fn set_min_reserve_ratio(env: &Env, ratio_bps: u32) {
+    env.storage().instance().set(&ConfigKey::MinReserveRatio, &ratio_bps);
+}
+
+/// ==================== BATCH CONFIGURATION ====================
+
+/// Get the maximum number of entries accepted by a single batch call
+pub fn get_max_batch_size(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxBatchSize)
+        .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+}
+
+/// Set the maximum number of entries accepted by a single batch call
+pub fn set_max_batch_size(env: &Env, max_batch_size: u32) {
+    env.storage().instance().set(&ConfigKey::MaxBatchSize, &max_batch_size);
+}
+
+/// Check whether bulk operations emit a single aggregate event
+pub fn get_batch_events(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::BatchEvents)
+        .unwrap_or(false)
+}
+
+/// Set whether bulk operations emit a single aggregate event
+pub fn set_batch_events(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::BatchEvents, &enabled);
+}
+
+/// ==================== TRANSFER CONFIGURATION ====================
+
+/// Check whether transfers from an address to itself are permitted
+pub fn get_allow_self_transfer(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AllowSelfTransfer)
+        .unwrap_or(false)
+}
+
+/// Set whether transfers from an address to itself are permitted
+pub fn set_allow_self_transfer(env: &Env, allow: bool) {
+    env.storage().instance().set(&ConfigKey::AllowSelfTransfer, &allow);
+}
+
+/// Check whether partial-fill transfers are enabled
+pub fn get_partial_transfers(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PartialTransfers)
+        .unwrap_or(false)
+}
+
+/// Enable or disable partial-fill transfers
+pub fn set_partial_transfers(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::PartialTransfers, &enabled);
+}
+
+/// Get the granularity amounts must be a multiple of; 0 means any amount
+pub fn get_precision_step(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PrecisionStep)
+        .unwrap_or(0)
+}
+
+/// Set the granularity amounts must be a multiple of
+pub fn set_precision_step(env: &Env, step: i128) {
+    env.storage().instance().set(&ConfigKey::PrecisionStep, &step);
+}
+
+/// Check whether strict address validation is enforced, defaulting to `ENABLE_STRICT_VALIDATION`
+pub fn get_strict_validation(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::StrictValidation)
+        .unwrap_or(ENABLE_STRICT_VALIDATION)
+}
+
+/// Set whether strict address validation is enforced
+pub fn set_strict_validation(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::StrictValidation, &enabled);
+}
+
+/// Check whether allowances and delegated spending are enabled, defaulting to `ENABLE_ALLOWANCES`
+pub fn get_allowances_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AllowancesEnabled)
+        .unwrap_or(ENABLE_ALLOWANCES)
+}
+
+/// Set whether allowances and delegated spending are enabled
+pub fn set_allowances_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::AllowancesEnabled, &enabled);
+}
+
+/// Check whether the admin or compliance role may set allowances for managed accounts
+pub fn get_managed_approvals(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::ManagedApprovals)
+        .unwrap_or(false)
+}
+
+/// Set whether the admin or compliance role may set allowances for managed accounts
+pub fn set_managed_approvals(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::ManagedApprovals, &enabled);
+}
+
+/// Check whether an account is registered as managed
+pub fn is_managed_account(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::ManagedAccount(account.clone()))
+        .unwrap_or(false)
+}
+
+/// Register or unregister a managed account, removing the entry when unregistered
+pub fn set_managed_account(env: &Env, account: &Address, managed: bool) {
+    let key = AccountKey::ManagedAccount(account.clone());
+    if managed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether total supply is frozen
+pub fn get_supply_frozen(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&StateKey::SupplyFrozen)
+        .unwrap_or(false)
+}
+
+/// Set whether total supply is frozen
+pub fn set_supply_frozen(env: &Env, frozen: bool) {
+    env.storage().instance().set(&StateKey::SupplyFrozen, &frozen);
+}
+
+/// ==================== BURN GOVERNANCE ====================
+
+/// Check whether burns above the threshold need an admin co-signature
+pub fn get_require_symmetric_authorization(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::RequireSymmetricAuthorization)
+        .unwrap_or(false)
+}
+
+/// Set whether burns above the threshold need an admin co-signature
+pub fn set_require_symmetric_authorization(env: &Env, required: bool) {
+    env.storage().instance().set(&ConfigKey::RequireSymmetricAuthorization, &required);
+}
+
+/// Get the burn amount above which an admin co-signature is required
+pub fn get_governed_burn_threshold(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::GovernedBurnThreshold)
+        .unwrap_or(0)
+}
+
+/// Set the burn amount above which an admin co-signature is required
+pub fn set_governed_burn_threshold(env: &Env, threshold: i128) {
+    env.storage().instance().set(&ConfigKey::GovernedBurnThreshold, &threshold);
+}
+
+/// Check whether burning is restricted to holders of the burner role
+pub fn get_role_gated_burns(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::RoleGatedBurns)
+        .unwrap_or(false)
+}
+
+/// Set whether burning is restricted to holders of the burner role
+pub fn set_role_gated_burns(env: &Env, gated: bool) {
+    env.storage().instance().set(&ConfigKey::RoleGatedBurns, &gated);
+}
+
+/// ==================== IDEMPOTENCY ====================
+
+/// Check whether replayed mint keys are rejected instead of being a no-op
+pub fn get_reject_duplicate_mints(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::RejectDuplicateMints)
+        .unwrap_or(false)
+}
+
+/// Set the replayed mint key policy
+pub fn set_reject_duplicate_mints(env: &Env, reject: bool) {
+    env.storage().instance().set(&ConfigKey::RejectDuplicateMints, &reject);
+}
+
+/// Check whether a mint idempotency key has already been processed
+pub fn has_mint_key(env: &Env, key: &BytesN<32>) -> bool {
+    env.storage()
+        .temporary()
+        .has(&RecordKey::MintIdempotencyKey(key.clone()))
+}
+
+/// Record a mint idempotency key as processed, keeping it for a bounded TTL
+pub fn set_mint_key(env: &Env, key: &BytesN<32>) {
+    let storage_key = RecordKey::MintIdempotencyKey(key.clone());
+    env.storage().temporary().set(&storage_key, &true);
+    env.storage().temporary().extend_ttl(
+        &storage_key,
+        IDEMPOTENCY_KEY_TTL_LEDGERS,
+        IDEMPOTENCY_KEY_TTL_LEDGERS,
+    );
+}
+
+/// Check whether a bank deposit reference has already been minted for
+pub fn has_deposit_ref(env: &Env, bank_ref: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&RecordKey::DepositRef(bank_ref.clone()))
+}
+
+/// Record a bank deposit reference as minted; unlike idempotency keys it never expires
+pub fn set_deposit_ref(env: &Env, bank_ref: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&RecordKey::DepositRef(bank_ref.clone()), &true);
+}
+
+/// Get the ledgers past expiration during which `transfer_from` still honors an allowance
+pub fn get_allowance_grace_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AllowanceGraceLedgers)
+        .unwrap_or(0)
+}
+
+/// Set the ledgers past expiration during which `transfer_from` still honors an allowance
+pub fn set_allowance_grace_ledgers(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::AllowanceGraceLedgers, &ledgers);
+}
+
+/// Get the ledgers an allowance written by `approve_for` stays live
+pub fn get_default_allowance_ttl(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::DefaultAllowanceTtl)
+        .unwrap_or(DEFAULT_ALLOWANCE_TTL_LEDGERS)
+}
+
+/// Set the ledgers an allowance written by `approve_for` stays live
+pub fn set_default_allowance_ttl(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::DefaultAllowanceTtl, &ledgers);
+}
+
+/// Get the furthest ahead an allowance may expire; 0 means no limit
+pub fn get_max_allowance_ttl(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxAllowanceTtl)
+        .unwrap_or(0)
+}
+
+/// Set the furthest ahead an allowance may expire
+pub fn set_max_allowance_ttl(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::MaxAllowanceTtl, &ledgers);
+}
+
+/// ==================== STATISTICS ====================
+
+/// Get the cumulative amount ever minted
+pub fn get_total_minted(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::TotalMinted)
+        .unwrap_or(0)
+}
+
+/// Set the cumulative amount ever minted
+pub fn set_total_minted(env: &Env, amount: i128) {
+    env.storage().instance().set(&StateKey::TotalMinted, &amount);
+}
+
+/// Get the cumulative amount ever burned
+pub fn get_total_burned(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::TotalBurned)
+        .unwrap_or(0)
+}
+
+/// Set the cumulative amount ever burned
+pub fn set_total_burned(env: &Env, amount: i128) {
+    env.storage().instance().set(&StateKey::TotalBurned, &amount);
+}
+
+/// Get the number of accounts with a non-zero balance
+pub fn get_active_holders(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::ActiveHolders)
+        .unwrap_or(0)
+}
+
+/// Set the number of accounts with a non-zero balance
+pub fn set_active_holders(env: &Env, count: u32) {
+    env.storage().instance().set(&StateKey::ActiveHolders, &count);
+}
+
+/// Check whether an account is counted as an active holder
+pub fn is_holder(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Holder(account.clone()))
+        .unwrap_or(false)
+}
+
+/// Mark or unmark an account as counted in the active holders total
+pub fn set_holder(env: &Env, account: &Address, holder: bool) {
+    let key = AccountKey::Holder(account.clone());
+    if holder {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get the sum of all outstanding allowances
+pub fn get_outstanding_allowances(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::OutstandingAllowances)
+        .unwrap_or(0)
+}
+
+/// Set the sum of all outstanding allowances
+pub fn set_outstanding_allowances(env: &Env, amount: i128) {
+    env.storage().instance().set(&StateKey::OutstandingAllowances, &amount);
+}
+
+/// Get the allowance amount currently counted in the outstanding total
+pub fn get_counted_allowance(env: &Env, owner: &Address, spender: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::CountedAllowance(owner.clone(), spender.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the allowance amount counted in the outstanding total, removing the entry at zero
+pub fn set_counted_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let key = AccountKey::CountedAllowance(owner.clone(), spender.clone());
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// ==================== MINTER ALLOWANCES ====================
+
+/// Get the remaining amount a minter may mint, or `None` if unlimited
+pub fn get_minter_allowance(env: &Env, minter: &Address) -> Option<i128> {
+    env.storage().persistent().get(&AccountKey::MinterAllowance(minter.clone()))
+}
+
+/// Set or clear (unlimited) the remaining amount a minter may mint
+pub fn set_minter_allowance(env: &Env, minter: &Address, allowance: Option<i128>) {
+    let key = AccountKey::MinterAllowance(minter.clone());
+    match allowance {
+        Some(amount) => env.storage().persistent().set(&key, &amount),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// ==================== FLOW CAPS ====================
+
+/// Get the protocol-wide per-ledger (mint_cap, burn_cap); 0 means uncapped
+pub fn get_flow_caps(env: &Env) -> (i128, i128) {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FlowCaps)
+        .unwrap_or((0, 0))
+}
+
+/// Set the protocol-wide per-ledger (mint_cap, burn_cap)
+pub fn set_flow_caps(env: &Env, mint_cap: i128, burn_cap: i128) {
+    env.storage().instance().set(&ConfigKey::FlowCaps, &(mint_cap, burn_cap));
+}
+
+/// Get the amounts minted and burned in the most recently active ledger
+pub fn get_ledger_flow(env: &Env) -> Option<LedgerFlow> {
+    env.storage().instance().get(&StateKey::LedgerFlow)
+}
+
+/// Set the amounts minted and burned in the current ledger
+pub fn set_ledger_flow(env: &Env, flow: &LedgerFlow) {
+    env.storage().instance().set(&StateKey::LedgerFlow, flow);
+}
+
+/// Get the recorded transfer volume and the start ledger of its window
+pub fn get_transfer_volume(env: &Env) -> Option<(i128, u32)> {
+    env.storage().instance().get(&StateKey::TransferVolume)
+}
+
+/// Set the transfer volume and the start ledger of its window
+pub fn set_transfer_volume(env: &Env, volume: i128, window_start: u32) {
+    env.storage().instance().set(&StateKey::TransferVolume, &(volume, window_start));
+}
+
+/// ==================== MINT COOLDOWN ====================
+
+/// Get the ledgers that must pass between mints to the same recipient; 0 means no cooldown
+pub fn get_mint_cooldown_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MintCooldownLedgers)
+        .unwrap_or(0)
+}
+
+/// Set the ledgers that must pass between mints to the same recipient
+pub fn set_mint_cooldown_ledgers(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::MintCooldownLedgers, &ledgers);
+}
+
+/// Get the ledgers received tokens must be held before transfer; 0 means no holding period
+pub fn get_hold_period_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::HoldPeriodLedgers)
+        .unwrap_or(0)
+}
+
+/// Set the ledgers received tokens must be held before transfer
+pub fn set_hold_period_ledgers(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::HoldPeriodLedgers, &ledgers);
+}
+
+/// Get the ledger of the last mint to a recipient, if any
+pub fn get_last_mint_ledger(env: &Env, account: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::LastMintLedger(account.clone()))
+}
+
+/// Record the ledger of the latest mint to a recipient
+pub fn set_last_mint_ledger(env: &Env, account: &Address, ledger: u32) {
+    env.storage()
+        .persistent()
+        .set(&AccountKey::LastMintLedger(account.clone()), &ledger);
+}
+
+/// ==================== ESCROW ====================
+
+/// Get the id the next escrow will be assigned
+pub fn get_next_escrow_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&StateKey::NextEscrowId)
+        .unwrap_or(0)
+}
+
+/// Set the id the next escrow will be assigned
+pub fn set_next_escrow_id(env: &Env, id: u64) {
+    env.storage().instance().set(&StateKey::NextEscrowId, &id);
+}
+
+/// Get the sum of all open escrows
+pub fn get_total_escrowed(env: &Env) -> i128 {
+    env.storage().instance().get(&StateKey::TotalEscrowed).unwrap_or(0)
+}
+
+/// Set the sum of all open escrows
+pub fn set_total_escrowed(env: &Env, total: i128) {
+    env.storage().instance().set(&StateKey::TotalEscrowed, &total);
+}
+
+/// Get an open escrow by id
+pub fn get_escrow(env: &Env, id: u64) -> Option<Escrow> {
+    env.storage().persistent().get(&RecordKey::Escrow(id))
+}
+
+/// Store an open escrow
+pub fn set_escrow(env: &Env, id: u64, escrow: &Escrow) {
+    env.storage().persistent().set(&RecordKey::Escrow(id), escrow);
+}
+
+/// Remove a settled escrow
+pub fn remove_escrow(env: &Env, id: u64) {
+    env.storage().persistent().remove(&RecordKey::Escrow(id));
+}
+
+/// ==================== TRANSFER FEES ====================
+
+/// Get the transfer fee configuration, if fees are enabled
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&ConfigKey::FeeConfig)
+}
+
+/// Set or clear the transfer fee configuration
+pub fn set_fee_config(env: &Env, config: Option<&FeeConfig>) {
+    match config {
+        Some(config) => env.storage().instance().set(&ConfigKey::FeeConfig, config),
+        None => env.storage().instance().remove(&ConfigKey::FeeConfig),
+    }
+}
+
+/// Get the tiered fee schedule; empty when the flat rate applies
+pub fn get_fee_tiers(env: &Env) -> Vec<(i128, u32)> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FeeTiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the tiered fee schedule
+pub fn set_fee_tiers(env: &Env, tiers: &Vec<(i128, u32)>) {
+    env.storage().instance().set(&ConfigKey::FeeTiers, tiers);
+}
+
+/// Get what happens to transfer fees, defaulting to routing them to the collector
+pub fn get_fee_disposition(env: &Env) -> FeeDisposition {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FeeDisposition)
+        .unwrap_or(FeeDisposition::Route)
+}
+
+/// Set what happens to transfer fees
+pub fn set_fee_disposition(env: &Env, disposition: FeeDisposition) {
+    env.storage().instance().set(&ConfigKey::FeeDisposition, &disposition);
+}
+
+/// Get the automatic TTL maintenance configuration, if enabled
+pub fn get_ttl_maintenance(env: &Env) -> Option<TtlMaintenance> {
+    env.storage().instance().get(&ConfigKey::TtlMaintenance)
+}
+
+/// Set or clear the automatic TTL maintenance configuration
+pub fn set_ttl_maintenance(env: &Env, config: Option<&TtlMaintenance>) {
+    match config {
+        Some(config) => env.storage().instance().set(&ConfigKey::TtlMaintenance, config),
+        None => env.storage().instance().remove(&ConfigKey::TtlMaintenance),
+    }
+}
+
+/// Get the operation sequence at the last automatic TTL extension
+pub fn get_last_ttl_extension_op(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&StateKey::LastTtlExtensionOp)
+        .unwrap_or(0)
+}
+
+/// Record the operation sequence of an automatic TTL extension
+pub fn set_last_ttl_extension_op(env: &Env, op_seq: u64) {
+    env.storage().instance().set(&StateKey::LastTtlExtensionOp, &op_seq);
+}
+
+/// Get the transfer fees set aside for rent
+pub fn get_rent_reserve(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::RentReserve)
+        .unwrap_or(0)
+}
+
+/// Set the transfer fees set aside for rent
+pub fn set_rent_reserve(env: &Env, amount: i128) {
+    env.storage().instance().set(&StateKey::RentReserve, &amount);
+}
+
+/// ==================== REDEMPTION SETTLEMENT ====================
+
+/// Get the settlement contract called after burns, if one is configured
+pub fn get_settlement(env: &Env) -> Option<SettlementConfig> {
+    env.storage().instance().get(&ConfigKey::Settlement)
+}
+
+/// Set or clear the settlement contract called after burns
+pub fn set_settlement(env: &Env, config: Option<&SettlementConfig>) {
+    match config {
+        Some(config) => env.storage().instance().set(&ConfigKey::Settlement, config),
+        None => env.storage().instance().remove(&ConfigKey::Settlement),
+    }
+}
+
+/// ==================== PAUSE CONTROLS ====================
+
+/// Get the contracts notified whenever the pause state changes
+pub fn get_pause_subscribers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PauseSubscribers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the contracts notified whenever the pause state changes
+pub fn set_pause_subscribers(env: &Env, subscribers: &Vec<Address>) {
+    env.storage().instance().set(&ConfigKey::PauseSubscribers, subscribers);
+}
+
+/// Get the migration rescue address, if migration mode is on
+pub fn get_migration_rescue(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::MigrationRescue)
+}
+
+/// Set or clear the migration rescue address
+pub fn set_migration_rescue(env: &Env, rescue: Option<&Address>) {
+    match rescue {
+        Some(rescue) => env.storage().instance().set(&ConfigKey::MigrationRescue, rescue),
+        None => env.storage().instance().remove(&ConfigKey::MigrationRescue),
+    }
+}
+
+/// Check whether at most one pause action may be applied per ledger
+pub fn get_single_pause_action(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::SinglePauseAction)
+        .unwrap_or(false)
+}
+
+/// Set whether at most one pause action may be applied per ledger
+pub fn set_single_pause_action(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::SinglePauseAction, &enabled);
+}
+
+/// Get the ledger of the latest pause action, if one was recorded
+pub fn get_last_pause_action_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&StateKey::LastPauseActionLedger)
+}
+
+/// Record the ledger of the latest pause action
+pub fn set_last_pause_action_ledger(env: &Env, ledger: u32) {
+    env.storage().instance().set(&StateKey::LastPauseActionLedger, &ledger);
+}
+
+/// Check whether balance and supply reads are refused
+pub fn get_read_lockdown(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::ReadLockdown)
+        .unwrap_or(false)
+}
+
+/// Set whether balance and supply reads are refused
+pub fn set_read_lockdown(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::ReadLockdown, &enabled);
+}
+
+/// Check whether detailed token stats are restricted to admin and compliance
+pub fn get_private_stats(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PrivateStats)
+        .unwrap_or(false)
+}
+
+/// Set whether detailed token stats are restricted to admin and compliance
+pub fn set_private_stats(env: &Env, private: bool) {
+    env.storage().instance().set(&ConfigKey::PrivateStats, &private);
+}
+
+/// Check whether admin and role membership reads are restricted to admin and compliance
+pub fn get_private_roles(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PrivateRoles)
+        .unwrap_or(false)
+}
+
+/// Set whether admin and role membership reads are restricted to admin and compliance
+pub fn set_private_roles(env: &Env, private: bool) {
+    env.storage().instance().set(&ConfigKey::PrivateRoles, &private);
+}
+
+/// Get the roles whose methods keep working during a general pause, defaulting to compliance
+pub fn get_pause_immune_roles(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PauseImmuneRoles)
+        .unwrap_or(vec![env, Symbol::new(env, COMPLIANCE_ROLE)])
+}
+
+/// Set the roles whose methods keep working during a general pause
+pub fn set_pause_immune_roles(env: &Env, roles: &Vec<Symbol>) {
+    env.storage().instance().set(&ConfigKey::PauseImmuneRoles, roles);
+}
+
+/// Get the individually paused method names
+pub fn get_paused_methods(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PausedMethods)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the individually paused method names
+pub fn set_paused_methods(env: &Env, methods: &Vec<Symbol>) {
+    env.storage().instance().set(&ConfigKey::PausedMethods, methods);
+}
+
+/// ==================== ADMIN RECOVERY ====================
+
+/// Get the configured recovery address and delay, if recovery is configured
+pub fn get_recovery_config(env: &Env) -> Option<(Address, u32)> {
+    let recovery: Option<Address> = env.storage().instance().get(&ConfigKey::RecoveryAddress);
+    let delay: Option<u32> = env.storage().instance().get(&ConfigKey::RecoveryDelay);
+    recovery.zip(delay)
+}
+
+/// Set the recovery address and delay
+pub fn set_recovery_config(env: &Env, recovery: &Address, delay_ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::RecoveryAddress, recovery);
+    env.storage().instance().set(&ConfigKey::RecoveryDelay, &delay_ledgers);
+}
+
+/// Get the addresses allowed to initiate recovery
+pub fn get_guardians(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::Guardians)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the addresses allowed to initiate recovery
+pub fn set_guardians(env: &Env, guardians: &Vec<Address>) {
+    env.storage().instance().set(&ConfigKey::Guardians, guardians);
+}
+
+/// Get the ledger at which a pending recovery becomes claimable
+pub fn get_recovery_claimable_at(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&StateKey::RecoveryClaimableAt)
+}
+
+/// Set or clear the ledger at which a pending recovery becomes claimable
+pub fn set_recovery_claimable_at(env: &Env, claimable_at: Option<u32>) {
+    match claimable_at {
+        Some(ledger) => env.storage().instance().set(&StateKey::RecoveryClaimableAt, &ledger),
+        None => env.storage().instance().remove(&StateKey::RecoveryClaimableAt),
+    }
+}
+
+/// Check whether privileged operations are restricted while a recovery is pending
+pub fn get_restrict_during_recovery(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::RestrictDuringRecovery)
+        .unwrap_or(false)
+}
+
+/// Set whether privileged operations are restricted while a recovery is pending
+pub fn set_restrict_during_recovery(env: &Env, restrict: bool) {
+    env.storage().instance().set(&ConfigKey::RestrictDuringRecovery, &restrict);
+}
+
+/// ==================== ACCOUNT RESTRICTIONS ====================
+
+/// Check whether an account is frozen
+pub fn is_frozen(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Frozen(account.clone()))
+        .unwrap_or(false)
+}
+
+/// Freeze or unfreeze an account, removing the entry when unfrozen
+pub fn set_frozen(env: &Env, account: &Address, frozen: bool) {
+    let key = AccountKey::Frozen(account.clone());
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether recipients must be allowlisted
+pub fn get_allowlist_mode(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AllowlistMode)
+        .unwrap_or(false)
+}
+
+/// Set whether recipients must be allowlisted
+pub fn set_allowlist_mode(env: &Env, enabled: bool) {
+    env.storage().instance().set(&ConfigKey::AllowlistMode, &enabled);
+}
+
+/// Check whether an account is allowlisted
+pub fn is_allowlisted(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Allowlisted(account.clone()))
+        .unwrap_or(false)
+}
+
+/// Add or remove an account from the allowlist, removing the entry when delisted
+pub fn set_allowlisted(env: &Env, account: &Address, allowlisted: bool) {
+    let key = AccountKey::Allowlisted(account.clone());
+    if allowlisted {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether an account has opted in to pulls by a spender contract
+pub fn is_spender_opted_in(env: &Env, account: &Address, spender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::SpenderOptIn(account.clone(), spender.clone()))
+        .unwrap_or(false)
+}
+
+/// Record or withdraw an account's consent to pulls by a spender contract
+pub fn set_spender_opt_in(env: &Env, account: &Address, spender: &Address, opted_in: bool) {
+    let key = AccountKey::SpenderOptIn(account.clone(), spender.clone());
+    if opted_in {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get the running total of balances held by frozen accounts
+pub fn get_frozen_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::FrozenSupply)
+        .unwrap_or(0)
+}
+
+/// Set the running total of balances held by frozen accounts
+pub fn set_frozen_supply(env: &Env, amount: i128) {
+    env.storage().instance().set(&StateKey::FrozenSupply, &amount);
+}
+
+/// Check whether an account's balance is excluded from circulating supply
+pub fn is_supply_excluded(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::SupplyExcluded(account.clone()))
+        .unwrap_or(false)
+}
+
+/// Exclude an account from circulating supply or include it again, removing the entry when included
+pub fn set_supply_excluded(env: &Env, account: &Address, excluded: bool) {
+    let key = AccountKey::SupplyExcluded(account.clone());
+    if excluded {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get the running total of balances held by supply-excluded accounts
+pub fn get_excluded_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StateKey::ExcludedSupply)
+        .unwrap_or(0)
+}
+
+/// Set the running total of balances held by supply-excluded accounts
+pub fn set_excluded_supply(env: &Env, amount: i128) {
+    env.storage().instance().set(&StateKey::ExcludedSupply, &amount);
+}
+
+/// Check whether mints to frozen accounts are rejected
+pub fn get_block_mint_to_frozen(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::BlockMintToFrozen)
+        .unwrap_or(false)
+}
+
+/// Set whether mints to frozen accounts are rejected
+pub fn set_block_mint_to_frozen(env: &Env, block: bool) {
+    env.storage().instance().set(&ConfigKey::BlockMintToFrozen, &block);
+}
+
+/// Get the vesting schedule of an account, if it received a vested genesis mint
+pub fn get_vesting(env: &Env, account: &Address) -> Option<VestingSchedule> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Vesting(account.clone()))
+}
+
+/// Set the vesting schedule of an account
+pub fn set_vesting(env: &Env, account: &Address, schedule: &VestingSchedule) {
+    env.storage()
+        .persistent()
+        .set(&AccountKey::Vesting(account.clone()), schedule);
+}
+
+/// Get the low balance alert configured for an account
+pub fn get_balance_alert(env: &Env, account: &Address) -> Option<BalanceAlert> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::BalanceAlert(account.clone()))
+}
+
+/// Set or clear the low balance alert for an account
+pub fn set_balance_alert(env: &Env, account: &Address, alert: Option<&BalanceAlert>) {
+    let key = AccountKey::BalanceAlert(account.clone());
+    match alert {
+        Some(alert) => env.storage().persistent().set(&key, alert),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Get the maximum number of lock entries stored per account
+pub fn get_max_locks_per_account(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxLocksPerAccount)
+        .unwrap_or(DEFAULT_MAX_LOCKS_PER_ACCOUNT)
+}
+
+/// Set the maximum number of lock entries stored per account
+pub fn set_max_locks_per_account(env: &Env, max_locks: u32) {
+    env.storage().instance().set(&ConfigKey::MaxLocksPerAccount, &max_locks);
+}
+
+/// Get all balance locks recorded for an account, including released ones
+pub fn get_locks(env: &Env, account: &Address) -> Vec<BalanceLock> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Locks(account.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the balance locks for an account, removing the entry when empty
+pub fn set_locks(env: &Env, account: &Address, locks: &Vec<BalanceLock>) {
+    let key = AccountKey::Locks(account.clone());
+    if locks.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, locks);
+    }
+}
+
+/// Get the sum of all recorded balance locks not yet consolidated
+pub fn get_total_locked(env: &Env) -> i128 {
+    env.storage().instance().get(&StateKey::TotalLocked).unwrap_or(0)
+}
+
+/// Set the sum of all recorded balance locks not yet consolidated
+pub fn set_total_locked(env: &Env, total: i128) {
+    env.storage().instance().set(&StateKey::TotalLocked, &total);
+}
+
+/// ==================== DENYLIST ====================
+
+/// Get the denylist index position of an account, if it is denylisted
+pub fn get_denylist_position(env: &Env, account: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Denylisted(account.clone()))
+}
+
+/// Set the denylist index position of an account
+pub fn set_denylist_position(env: &Env, account: &Address, position: u32) {
+    env.storage()
+        .persistent()
+        .set(&AccountKey::Denylisted(account.clone()), &position);
+}
+
+/// Remove an account's denylist index position
+pub fn remove_denylist_position(env: &Env, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&AccountKey::Denylisted(account.clone()));
+}
+
+/// Get the denylisted account stored at an index position
+pub fn get_denylist_entry(env: &Env, position: u32) -> Option<Address> {
+    env.storage().persistent().get(&RecordKey::DenylistEntry(position))
+}
+
+/// Store a denylisted account at an index position
+pub fn set_denylist_entry(env: &Env, position: u32, account: &Address) {
+    env.storage().persistent().set(&RecordKey::DenylistEntry(position), account);
+}
+
+/// Remove the entry stored at an index position
+pub fn remove_denylist_entry(env: &Env, position: u32) {
+    env.storage().persistent().remove(&RecordKey::DenylistEntry(position));
+}
+
+/// Get the number of denylisted accounts
+pub fn get_denylist_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::DenylistCount)
+        .unwrap_or(0)
+}
+
+/// Set the number of denylisted accounts
+pub fn set_denylist_count(env: &Env, count: u32) {
+    env.storage().instance().set(&StateKey::DenylistCount, &count);
+}
+
+/// Get the sanctions oracle consulted alongside the denylist, if one is configured
+pub fn get_denylist_oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::DenylistOracle)
+}
+
+/// Set or clear the sanctions oracle
+pub fn set_denylist_oracle(env: &Env, oracle: Option<&Address>) {
+    match oracle {
+        Some(oracle) => env.storage().instance().set(&ConfigKey::DenylistOracle, oracle),
+        None => env.storage().instance().remove(&ConfigKey::DenylistOracle),
+    }
+}
+
+/// Get a cached oracle answer for an account as (sanctioned, ledger it is fresh until)
+pub fn get_sanctions_cache(env: &Env, oracle: &Address, account: &Address) -> Option<(bool, u32)> {
+    env.storage()
+        .temporary()
+        .get(&AccountKey::SanctionsCache(oracle.clone(), account.clone()))
+}
+
+/// Cache an oracle answer for an account, fresh for `fresh_ledgers`. The entry is kept one
+/// SANCTIONS_CACHE_TTL_LEDGERS longer so a stale answer can stand in for a failing oracle.
+pub fn set_sanctions_cache(env: &Env, oracle: &Address, account: &Address, sanctioned: bool, fresh_ledgers: u32) {
+    let storage_key = AccountKey::SanctionsCache(oracle.clone(), account.clone());
+    let fresh_until = env.ledger().sequence().saturating_add(fresh_ledgers);
+    env.storage().temporary().set(&storage_key, &(sanctioned, fresh_until));
+    
+    let keep_for = fresh_ledgers.saturating_add(SANCTIONS_CACHE_TTL_LEDGERS);
+    env.storage().temporary().extend_ttl(&storage_key, keep_for, keep_for);
+}
+
+/// ==================== COUNTERPARTY RESTRICTIONS ====================
+
+/// Get the counterparties an account may transfer to
+pub fn get_allowed_counterparties(env: &Env, account: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::AllowedCounterparties(account.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the counterparties an account may transfer to, removing the entry when empty
+pub fn set_allowed_counterparties(env: &Env, account: &Address, counterparties: &Vec<Address>) {
+    let key = AccountKey::AllowedCounterparties(account.clone());
+    if counterparties.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, counterparties);
+    }
+}
+
+/// ==================== ALLOWANCE INDEX ====================
+
+/// Get the spenders an owner has granted allowances to
+pub fn get_spenders(env: &Env, owner: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::Spenders(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the spenders an owner has granted allowances to, removing the entry when empty
+pub fn set_spenders(env: &Env, owner: &Address, spenders: &Vec<Address>) {
+    let key = AccountKey::Spenders(owner.clone());
+    if spenders.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, spenders);
+    }
+}
+
+/// Get the expiration ledger recorded for an allowance
+pub fn get_allowance_expiration(env: &Env, owner: &Address, spender: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::AllowanceExpiration(owner.clone(), spender.clone()))
+}
+
+/// Record or clear the expiration ledger of an allowance
+pub fn set_allowance_expiration(env: &Env, owner: &Address, spender: &Address, expiration: Option<u32>) {
+    let key = AccountKey::AllowanceExpiration(owner.clone(), spender.clone());
+    match expiration {
+        Some(ledger) => env.storage().persistent().set(&key, &ledger),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Get the owner index position of an owner, if it has indexed spenders
+pub fn get_allowance_owner_position(env: &Env, owner: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::AllowanceOwner(owner.clone()))
+}
+
+/// Set the owner index position of an owner
+pub fn set_allowance_owner_position(env: &Env, owner: &Address, position: u32) {
+    env.storage()
+        .persistent()
+        .set(&AccountKey::AllowanceOwner(owner.clone()), &position);
+}
+
+/// Remove an owner's index position
+pub fn remove_allowance_owner_position(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&AccountKey::AllowanceOwner(owner.clone()));
+}
+
+/// Get the owner stored at an index position
+pub fn get_allowance_owner_entry(env: &Env, position: u32) -> Option<Address> {
+    env.storage().persistent().get(&RecordKey::AllowanceOwnerEntry(position))
+}
+
+/// Store an owner at an index position
+pub fn set_allowance_owner_entry(env: &Env, position: u32, owner: &Address) {
+    env.storage().persistent().set(&RecordKey::AllowanceOwnerEntry(position), owner);
+}
+
+/// Remove the owner stored at an index position
+pub fn remove_allowance_owner_entry(env: &Env, position: u32) {
+    env.storage().persistent().remove(&RecordKey::AllowanceOwnerEntry(position));
+}
+
+/// Get the number of owners with indexed spenders
+pub fn get_allowance_owner_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::AllowanceOwnerCount)
+        .unwrap_or(0)
+}
+
+/// Set the number of owners with indexed spenders
+pub fn set_allowance_owner_count(env: &Env, count: u32) {
+    env.storage().instance().set(&StateKey::AllowanceOwnerCount, &count);
+}
+
+/// Get the allowances zeroed by a freezing pause, if one is in effect
+pub fn get_allowance_snapshot(env: &Env) -> Option<Vec<AllowanceSnapshotEntry>> {
+    env.storage().persistent().get(&RecordKey::AllowanceSnapshot)
+}
+
+/// Store or clear the allowances zeroed by a freezing pause
+pub fn set_allowance_snapshot(env: &Env, snapshot: Option<&Vec<AllowanceSnapshotEntry>>) {
+    match snapshot {
+        Some(entries) => env.storage().persistent().set(&RecordKey::AllowanceSnapshot, entries),
+        None => env.storage().persistent().remove(&RecordKey::AllowanceSnapshot),
+    }
+}
+
+/// ==================== DIAGNOSTICS ====================
+
+/// Count the custom persistent entries this contract keeps for an account, including
+/// the per-spender entries of its allowances. Token balances and allowances kept by
+/// `Base` are not included.
+pub fn account_entry_count(env: &Env, account: &Address) -> u32 {
+    let persistent = env.storage().persistent();
+    let keys = [
+        AccountKey::Holder(account.clone()),
+        AccountKey::MinterAllowance(account.clone()),
+        AccountKey::LastMintLedger(account.clone()),
+        AccountKey::Frozen(account.clone()),
+        AccountKey::Locks(account.clone()),
+        AccountKey::Vesting(account.clone()),
+        AccountKey::BalanceAlert(account.clone()),
+        AccountKey::Denylisted(account.clone()),
+        AccountKey::Allowlisted(account.clone()),
+        AccountKey::AllowedCounterparties(account.clone()),
+        AccountKey::Spenders(account.clone()),
+        AccountKey::AllowanceOwner(account.clone()),
+    ];
+    let mut count = keys.iter().filter(|key| persistent.has(*key)).count() as u32;
+    
+    // The dense index slot behind each index position
+    if get_denylist_position(env, account).is_some() {
+        count += 1;
+    }
+    if get_allowance_owner_position(env, account).is_some() {
+        count += 1;
+    }
+    
+    for spender in get_spenders(env, account).iter() {
+        for key in [
+            AccountKey::AllowanceExpiration(account.clone(), spender.clone()),
+            AccountKey::CountedAllowance(account.clone(), spender),
+        ] {
+            if persistent.has(&key) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// ==================== MINT LOG ====================
+
+/// Get the total number of mint log entries ever appended
+pub fn get_mint_log_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::MintLogCount)
+        .unwrap_or(0)
+}
+
+/// Set the total number of mint log entries ever appended
+pub fn set_mint_log_count(env: &Env, count: u32) {
+    env.storage().instance().set(&StateKey::MintLogCount, &count);
+}
+
+/// Get the mint log entry stored in a rolling slot
+pub fn get_mint_log_slot(env: &Env, slot: u32) -> Option<MintLogEntry> {
+    env.storage().persistent().get(&RecordKey::MintLogSlot(slot))
+}
+
+/// Store a mint log entry in a rolling slot, overwriting any previous entry
+pub fn set_mint_log_slot(env: &Env, slot: u32, entry: &MintLogEntry) {
+    env.storage().persistent().set(&RecordKey::MintLogSlot(slot), entry);
+}
+
+/// Get the total number of operation summaries ever appended
+pub fn get_recent_op_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::RecentOpCount)
+        .unwrap_or(0)
+}
+
+/// Set the total number of operation summaries ever appended
+pub fn set_recent_op_count(env: &Env, count: u32) {
+    env.storage().instance().set(&StateKey::RecentOpCount, &count);
+}
+
+/// Get the operation summary stored in a rolling slot
+pub fn get_recent_op_slot(env: &Env, slot: u32) -> Option<OpSummary> {
+    env.storage().persistent().get(&RecordKey::RecentOpSlot(slot))
+}
+
+/// Store an operation summary in a rolling slot, overwriting any previous entry
+pub fn set_recent_op_slot(env: &Env, slot: u32, entry: &OpSummary) {
+    env.storage().persistent().set(&RecordKey::RecentOpSlot(slot), entry);
+}
+
+/// Extend the TTL of the contract-wide persistent entries: the allowance snapshot, the
+/// recent operation ring and the newest RECENT_OPS_CAPACITY mint log slots. Bounded so
+/// the footprint stays small; per-account entries are extended by their own activity.
+pub fn extend_shared_persistent_ttl(env: &Env, extend_to: u32) {
+    let persistent = env.storage().persistent();
+    let mut keys = vec![env, RecordKey::AllowanceSnapshot];
+    for slot in 0..RECENT_OPS_CAPACITY {
+        keys.push_back(RecordKey::RecentOpSlot(slot));
+    }
+    let count = get_mint_log_count(env);
+    for index in count.saturating_sub(RECENT_OPS_CAPACITY)..count {
+        keys.push_back(RecordKey::MintLogSlot(index % MINT_LOG_CAPACITY));
+    }
+    
+    for key in keys.iter() {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, extend_to, extend_to);
+        }
+    }
+}
+
+/// Get the admin nominated by a pending two-step admin transfer
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StateKey::PendingAdmin)
+}
+
+/// Set or clear the admin nominated by a two-step admin transfer
+pub fn set_pending_admin(env: &Env, pending: Option<&Address>) {
+    match pending {
+        Some(account) => env.storage().instance().set(&StateKey::PendingAdmin, account),
+        None => env.storage().instance().remove(&StateKey::PendingAdmin),
+    }
+}
+
+/// ==================== TIMELOCK ====================
+
+/// Get the minimum delay between scheduling a timelocked action and its eta
+pub fn get_timelock_delay(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::TimelockDelay)
+        .unwrap_or(DEFAULT_TIMELOCK_DELAY_LEDGERS)
+}
+
+/// Set the minimum delay between scheduling a timelocked action and its eta
+pub fn set_timelock_delay(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&ConfigKey::TimelockDelay, &ledgers);
+}
+
+/// Get a scheduled timelocked action by hash
+pub fn get_scheduled_action(env: &Env, action_hash: &BytesN<32>) -> Option<ScheduledAction> {
+    env.storage()
+        .persistent()
+        .get(&RecordKey::ScheduledAction(action_hash.clone()))
+}
+
+/// Store a scheduled timelocked action, or remove it with `None`
+pub fn set_scheduled_action(env: &Env, action_hash: &BytesN<32>, action: Option<&ScheduledAction>) {
+    let key = RecordKey::ScheduledAction(action_hash.clone());
+    match action {
+        Some(action) => env.storage().persistent().set(&key, action),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Get the sequence number of the last state-changing operation
+pub fn get_op_seq(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&StateKey::OpSeq)
+        .unwrap_or(0)
+}
+
+/// Set the sequence number of the last state-changing operation
+pub fn set_op_seq(env: &Env, op_seq: u64) {
+    env.storage().instance().set(&StateKey::OpSeq, &op_seq);
+}
+
+/// Get the ledger the contract was initialized in
+pub fn get_init_ledger(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::InitLedger)
+        .unwrap_or(0)
+}
+
+/// Set the ledger the contract was initialized in
+pub fn set_init_ledger(env: &Env, ledger: u32) {
+    env.storage().instance().set(&StateKey::InitLedger, &ledger);
+}
+
+/// Check whether the initialization ledger was recorded (layouts before version 2 lack it)
+pub fn has_init_ledger(env: &Env) -> bool {
+    env.storage().instance().has(&StateKey::InitLedger)
+}
+
+/// Get the storage layout version, defaulting to the pre-versioning layout
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::StorageVersion)
+        .unwrap_or(LEGACY_STORAGE_VERSION)
+}
+
+/// Set the storage layout version
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage().instance().set(&StateKey::StorageVersion, &version);
+}
+
+/// Get the ledger of the last state-changing operation
+pub fn get_last_activity_ledger(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::LastActivityLedger)
+        .unwrap_or(0)
+}
+
+/// Set the ledger of the last state-changing operation
+pub fn set_last_activity_ledger(env: &Env, ledger: u32) {
+    env.storage().instance().set(&StateKey::LastActivityLedger, &ledger);
+}
@@ -3,8 +3,171 @@
 
 #[cfg(test)]
 mod test {
-    use soroban_sdk::{testutils::Address as _, Address, Env, Vec, String};
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short, token,
+        testutils::{storage::Instance as _, Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+        vec, Address, Bytes, BytesN, Env, IntoVal, Vec, String, Symbol, Val,
+    };
     use crate::contract::{MyStablecoin, MyStablecoinClient};
+    use crate::extensions::PauseStatus;
+    use crate::storage;
+    use crate::utils;
+    use crate::types::{AccountCompliance, AccountRecovered, AdminApproval, BalanceLock, EffectiveLimits, EmergencyAction, BatchSummary, SystemAddresses, TtlMaintenance, ConfigChanged, DisplayAmount, DuplicateSuppressed, FeeDisposition, GenesisBacking, LowBalance, OpSummary, ProtocolBurn, UserBurn, StablecoinConfig, StablecoinError, MINT_LOG_CAPACITY, MIN_RECOVERY_DELAY_LEDGERS, DECIMALS, MAX_ALLOWANCE_SNAPSHOT, MAX_SINGLE_OPERATION, MAX_SPENDERS_PER_OWNER, MAX_SUPPLY, MIN_AMOUNT, SANCTIONS_CACHE_TTL_LEDGERS, VOLUME_WINDOW_LEDGERS};
+
+    /// Mock dependent contract recording every pause notification it receives
+    #[contract]
+    pub struct MockPauseSubscriber;
+
+    #[contractimpl]
+    impl MockPauseSubscriber {
+        pub fn on_pause_changed(env: Env, paused: bool) {
+            let mut log: Vec<bool> = env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env));
+            log.push_back(paused);
+            env.storage().instance().set(&symbol_short!("log"), &log);
+        }
+
+        pub fn notifications(env: Env) -> Vec<bool> {
+            env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env))
+        }
+    }
+
+    /// Mock dependent contract that always fails when notified
+    #[contract]
+    pub struct FailingPauseSubscriber;
+
+    #[contractimpl]
+    impl FailingPauseSubscriber {
+        pub fn on_pause_changed(_env: Env, _paused: bool) {
+            panic!("subscriber failure");
+        }
+    }
+
+    /// Mock settlement contract that records every redemption it is asked to settle
+    #[contract]
+    pub struct MockSettlement;
+
+    #[contractimpl]
+    impl MockSettlement {
+        pub fn settle_redemption(env: Env, from: Address, amount: i128) {
+            let mut log: Vec<(Address, i128)> = env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env));
+            log.push_back((from, amount));
+            env.storage().instance().set(&symbol_short!("log"), &log);
+        }
+
+        pub fn settlements(env: Env) -> Vec<(Address, i128)> {
+            env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env))
+        }
+    }
+
+    /// Mock settlement contract that always fails
+    #[contract]
+    pub struct FailingSettlement;
+
+    #[contractimpl]
+    impl FailingSettlement {
+        pub fn settle_redemption(_env: Env, _from: Address, _amount: i128) {
+            panic!("settlement failure");
+        }
+    }
+
+    /// Mock transfer-and-call receiver recording each callback
+    #[contract]
+    pub struct MockReceiver;
+
+    #[contractimpl]
+    impl MockReceiver {
+        pub fn on_token_transfer(env: Env, from: Address, amount: i128, data: Bytes) {
+            let mut log: Vec<(Address, i128, Bytes)> = env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env));
+            log.push_back((from, amount, data));
+            env.storage().instance().set(&symbol_short!("log"), &log);
+        }
+
+        pub fn received(env: Env) -> Vec<(Address, i128, Bytes)> {
+            env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env))
+        }
+    }
+
+    /// Mock transfer-and-call receiver that rejects every transfer
+    #[contract]
+    pub struct RejectingReceiver;
+
+    #[contractimpl]
+    impl RejectingReceiver {
+        pub fn on_token_transfer(_env: Env, _from: Address, _amount: i128, _data: Bytes) {
+            panic!("transfer rejected");
+        }
+    }
+
+    /// Mock post-upgrade hook recording each migration it runs
+    #[contract]
+    pub struct MockUpgradeHook;
+
+    #[contractimpl]
+    impl MockUpgradeHook {
+        pub fn on_upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+            let mut log: Vec<BytesN<32>> = env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env));
+            log.push_back(new_wasm_hash);
+            env.storage().instance().set(&symbol_short!("log"), &log);
+        }
+
+        pub fn migrations(env: Env) -> Vec<BytesN<32>> {
+            env.storage().instance().get(&symbol_short!("log")).unwrap_or(Vec::new(&env))
+        }
+    }
+
+    /// Mock post-upgrade hook whose migration always fails
+    #[contract]
+    pub struct FailingUpgradeHook;
+
+    #[contractimpl]
+    impl FailingUpgradeHook {
+        pub fn on_upgrade(_env: Env, _new_wasm_hash: BytesN<32>) {
+            panic!("migration failure");
+        }
+    }
+
+    /// Mock sanctions registry flagging the addresses it is told about
+    #[contract]
+    pub struct MockSanctionsOracle;
+
+    #[contractimpl]
+    impl MockSanctionsOracle {
+        pub fn set_sanctioned(env: Env, account: Address, sanctioned: bool) {
+            env.storage().persistent().set(&account, &sanctioned);
+        }
+
+        pub fn set_failing(env: Env, failing: bool) {
+            env.storage().instance().set(&symbol_short!("failing"), &failing);
+        }
+
+        pub fn is_sanctioned(env: Env, account: Address) -> bool {
+            if env.storage().instance().get(&symbol_short!("failing")).unwrap_or(false) {
+                panic!("oracle unavailable");
+            }
+            env.storage().persistent().get(&account).unwrap_or(false)
+        }
+    }
+
+    /// Register and initialize the contract, returning the client and the
+    /// (admin, pauser, upgrader, minter) addresses
+    fn setup(env: &Env) -> (MyStablecoinClient<'_>, Address, Address, Address, Address) {
+        // Generous TTLs so tests can advance the ledger without archiving entries
+        env.ledger().with_mut(|li| {
+            li.min_persistent_entry_ttl = 1_000_000;
+            li.min_temp_entry_ttl = 1_000_000;
+            li.max_entry_ttl = 10_000_000;
+        });
+        
+        let admin = Address::generate(env);
+        let minter = Address::generate(env);
+        let pauser = Address::generate(env);
+        let upgrader = Address::generate(env);
+        
+        let contract = MyStablecoinClient::new(env, &env.register(MyStablecoin, ()));
+        contract.initialize(&admin, &pauser, &upgrader, &minter);
+        
+        (contract, admin, pauser, upgrader, minter)
+    }
 
     #[test]
     fn test_basic_functionality() {
@@ -416,6 +579,5034 @@ mod test {
         // Verify decimals is 2
         assert_eq!(contract.decimals(), 2);
     }
-}
 
+    #[test]
+    fn test_max_batch_size() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        
+        // Default cap comes from the constant
+        assert_eq!(contract.get_max_batch_size(), 100);
+        
+        // Lower the cap to make the boundary easy to exercise
+        contract.set_max_batch_size(&admin, &3);
+        assert_eq!(contract.get_max_batch_size(), 3);
+        
+        // Exactly the maximum is accepted
+        let mut recipients = Vec::new(&env);
+        for _ in 0..3 {
+            recipients.push_back((Address::generate(&env), 100));
+        }
+        contract.batch_mint(&minter, &recipients);
+        assert_eq!(contract.total_supply(), 300);
+        
+        // One over the maximum is rejected for both batch methods
+        recipients.push_back((Address::generate(&env), 100));
+        let result = contract.try_batch_mint(&minter, &recipients);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert_eq!(contract.total_supply(), 300);
+        
+        let holder = recipients.get(0).unwrap().0;
+        let mut transfers = Vec::new(&env);
+        for _ in 0..4 {
+            transfers.push_back((Address::generate(&env), 10));
+        }
+        let result = contract.try_batch_transfer(&holder, &transfers);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Batch transfer at the boundary succeeds on a single signature from the sender
+        transfers.pop_back();
+        contract
+            .mock_auths(&[MockAuth {
+                address: &holder,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "batch_transfer",
+                    args: (&holder, transfers.clone()).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .batch_transfer(&holder, &transfers);
+        assert_eq!(contract.balance(&holder), 70);
+        
+        // Without the sender's signature the batch is refused
+        env.set_auths(&[]);
+        assert!(contract.try_batch_transfer(&holder, &transfers).is_err());
+        assert_eq!(contract.balance(&holder), 70);
+        env.mock_all_auths();
+        
+        // Zero is not a valid cap and only the admin may change it
+        let result = contract.try_set_max_batch_size(&admin, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        let result = contract.try_set_max_batch_size(&minter, &10);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_mint_idempotency_key() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let key = BytesN::from_array(&env, &[1u8; 32]);
+        
+        // First submission mints
+        assert!(!contract.is_mint_key_processed(&key));
+        contract.mint_idempotent(&minter, &user, &1000, &key);
+        assert_eq!(contract.balance(&user), 1000);
+        assert!(contract.is_mint_key_processed(&key));
+        
+        // Replaying the same key is a no-op by default
+        contract.mint_idempotent(&minter, &user, &1000, &key);
+        assert_eq!(contract.balance(&user), 1000);
+        assert_eq!(contract.total_supply(), 1000);
+        
+        // In strict mode the replay is reported as a duplicate
+        contract.set_reject_duplicate_mints(&admin, &true);
+        let result = contract.try_mint_idempotent(&minter, &user, &1000, &key);
+        assert_eq!(result, Err(Ok(StablecoinError::DuplicateOperation)));
+        assert_eq!(contract.balance(&user), 1000);
+        
+        // A fresh key still mints
+        let other_key = BytesN::from_array(&env, &[2u8; 32]);
+        contract.mint_idempotent(&minter, &user, &500, &other_key);
+        assert_eq!(contract.balance(&user), 1500);
+    }
+
+    #[test]
+    fn test_pause_subscribers_notified() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, _minter) = setup(&env);
+        
+        let subscriber_id = env.register(MockPauseSubscriber, ());
+        let subscriber = MockPauseSubscriberClient::new(&env, &subscriber_id);
+        let failing_id = env.register(FailingPauseSubscriber, ());
+        
+        // Register a failing subscriber first so it cannot mask later ones
+        contract.add_pause_subscriber(&admin, &failing_id);
+        contract.add_pause_subscriber(&admin, &subscriber_id);
+        assert_eq!(contract.get_pause_subscribers().len(), 2);
+        
+        // Duplicates are rejected
+        let result = contract.try_add_pause_subscriber(&admin, &subscriber_id);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Pause and unpause succeed despite the failing subscriber
+        contract.pause(&pauser);
+        assert!(contract.is_paused());
+        contract.unpause(&pauser);
+        assert!(!contract.is_paused());
+        
+        let notifications = subscriber.notifications();
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(notifications.get(0), Some(true));
+        assert_eq!(notifications.get(1), Some(false));
+        
+        // Removed subscribers stop receiving notifications
+        contract.remove_pause_subscriber(&admin, &subscriber_id);
+        contract.pause(&pauser);
+        assert_eq!(subscriber.notifications().len(), 2);
+    }
+
+    #[test]
+    fn test_transfer_from_decrements_allowance_exactly() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &500, &1000);
+        
+        // A single transfer_from debits the allowance by exactly the amount
+        contract.transfer_from(&spender, &owner, &recipient, &200);
+        assert_eq!(contract.allowance(&owner, &spender), 300);
+        assert_eq!(contract.balance(&recipient), 200);
+        
+        // Spending the remainder leaves exactly zero
+        contract.transfer_from(&spender, &owner, &recipient, &300);
+        assert_eq!(contract.allowance(&owner, &spender), 0);
+        assert_eq!(contract.balance(&owner), 500);
+        
+        // Nothing further can be pulled
+        let result = contract.try_transfer_from(&spender, &owner, &recipient, &1);
+        assert!(result.is_err());
+        assert_eq!(contract.balance(&owner), 500);
+    }
+
+    #[test]
+    fn test_burn_from_decrements_allowance_exactly() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &500, &1000);
+        
+        contract.burn_from(&spender, &owner, &200);
+        assert_eq!(contract.allowance(&owner, &spender), 300);
+        assert_eq!(contract.total_supply(), 800);
+    }
+
+    #[test]
+    fn test_spendable_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let unrestricted = Address::generate(&env);
+        let locked = Address::generate(&env);
+        let frozen = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        contract.mint(&minter, &unrestricted, &1000);
+        contract.mint(&minter, &locked, &1000);
+        contract.mint(&minter, &frozen, &1000);
+        
+        // Unrestricted account can spend its full balance
+        assert_eq!(contract.spendable_balance(&unrestricted), 1000);
+        
+        // Partially locked account can only spend the unlocked part
+        contract.lock_balance(&admin, &locked, &400, &100);
+        assert_eq!(contract.locked_balance(&locked), 400);
+        assert_eq!(contract.spendable_balance(&locked), 600);
+        let result = contract.try_transfer(&locked, &recipient, &601);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        contract.transfer(&locked, &recipient, &600);
+        assert_eq!(contract.spendable_balance(&locked), 0);
+        
+        // Frozen account has nothing spendable
+        contract.freeze_account(&admin, &frozen);
+        assert!(contract.is_frozen(&frozen));
+        assert_eq!(contract.spendable_balance(&frozen), 0);
+        let result = contract.try_transfer(&frozen, &recipient, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountFrozen)));
+        
+        // Restrictions lift once the lock releases and the account is unfrozen
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        assert_eq!(contract.spendable_balance(&locked), 400);
+        contract.unfreeze_account(&admin, &frozen);
+        assert_eq!(contract.spendable_balance(&frozen), 1000);
+        
+        // Only admin or compliance can restrict accounts
+        let result = contract.try_freeze_account(&minter, &unrestricted);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_storage_keys_are_isolated() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let account = Address::generate(&env);
+        let other = Address::generate(&env);
+        
+        contract.mint(&minter, &account, &1000);
+        
+        env.as_contract(&contract.address, || {
+            // Per-account keys do not leak between accounts or key kinds
+            storage::set_frozen(&env, &account, true);
+            assert!(storage::is_frozen(&env, &account));
+            assert!(!storage::is_frozen(&env, &other));
+            assert!(storage::get_locks(&env, &account).is_empty());
+            
+            let mut locks = Vec::new(&env);
+            locks.push_back(BalanceLock { amount: 100, release_ledger: 10 });
+            storage::set_locks(&env, &other, &locks);
+            assert_eq!(storage::get_locks(&env, &other), locks);
+            assert!(storage::get_locks(&env, &account).is_empty());
+            assert!(!storage::is_frozen(&env, &other));
+            
+            // Instance configuration keys are independent of each other
+            storage::set_max_batch_size(&env, 7);
+            assert!(!storage::get_reject_duplicate_mints(&env));
+            storage::set_reject_duplicate_mints(&env, true);
+            assert_eq!(storage::get_max_batch_size(&env), 7);
+            assert!(storage::get_pause_subscribers(&env).is_empty());
+        });
+        
+        // Custom keys never disturb the OZ Base token storage
+        assert_eq!(contract.balance(&account), 1000);
+        assert_eq!(contract.total_supply(), 1000);
+        assert_eq!(contract.name(), String::from_str(&env, "Costa Rica Colon"));
+    }
+
+    #[test]
+    fn test_mint_log_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+        
+        env.ledger().with_mut(|li| li.sequence_number = 10);
+        contract.mint(&minter, &user1, &100);
+        contract.mint(&minter, &user2, &200);
+        
+        let mut recipients = Vec::new(&env);
+        recipients.push_back((user1.clone(), 300));
+        recipients.push_back((user2.clone(), 400));
+        contract.batch_mint(&minter, &recipients);
+        
+        env.ledger().with_mut(|li| li.sequence_number = 11);
+        contract.mint(&minter, &user1, &500);
+        
+        assert_eq!(contract.get_mint_log_count(), 5);
+        
+        // First page
+        let page = contract.get_mint_log(&0, &2);
+        assert_eq!(page.len(), 2);
+        let first = page.get(0).unwrap();
+        assert_eq!(first.ledger, 10);
+        assert_eq!(first.minter, minter);
+        assert_eq!(first.to, user1);
+        assert_eq!(first.amount, 100);
+        assert_eq!(page.get(1).unwrap().amount, 200);
+        
+        // Second page includes the batch entries
+        let page = contract.get_mint_log(&2, &2);
+        assert_eq!(page.get(0).unwrap().amount, 300);
+        assert_eq!(page.get(1).unwrap().amount, 400);
+        
+        // Last page is truncated at the end of the log
+        let page = contract.get_mint_log(&4, &10);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().ledger, 11);
+        assert_eq!(page.get(0).unwrap().amount, 500);
+        
+        // Reading past the end returns nothing
+        assert!(contract.get_mint_log(&5, &10).is_empty());
+    }
+
+    #[test]
+    fn test_mint_log_rolling_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        env.cost_estimate().budget().reset_unlimited();
+        
+        // Overflow the window by two entries
+        for i in 0..(MINT_LOG_CAPACITY + 2) {
+            env.as_contract(&contract.address, || {
+                crate::utils::record_mint(&env, &minter, &user, (i + 1) as i128);
+            });
+        }
+        
+        assert_eq!(contract.get_mint_log_count(), MINT_LOG_CAPACITY + 2);
+        
+        // The two oldest entries have been evicted
+        let page = contract.get_mint_log(&0, &1);
+        assert_eq!(page.get(0).unwrap().amount, 3);
+        
+        let page = contract.get_mint_log(&(MINT_LOG_CAPACITY + 1), &1);
+        assert_eq!(page.get(0).unwrap().amount, (MINT_LOG_CAPACITY + 2) as i128);
+    }
+
+    #[test]
+    fn test_self_transfer_toggle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        contract.mint(&minter, &user, &1000);
+        
+        // Rejected by default
+        assert!(!contract.allows_self_transfer());
+        let result = contract.try_transfer(&user, &user, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::SelfTransfer)));
+        
+        // Permitted once enabled, leaving the balance unchanged
+        contract.set_allow_self_transfer(&admin, &true);
+        assert!(contract.allows_self_transfer());
+        contract.transfer(&user, &user, &100);
+        assert_eq!(contract.balance(&user), 1000);
+        assert_eq!(contract.total_supply(), 1000);
+        
+        // Disabling restores the rejection
+        contract.set_allow_self_transfer(&admin, &false);
+        let result = contract.try_transfer(&user, &user, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::SelfTransfer)));
+    }
+
+    #[test]
+    fn test_total_approved_across_spenders() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender1 = Address::generate(&env);
+        let spender2 = Address::generate(&env);
+        let spender3 = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        contract.mint(&minter, &owner, &1000);
+        assert_eq!(contract.total_approved(&owner), 0);
+        
+        contract.approve(&owner, &spender1, &100, &1000);
+        contract.approve(&owner, &spender2, &200, &1000);
+        contract.approve(&owner, &spender3, &300, &50);
+        assert_eq!(contract.total_approved(&owner), 600);
+        assert_eq!(contract.get_allowances(&owner).len(), 3);
+        
+        // Re-approving replaces rather than adds
+        contract.approve(&owner, &spender1, &150, &1000);
+        assert_eq!(contract.total_approved(&owner), 650);
+        
+        // Spending reduces the exposure
+        contract.transfer_from(&spender2, &owner, &recipient, &50);
+        assert_eq!(contract.total_approved(&owner), 600);
+        
+        // Expired allowances no longer count
+        env.ledger().with_mut(|li| li.sequence_number = 51);
+        assert_eq!(contract.total_approved(&owner), 300);
+        assert_eq!(contract.get_allowances(&owner).len(), 2);
+    }
+
+    #[test]
+    fn test_governed_burn_symmetric_authorization() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &10_000);
+        
+        contract.set_symmetric_authorization(&admin, &true, &1_000);
+        assert_eq!(contract.get_symmetric_authorization(), (true, 1_000));
+        
+        // A small burn only needs the holder's signature
+        contract.governed_burn(&holder, &admin, &500);
+        let auths = env.auths();
+        assert!(auths.iter().any(|(address, _)| *address == holder));
+        assert!(!auths.iter().any(|(address, _)| *address == admin));
+        
+        // A large burn needs both the holder and the admin
+        contract.governed_burn(&holder, &admin, &2_000);
+        let auths = env.auths();
+        assert!(auths.iter().any(|(address, _)| *address == holder));
+        assert!(auths.iter().any(|(address, _)| *address == admin));
+        assert_eq!(contract.balance(&holder), 7_500);
+        
+        // A non-admin co-signer cannot authorize a large burn
+        let result = contract.try_governed_burn(&holder, &minter, &2_000);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        assert_eq!(contract.balance(&holder), 7_500);
+    }
+
+    #[test]
+    fn test_denylist_export() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        let d = Address::generate(&env);
+        
+        contract.add_to_denylist(&admin, &a);
+        contract.add_to_denylist(&admin, &b);
+        contract.add_to_denylist(&admin, &c);
+        contract.add_to_denylist(&admin, &d);
+        assert_eq!(contract.denylist_count(), 4);
+        
+        // Duplicate additions are rejected
+        let result = contract.try_add_to_denylist(&admin, &a);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Remove from the middle; the index stays dense
+        contract.remove_from_denylist(&admin, &b);
+        assert_eq!(contract.denylist_count(), 3);
+        assert!(!contract.is_denylisted(&b));
+        
+        let page1 = contract.export_denylist(&admin, &0, &2);
+        let page2 = contract.export_denylist(&admin, &2, &2);
+        assert_eq!(page1.len(), 2);
+        assert_eq!(page2.len(), 1);
+        
+        let mut exported = page1.clone();
+        exported.append(&page2);
+        assert!(exported.contains(&a));
+        assert!(exported.contains(&c));
+        assert!(exported.contains(&d));
+        assert!(!exported.contains(&b));
+        
+        // Export is restricted to compliance
+        let result = contract.try_export_denylist(&minter, &0, &10);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_denylist_blocks_transfers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let sanctioned = Address::generate(&env);
+        
+        contract.mint(&minter, &user, &1000);
+        contract.mint(&minter, &sanctioned, &1000);
+        contract.add_to_denylist(&admin, &sanctioned);
+        
+        assert_eq!(contract.spendable_balance(&sanctioned), 0);
+        let result = contract.try_transfer(&sanctioned, &user, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        let result = contract.try_transfer(&user, &sanctioned, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        let result = contract.try_mint(&minter, &sanctioned, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+    }
+
+    #[test]
+    fn test_validate_init_params() {
+        let env = Env::default();
+        
+        let admin = Address::generate(&env);
+        let pauser = Address::generate(&env);
+        let upgrader = Address::generate(&env);
+        let minter = Address::generate(&env);
+        
+        // Validation runs against an uninitialized contract and writes nothing
+        let contract = MyStablecoinClient::new(&env, &env.register(MyStablecoin, ()));
+        let name = String::from_str(&env, "Costa Rica Colon");
+        let symbol = String::from_str(&env, "CRCX");
+        
+        // Valid configuration
+        contract.validate_init_params(&admin, &pauser, &upgrader, &minter, &name, &symbol, &2);
+        assert!(contract.get_admin().is_none());
+        
+        // Empty name
+        let result = contract.try_validate_init_params(
+            &admin, &pauser, &upgrader, &minter, &String::from_str(&env, ""), &symbol, &2,
+        );
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Over-length symbol
+        let result = contract.try_validate_init_params(
+            &admin, &pauser, &upgrader, &minter, &name, &String::from_str(&env, "WAYTOOLONGSYMBOL"), &2,
+        );
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Out-of-bounds decimals
+        let result = contract.try_validate_init_params(&admin, &pauser, &upgrader, &minter, &name, &symbol, &19);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Shared role holders are allowed unless distinct holders are required
+        contract.validate_init_params(&admin, &admin, &admin, &admin, &name, &symbol, &2);
+    }
 
+    #[test]
+    fn test_counterparty_whitelist() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let settlement = Address::generate(&env);
+        let approved = Address::generate(&env);
+        let other = Address::generate(&env);
+        let spender = Address::generate(&env);
+        
+        contract.mint(&minter, &settlement, &1000);
+        
+        // Unrestricted by default
+        contract.transfer(&settlement, &other, &100);
+        
+        // Once a counterparty is approved, only approved recipients are allowed
+        contract.add_allowed_counterparty(&settlement, &settlement, &approved);
+        assert_eq!(contract.get_allowed_counterparties(&settlement).len(), 1);
+        
+        contract.transfer(&settlement, &approved, &100);
+        assert_eq!(contract.balance(&approved), 100);
+        
+        let result = contract.try_transfer(&settlement, &other, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::CounterpartyNotAllowed)));
+        
+        // The restriction also applies to delegated transfers
+        contract.approve(&settlement, &spender, &500, &1000);
+        let result = contract.try_transfer_from(&spender, &settlement, &other, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::CounterpartyNotAllowed)));
+        contract.transfer_from(&spender, &settlement, &approved, &100);
+        
+        // Other accounts cannot manage someone else's list
+        let result = contract.try_add_allowed_counterparty(&other, &settlement, &other);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // Removing the last counterparty lifts the restriction
+        contract.remove_allowed_counterparty(&settlement, &settlement, &approved);
+        contract.transfer(&settlement, &other, &100);
+        assert_eq!(contract.balance(&other), 200);
+    }
+
+    #[test]
+    fn test_pause_single_method() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let approve = Symbol::new(&env, "approve");
+        
+        contract.mint(&minter, &owner, &1000);
+        
+        // Pause only approve
+        contract.pause_method(&pauser, &approve);
+        assert_eq!(contract.paused_methods().len(), 1);
+        assert!(!contract.is_paused());
+        
+        let result = contract.try_approve(&owner, &spender, &100, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        
+        // Everything else keeps working
+        contract.mint(&minter, &owner, &100);
+        contract.transfer(&owner, &recipient, &100);
+        contract.burn(&owner, &100);
+        assert_eq!(contract.balance(&owner), 900);
+        
+        // Only pausers can pause methods
+        let result = contract.try_pause_method(&minter, &Symbol::new(&env, "transfer"));
+        assert!(result.is_err());
+        
+        // Unpausing restores the method
+        contract.unpause_method(&pauser, &approve);
+        assert!(contract.paused_methods().is_empty());
+        contract.approve(&owner, &spender, &100, &1000);
+        assert_eq!(contract.allowance(&owner, &spender), 100);
+        
+        // Unpausing a method that is not paused is rejected
+        let result = contract.try_unpause_method(&pauser, &approve);
+        assert_eq!(result, Err(Ok(StablecoinError::NotPaused)));
+        
+        // Unknown names are rejected instead of being recorded as paused
+        let result = contract.try_pause_method(&pauser, &Symbol::new(&env, "not_a_method"));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert!(contract.paused_methods().is_empty());
+        
+        // Each method checks its own name: pausing `transfer` leaves the memo variant running
+        contract.pause_method(&pauser, &Symbol::new(&env, "transfer"));
+        let result = contract.try_transfer(&owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        contract.transfer_with_memo(&owner, &recipient, &100, &String::from_str(&env, "REF-1"));
+        contract.pause_method(&pauser, &Symbol::new(&env, "transfer_with_memo"));
+        let result = contract.try_transfer_with_memo(&owner, &recipient, &100, &String::from_str(&env, "REF-1"));
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        
+        // Airdrops are paused under their own name
+        contract.pause_method(&pauser, &Symbol::new(&env, "airdrop"));
+        let result = contract.try_airdrop(&minter, &100, &vec![&env, recipient.clone()]);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        contract.batch_mint(&minter, &vec![&env, (recipient.clone(), 100)]);
+    }
+
+    #[test]
+    fn test_admin_recovery_timelock() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let guardian = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let delay = MIN_RECOVERY_DELAY_LEDGERS;
+        
+        contract.set_recovery_config(&admin, &recovery, &delay);
+        contract.add_guardian(&admin, &guardian);
+        
+        // Non-guardians cannot start recovery
+        let result = contract.try_initiate_recovery(&recovery);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // Guardian starts the clock
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        let claimable_at = contract.initiate_recovery(&guardian);
+        assert_eq!(claimable_at, 100 + delay);
+        assert_eq!(contract.recovery_pending_until(), Some(claimable_at));
+        
+        // Claiming before the deadline fails
+        env.ledger().with_mut(|li| li.sequence_number = claimable_at - 1);
+        let result = contract.try_claim_admin(&recovery);
+        assert_eq!(result, Err(Ok(StablecoinError::TimelockNotExpired)));
+        
+        // Only the configured recovery address can claim
+        env.ledger().with_mut(|li| li.sequence_number = claimable_at);
+        let result = contract.try_claim_admin(&guardian);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        contract.claim_admin(&recovery);
+        assert_eq!(contract.get_admin(), Some(recovery.clone()));
+        assert_eq!(contract.recovery_pending_until(), None);
+        
+        // The new admin can use admin functions, the old one cannot
+        contract.set_max_batch_size(&recovery, &10);
+        let result = contract.try_set_max_batch_size(&admin, &20);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_admin_recovery_cancellation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let guardian = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        
+        contract.set_recovery_config(&admin, &recovery, &MIN_RECOVERY_DELAY_LEDGERS);
+        contract.add_guardian(&admin, &guardian);
+        
+        // Delays shorter than the minimum are rejected
+        let result = contract.try_set_recovery_config(&admin, &recovery, &(MIN_RECOVERY_DELAY_LEDGERS - 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        let claimable_at = contract.initiate_recovery(&guardian);
+        
+        // The active admin cancels before the deadline
+        contract.cancel_recovery(&admin);
+        assert_eq!(contract.recovery_pending_until(), None);
+        
+        // Nothing can be claimed after cancellation, even past the old deadline
+        env.ledger().with_mut(|li| li.sequence_number = claimable_at);
+        let result = contract.try_claim_admin(&recovery);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert_eq!(contract.get_admin(), Some(admin));
+    }
+
+    #[test]
+    fn test_can_spend() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &500, &100);
+        
+        // Success case
+        contract.can_spend(&spender, &owner, &recipient, &500);
+        
+        // Insufficient allowance
+        let result = contract.try_can_spend(&spender, &owner, &recipient, &501);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientAllowance)));
+        
+        // Insufficient balance
+        contract.approve(&owner, &spender, &2000, &100);
+        let result = contract.try_can_spend(&spender, &owner, &recipient, &1500);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // Frozen owner
+        contract.freeze_account(&admin, &owner);
+        let result = contract.try_can_spend(&spender, &owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountFrozen)));
+        contract.unfreeze_account(&admin, &owner);
+        
+        // Denylisted spender
+        contract.add_to_denylist(&admin, &spender);
+        let result = contract.try_can_spend(&spender, &owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        contract.remove_from_denylist(&admin, &spender);
+        
+        // Paused contract
+        contract.pause(&pauser);
+        let result = contract.try_can_spend(&spender, &owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        contract.unpause(&pauser);
+        
+        // Expired allowance
+        env.ledger().with_mut(|li| li.sequence_number = 101);
+        let result = contract.try_can_spend(&spender, &owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AllowanceExpired)));
+        
+        // transfer_from reports the same reason
+        let result = contract.try_transfer_from(&spender, &owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AllowanceExpired)));
+    }
+
+    #[test]
+    fn test_currency_code() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        
+        // Seeded at initialization
+        assert_eq!(contract.currency(), Symbol::new(&env, "CRC"));
+        
+        // Admin can change it to another valid code
+        contract.set_currency(&admin, &String::from_str(&env, "USD"));
+        assert_eq!(contract.currency(), Symbol::new(&env, "USD"));
+        contract.set_currency(&admin, &String::from_str(&env, "USDC"));
+        assert_eq!(contract.currency(), Symbol::new(&env, "USDC"));
+        
+        // Invalid codes are rejected
+        for code in ["US", "USDXX", "usd", "U1D"] {
+            let result = contract.try_set_currency(&admin, &String::from_str(&env, code));
+            assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        }
+        assert_eq!(contract.currency(), Symbol::new(&env, "USDC"));
+    }
+
+    #[test]
+    fn test_burn_with_memo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        
+        let memo = String::from_str(&env, "REDEEM-2024-0001");
+        contract.burn_with_memo(&holder, &400, &memo);
+        assert_eq!(contract.balance(&holder), 600);
+        assert_eq!(contract.total_supply(), 600);
+        
+        // The memo-carrying event is the last one published
+        let last = env.events().all().last().unwrap();
+        assert_eq!(
+            vec![&env, last],
+            vec![
+                &env,
+                (
+                    contract.address.clone(),
+                    (Symbol::new(&env, "burn_with_memo"), holder.clone()).into_val(&env),
+                    (
+                        contract.current_op_seq(),
+                        (
+                            400_i128,
+                            memo.clone(),
+                            DisplayAmount { text: String::from_str(&env, "4.00"), currency: Symbol::new(&env, "CRC") },
+                        ),
+                    ).into_val(&env),
+                ),
+            ]
+        );
+        
+        // Empty and oversized memos are rejected
+        let result = contract.try_burn_with_memo(&holder, &100, &String::from_str(&env, ""));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        let long_memo = String::from_str(&env, "0123456789012345678901234567890123456789012345678901234567890123456789");
+        let result = contract.try_burn_with_memo(&holder, &100, &long_memo);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Usual burn validation still applies
+        let result = contract.try_burn_with_memo(&holder, &1000, &memo);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        assert_eq!(contract.total_supply(), 600);
+    }
+
+    #[test]
+    fn test_grant_role_rejects_flagged_accounts() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let minter_role = Symbol::new(&env, "minter");
+        
+        // Denylisted accounts cannot become minters
+        let denied = Address::generate(&env);
+        contract.add_to_denylist(&admin, &denied);
+        let result = contract.try_grant_role(&admin, &denied, &minter_role);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert!(!contract.has_role_minter(&denied));
+        
+        // Neither can frozen accounts
+        let frozen = Address::generate(&env);
+        contract.freeze_account(&admin, &frozen);
+        let result = contract.try_grant_role(&admin, &frozen, &minter_role);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // A clean address can be granted the role
+        let clean = Address::generate(&env);
+        contract.grant_role(&admin, &clean, &minter_role);
+        assert!(contract.has_role_minter(&clean));
+        
+        // Unknown roles and non-admin callers are rejected
+        let result = contract.try_grant_role(&admin, &clean, &Symbol::new(&env, "superuser"));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidRole)));
+        let result = contract.try_grant_role(&clean, &clean, &Symbol::new(&env, "pauser"));
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_supply_breakdown_tracks_frozen_holders() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let other = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        contract.mint(&minter, &other, &500);
+        assert_eq!(contract.supply_breakdown(), (1500, 0));
+        
+        // Freezing moves the holder's balance into the frozen bucket (idempotently)
+        contract.freeze_account(&admin, &holder);
+        contract.freeze_account(&admin, &holder);
+        assert_eq!(contract.supply_breakdown(), (500, 1000));
+        
+        // Frozen accounts can still receive, and the frozen total follows
+        contract.mint(&minter, &holder, &200);
+        contract.transfer(&other, &holder, &100);
+        assert_eq!(contract.supply_breakdown(), (400, 1300));
+        
+        // Clawback burns from the frozen bucket
+        contract.clawback(&admin, &holder, &300);
+        assert_eq!(contract.balance(&holder), 1000);
+        assert_eq!(contract.total_supply(), 1400);
+        assert_eq!(contract.supply_breakdown(), (400, 1000));
+        
+        // Clawback only applies to frozen accounts
+        let result = contract.try_clawback(&admin, &other, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Unfreezing returns the balance to the active bucket
+        contract.unfreeze_account(&admin, &holder);
+        assert_eq!(contract.supply_breakdown(), (1400, 0));
+    }
+
+    #[test]
+    fn test_approve_rejects_flagged_spenders() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let other_owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let frozen_spender = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        contract.mint(&minter, &other_owner, &1000);
+        
+        // Approvals granted while the spender is clean
+        contract.approve(&owner, &spender, &300, &1000);
+        contract.approve(&other_owner, &spender, &200, &1000);
+        
+        // Revocation requires the spender to actually be flagged
+        let owners = Vec::from_array(&env, [owner.clone(), other_owner.clone()]);
+        let result = contract.try_revoke_spender_allowances(&admin, &spender, &owners);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Once denylisted, new approvals fail
+        contract.add_to_denylist(&admin, &spender);
+        let result = contract.try_approve(&owner, &spender, &500, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        
+        // ...and compliance can revoke the existing ones
+        assert_eq!(contract.revoke_spender_allowances(&admin, &spender, &owners), 2);
+        assert_eq!(contract.allowance(&owner, &spender), 0);
+        assert_eq!(contract.allowance(&other_owner, &spender), 0);
+        assert_eq!(contract.get_allowances(&owner).len(), 0);
+        
+        // Owners can still zero out an allowance to a flagged spender
+        contract.approve(&owner, &spender, &0, &0);
+        
+        // Frozen spenders are rejected too
+        contract.freeze_account(&admin, &frozen_spender);
+        let result = contract.try_approve(&owner, &frozen_spender, &100, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountFrozen)));
+    }
+
+    #[test]
+    fn test_op_seq_increments_per_mutating_call() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        // Initialization is the first operation
+        assert_eq!(contract.current_op_seq(), 1);
+        
+        contract.mint(&minter, &holder, &1000);
+        assert_eq!(contract.current_op_seq(), 2);
+        contract.transfer(&holder, &recipient, &100);
+        assert_eq!(contract.current_op_seq(), 3);
+        contract.set_allow_self_transfer(&admin, &true);
+        assert_eq!(contract.current_op_seq(), 4);
+        
+        // Batches count as a single operation
+        let recipients = Vec::from_array(&env, [(recipient.clone(), 10), (admin.clone(), 10)]);
+        contract.batch_transfer(&holder, &recipients);
+        assert_eq!(contract.current_op_seq(), 5);
+        
+        // Reads and failed calls leave the sequence untouched
+        contract.balance(&holder);
+        let result = contract.try_transfer(&holder, &recipient, &1_000_000);
+        assert!(result.is_err());
+        assert_eq!(contract.current_op_seq(), 5);
+        
+        // The transfer event is stamped with the sequence of its call
+        contract.transfer(&holder, &recipient, &1);
+        let last = env.events().all().last().unwrap();
+        assert_eq!(
+            vec![&env, last],
+            vec![
+                &env,
+                (
+                    contract.address.clone(),
+                    (Symbol::new(&env, "transfer"), holder.clone(), recipient.clone()).into_val(&env),
+                    (6_u64, 1_i128).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_max_transferable_from() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        
+        // No allowance yet
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 0);
+        
+        // Allowance is the binding constraint
+        contract.approve(&owner, &spender, &300, &1000);
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 300);
+        
+        // Balance is the binding constraint
+        contract.approve(&owner, &spender, &5000, &1000);
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 1000);
+        
+        // Locks reduce what can be pulled
+        contract.lock_balance(&admin, &owner, &800, &500);
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 200);
+        
+        // The reported maximum actually succeeds, and one more unit does not
+        let to = Address::generate(&env);
+        assert!(contract.try_can_spend(&spender, &owner, &to, &201).is_err());
+        contract.transfer_from(&spender, &owner, &to, &200);
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 0);
+        
+        // Freezing the owner zeroes the cap
+        contract.mint(&minter, &owner, &100);
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 100);
+        contract.freeze_account(&admin, &owner);
+        assert_eq!(contract.max_transferable_from(&spender, &owner), 0);
+    }
+
+    #[test]
+    fn test_settlement_hook_modes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        
+        let settlement_id = env.register(MockSettlement, ());
+        let settlement = MockSettlementClient::new(&env, &settlement_id);
+        let failing_id = env.register(FailingSettlement, ());
+        
+        // Successful settlement is called with the burn details
+        contract.set_settlement_hook(&admin, &Some(settlement_id.clone()), &true);
+        contract.burn(&holder, &100);
+        contract.burn_with_memo(&holder, &50, &String::from_str(&env, "REF-1"));
+        assert_eq!(
+            settlement.settlements(),
+            Vec::from_array(&env, [(holder.clone(), 100), (holder.clone(), 50)])
+        );
+        
+        // Strict mode: a failing settlement reverts the burn
+        contract.set_settlement_hook(&admin, &Some(failing_id.clone()), &true);
+        let result = contract.try_burn(&holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::SettlementFailed)));
+        assert_eq!(contract.balance(&holder), 850);
+        
+        // Best-effort mode: the burn goes through regardless
+        contract.set_settlement_hook(&admin, &Some(failing_id), &false);
+        contract.burn(&holder, &100);
+        assert_eq!(contract.balance(&holder), 750);
+        
+        // Clearing the hook stops settlement calls
+        contract.set_settlement_hook(&admin, &None, &false);
+        assert_eq!(contract.get_settlement_hook(), None);
+        contract.burn(&holder, &100);
+        assert_eq!(settlement.settlements().len(), 2);
+    }
+
+    #[test]
+    fn test_global_flow_caps_per_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 10);
+        
+        contract.set_global_flow_caps(&admin, &1000, &300);
+        
+        // Mint up to the cap within one ledger
+        contract.mint(&minter, &holder, &600);
+        let recipients = Vec::from_array(&env, [(holder.clone(), 400)]);
+        contract.batch_mint(&minter, &recipients);
+        assert_eq!(contract.get_ledger_flow().minted, 1000);
+        
+        // One more unit in the same ledger is rejected
+        let result = contract.try_mint(&minter, &holder, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::RateLimitExceeded)));
+        
+        // Burns are capped independently
+        contract.burn(&holder, &300);
+        let result = contract.try_burn(&holder, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::RateLimitExceeded)));
+        
+        // The next ledger starts fresh
+        env.ledger().with_mut(|li| li.sequence_number = 11);
+        assert_eq!(contract.get_ledger_flow().minted, 0);
+        contract.mint(&minter, &holder, &1);
+        contract.burn(&holder, &1);
+        
+        // Zero caps disable the limits
+        contract.set_global_flow_caps(&admin, &0, &0);
+        contract.mint(&minter, &holder, &5000);
+        assert_eq!(contract.balance(&holder), 5700);
+        
+        // Negative caps are invalid
+        let result = contract.try_set_global_flow_caps(&admin, &-1, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_ensure_allowance_tops_up_only_when_needed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        
+        // Below the minimum: the allowance is topped up
+        assert!(contract.ensure_allowance(&owner, &spender, &200, &1000));
+        assert_eq!(contract.allowance(&owner, &spender), 200);
+        let seq = contract.current_op_seq();
+        
+        // Already sufficient: no write happens
+        contract.approve(&owner, &spender, &500, &1000);
+        let seq_after_approve = contract.current_op_seq();
+        assert!(seq_after_approve > seq);
+        assert!(!contract.ensure_allowance(&owner, &spender, &300, &2000));
+        assert_eq!(contract.allowance(&owner, &spender), 500);
+        assert_eq!(contract.current_op_seq(), seq_after_approve);
+        
+        // The owner's authorization is still required
+        assert_eq!(env.auths()[0].0, owner);
+        
+        // Topped-up allowance can be spent
+        assert!(contract.ensure_allowance(&owner, &spender, &800, &1000));
+        contract.transfer_from(&spender, &owner, &spender, &800);
+        assert_eq!(contract.balance(&spender), 800);
+    }
+
+    #[test]
+    fn test_strict_validation_toggle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        
+        // Strict by default: the contract's own address is rejected
+        assert!(contract.is_strict_validation());
+        let result = contract.try_mint(&minter, &contract.address, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::ZeroAddress)));
+        let result = contract.try_transfer(&holder, &contract.address, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::ZeroAddress)));
+        
+        // Permissive mode allows it
+        contract.set_strict_validation(&admin, &false);
+        assert!(!contract.is_strict_validation());
+        contract.mint(&minter, &contract.address, &100);
+        contract.transfer(&holder, &contract.address, &100);
+        assert_eq!(contract.balance(&contract.address), 200);
+        
+        // Only the admin can change the mode
+        let result = contract.try_set_strict_validation(&holder, &true);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_emergency_rotate_minter() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let replacement = Address::generate(&env);
+        let holder = Address::generate(&env);
+        
+        contract.set_minter_allowance(&admin, &minter, &Some(1000));
+        contract.mint(&minter, &holder, &400);
+        assert_eq!(contract.minter_allowance(&minter), Some(600));
+        
+        contract.emergency_rotate_minter(&admin, &minter, &replacement);
+        
+        // The compromised key is fully neutralized
+        assert!(!contract.has_role_minter(&minter));
+        assert!(contract.is_denylisted(&minter));
+        assert_eq!(contract.minter_allowance(&minter), Some(0));
+        assert!(contract.try_mint(&minter, &holder, &1).is_err());
+        
+        // The replacement is active with the remaining allowance
+        assert!(contract.has_role_minter(&replacement));
+        assert_eq!(contract.minter_allowance(&replacement), Some(600));
+        contract.mint(&replacement, &holder, &600);
+        let result = contract.try_mint(&replacement, &holder, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientAllowance)));
+        assert_eq!(contract.balance(&holder), 1000);
+        
+        // Rotating a non-minter is rejected
+        let result = contract.try_emergency_rotate_minter(&admin, &minter, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Rotating into an existing minter adds to its cap
+        let minter_role = Symbol::new(&env, "minter");
+        let backup = Address::generate(&env);
+        contract.grant_role(&admin, &backup, &minter_role);
+        contract.set_minter_allowance(&admin, &backup, &Some(100));
+        contract.set_minter_allowance(&admin, &replacement, &Some(50));
+        contract.emergency_rotate_minter(&admin, &replacement, &backup);
+        assert_eq!(contract.minter_allowance(&backup), Some(150));
+        
+        // An uncapped minter carries nothing over and leaves the replacement's cap alone
+        let uncapped = Address::generate(&env);
+        contract.grant_role(&admin, &uncapped, &minter_role);
+        contract.emergency_rotate_minter(&admin, &uncapped, &backup);
+        assert_eq!(contract.minter_allowance(&backup), Some(150));
+    }
+
+    #[test]
+    fn test_pause_status() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, pauser, _upgrader, _minter) = setup(&env);
+        assert_eq!(contract.pause_status(), PauseStatus::NotPaused);
+        
+        contract.pause(&pauser);
+        assert_eq!(contract.pause_status(), PauseStatus::Paused);
+        
+        contract.unpause(&pauser);
+        assert_eq!(contract.pause_status(), PauseStatus::NotPaused);
+        
+        // A pause with allowances frozen reports the emergency state
+        contract.pause_with_allowance_freeze(&pauser);
+        assert_eq!(contract.pause_status(), PauseStatus::EmergencyFrozen);
+        contract.unpause_restore_allowances(&pauser);
+        assert_eq!(contract.pause_status(), PauseStatus::NotPaused);
+    }
+
+    #[test]
+    fn test_stats_track_holders_and_allowances() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        contract.mint(&minter, &owner, &1000);
+        let stats = contract.get_stats(&admin);
+        assert_eq!(stats.total_minted, 1000);
+        assert_eq!(stats.active_holders, 1);
+        assert_eq!(stats.total_outstanding_allowances, 0);
+        
+        // Granted allowances add to the outstanding total
+        contract.approve(&owner, &spender, &400, &1000);
+        contract.approve(&owner, &recipient, &100, &1000);
+        assert_eq!(contract.get_stats(&admin).total_outstanding_allowances, 500);
+        
+        // Spending through an allowance reduces it and creates a new holder
+        contract.transfer_from(&spender, &owner, &recipient, &150);
+        let stats = contract.get_stats(&admin);
+        assert_eq!(stats.total_outstanding_allowances, 350);
+        assert_eq!(stats.active_holders, 2);
+        
+        // Revoking removes the remainder
+        contract.approve(&owner, &spender, &0, &0);
+        assert_eq!(contract.get_stats(&admin).total_outstanding_allowances, 100);
+        
+        // Emptying a balance drops the holder; burns are totalled
+        contract.burn(&recipient, &150);
+        let stats = contract.get_stats(&admin);
+        assert_eq!(stats.active_holders, 1);
+        assert_eq!(stats.total_burned, 150);
+        assert_eq!(stats.total_supply, 850);
+    }
+
+    #[test]
+    fn test_preview_batch_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let from = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        contract.mint(&minter, &from, &1000);
+        
+        // Duplicate recipient
+        let recipients = Vec::from_array(&env, [(alice.clone(), 100), (bob.clone(), 100), (alice.clone(), 100)]);
+        let result = contract.try_preview_batch_transfer(&from, &recipients);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Each leg fits the balance but the total does not
+        let recipients = Vec::from_array(&env, [(alice.clone(), 600), (bob.clone(), 600)]);
+        let result = contract.try_preview_batch_transfer(&from, &recipients);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // A valid batch previews cleanly and leaves state untouched
+        let recipients = Vec::from_array(&env, [(alice.clone(), 600), (bob.clone(), 400)]);
+        contract.preview_batch_transfer(&from, &recipients);
+        assert_eq!(contract.balance(&from), 1000);
+        
+        contract.batch_transfer(&from, &recipients);
+        assert_eq!(contract.balance(&alice), 600);
+        assert_eq!(contract.balance(&bob), 400);
+    }
+
+    #[test]
+    fn test_fungible_token_interface() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        contract.mint(&minter, &alice, &1000);
+        
+        // Standard fungible interface through the generic token client
+        let token = token::Client::new(&env, &contract.address);
+        assert_eq!(token.balance(&alice), 1000);
+        token.transfer(&alice, &bob, &250);
+        token.approve(&alice, &bob, &100, &1000);
+        assert_eq!(token.allowance(&alice, &bob), 100);
+        
+        // ERC20-style aliases agree with the native methods
+        assert_eq!(contract.balance_of(&alice), contract.balance(&alice));
+        assert_eq!(contract.balance_of(&bob), 250);
+        assert_eq!(contract.total_supply(), 1000);
+    }
+
+    #[test]
+    fn test_sep41_conformance() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        
+        let token = token::Client::new(&env, &contract.address);
+        
+        // Metadata
+        assert_eq!(token.decimals(), 2);
+        assert_eq!(token.name(), contract.name());
+        assert_eq!(token.symbol(), contract.symbol());
+        
+        // transfer requires the sender's authorization
+        token.transfer(&owner, &recipient, &100);
+        assert_eq!(env.auths()[0].0, owner);
+        
+        // approve requires the owner's authorization
+        token.approve(&owner, &spender, &300, &1000);
+        assert_eq!(env.auths()[0].0, owner);
+        
+        // transfer_from and burn_from require the spender's authorization and debit the allowance
+        token.transfer_from(&spender, &owner, &recipient, &100);
+        assert_eq!(env.auths()[0].0, spender);
+        token.burn_from(&spender, &owner, &50);
+        assert_eq!(env.auths()[0].0, spender);
+        assert_eq!(token.allowance(&owner, &spender), 150);
+        
+        // burn requires the holder's authorization
+        token.burn(&recipient, &20);
+        assert_eq!(env.auths()[0].0, recipient);
+        
+        assert_eq!(token.balance(&owner), 750);
+        assert_eq!(token.balance(&recipient), 180);
+        
+        // Errors surface to standard callers as failed invocations
+        assert!(token.try_transfer(&owner, &recipient, &1_000_000).is_err());
+        assert!(token.try_transfer_from(&spender, &owner, &recipient, &151).is_err());
+    }
+
+    #[test]
+    fn test_fee_absolute_cap() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let collector = Address::generate(&env);
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &sender, &1_000_000);
+        
+        // 1% fee, capped at 50
+        contract.set_fee_config(&admin, &100, &collector, &50);
+        
+        // Small transfer: the percentage binds
+        assert_eq!(contract.quote_transfer_fee(&1000), (990, 10));
+        contract.transfer(&sender, &recipient, &1000);
+        assert_eq!(contract.balance(&recipient), 990);
+        assert_eq!(contract.balance(&collector), 10);
+        
+        // Large transfer: the absolute cap binds, and net + fee == amount
+        let (net, fee) = contract.quote_transfer_fee(&100_000);
+        assert_eq!((net, fee), (99_950, 50));
+        contract.transfer(&sender, &recipient, &100_000);
+        assert_eq!(contract.balance(&recipient), 990 + net);
+        assert_eq!(contract.balance(&collector), 10 + fee);
+        assert_eq!(contract.balance(&sender), 1_000_000 - 101_000);
+        
+        // Delegated transfers debit the allowance by exactly the amount sent
+        contract.approve(&sender, &spender, &20_000, &1000);
+        contract.transfer_from(&spender, &sender, &recipient, &10_000);
+        assert_eq!(contract.allowance(&sender, &spender), 10_000);
+        assert_eq!(contract.balance(&collector), 10 + 50 + 50);
+        
+        // Zero cap means the percentage alone applies
+        contract.set_fee_config(&admin, &100, &collector, &0);
+        assert_eq!(contract.quote_transfer_fee(&100_000), (99_000, 1000));
+        
+        // Fees above the maximum are rejected
+        let result = contract.try_set_fee_config(&admin, &1001, &collector, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Zero bps disables fees
+        contract.set_fee_config(&admin, &0, &collector, &0);
+        assert_eq!(contract.get_fee_config(), None);
+        assert_eq!(contract.quote_transfer_fee(&100_000), (100_000, 0));
+    }
+
+    #[test]
+    fn test_escrow_release_and_refund() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let depositor = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+        contract.mint(&minter, &depositor, &1000);
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        
+        // Deposits move tokens into contract-held escrow
+        let first = contract.escrow_deposit(&depositor, &beneficiary, &300, &200);
+        let second = contract.escrow_deposit(&depositor, &beneficiary, &200, &200);
+        assert_ne!(first, second);
+        assert_eq!(contract.balance(&depositor), 500);
+        assert_eq!(contract.balance(&contract.address), 500);
+        
+        // Early release is rejected
+        let result = contract.try_escrow_release(&first);
+        assert_eq!(result, Err(Ok(StablecoinError::TimelockNotExpired)));
+        
+        // Refund before the release ledger returns tokens to the depositor
+        contract.escrow_refund(&second);
+        assert_eq!(env.auths()[0].0, depositor);
+        assert_eq!(contract.balance(&depositor), 700);
+        assert_eq!(contract.get_escrow(&second), None);
+        
+        // Release after the ledger pays the beneficiary
+        env.ledger().with_mut(|li| li.sequence_number = 200);
+        let result = contract.try_escrow_refund(&first);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        contract.escrow_release(&first);
+        assert_eq!(contract.balance(&beneficiary), 300);
+        assert_eq!(contract.balance(&contract.address), 0);
+        
+        // Settled escrows cannot be paid twice
+        let result = contract.try_escrow_release(&first);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_reconcile_supply() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        
+        // Every supply-changing path keeps the books balanced
+        contract.mint(&minter, &holder, &1000);
+        contract.burn(&holder, &100);
+        contract.freeze_account(&admin, &holder);
+        contract.clawback(&admin, &holder, &50);
+        assert!(contract.reconcile_supply());
+        
+        // Drift in the tracked totals is reported
+        env.as_contract(&contract.address, || {
+            storage::set_total_minted(&env, 2000);
+        });
+        let result = contract.try_reconcile_supply();
+        assert_eq!(result, Err(Ok(StablecoinError::SupplyMismatch)));
+    }
+
+    #[test]
+    fn test_allowance_grace_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let to = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        contract.approve(&owner, &spender, &500, &110);
+        
+        // Off by default: a just-expired allowance fails
+        assert_eq!(contract.get_allowance_grace(), 0);
+        env.ledger().with_mut(|li| li.sequence_number = 111);
+        let result = contract.try_transfer_from(&spender, &owner, &to, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AllowanceExpired)));
+        
+        // Within the grace window it still works, up to what remained
+        contract.set_allowance_grace(&admin, &5);
+        contract.transfer_from(&spender, &owner, &to, &100);
+        assert_eq!(contract.balance(&to), 100);
+        let result = contract.try_transfer_from(&spender, &owner, &to, &401);
+        assert_eq!(result, Err(Ok(StablecoinError::AllowanceExpired)));
+        
+        env.ledger().with_mut(|li| li.sequence_number = 115);
+        contract.transfer_from(&spender, &owner, &to, &400);
+        assert_eq!(contract.balance(&to), 500);
+        
+        // Beyond the grace window it fails again
+        contract.approve(&owner, &spender, &300, &120);
+        env.ledger().with_mut(|li| li.sequence_number = 126);
+        let result = contract.try_transfer_from(&spender, &owner, &to, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AllowanceExpired)));
+        
+        // The grace window is bounded
+        let result = contract.try_set_allowance_grace(&admin, &721);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_allowance_grace_transfer_with_single_spender_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let to = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        contract.approve(&owner, &spender, &500, &110);
+        contract.set_allowance_grace(&admin, &5);
+        env.ledger().with_mut(|li| li.sequence_number = 112);
+        
+        // One spender signature covers a spend through the grace window
+        contract
+            .mock_auths(&[MockAuth {
+                address: &spender,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "transfer_from",
+                    args: (&spender, &owner, &to, 100_i128).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .transfer_from(&spender, &owner, &to, &100);
+        assert_eq!(contract.balance(&to), 100);
+        
+        // Without the signature the grace path is refused
+        env.set_auths(&[]);
+        assert!(contract.try_transfer_from(&spender, &owner, &to, &100).is_err());
+        assert_eq!(contract.balance(&to), 100);
+    }
+
+    #[test]
+    fn test_backfill_holders() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let legacy_a = Address::generate(&env);
+        let legacy_b = Address::generate(&env);
+        let tracked = Address::generate(&env);
+        let empty = Address::generate(&env);
+        
+        // Balances written before holder tracking existed
+        env.as_contract(&contract.address, || {
+            stellar_fungible::Base::mint(&env, &legacy_a, 100);
+            stellar_fungible::Base::mint(&env, &legacy_b, 200);
+        });
+        contract.mint(&minter, &tracked, &300);
+        assert_eq!(contract.get_stats(&admin).active_holders, 1);
+        
+        let accounts = Vec::from_array(&env, [legacy_a.clone(), legacy_b.clone(), tracked.clone(), empty.clone()]);
+        assert_eq!(contract.backfill_holders(&admin, &accounts), 2);
+        assert_eq!(contract.get_stats(&admin).active_holders, 3);
+        
+        // Repeating the backfill does not double count
+        assert_eq!(contract.backfill_holders(&admin, &accounts), 0);
+        assert_eq!(contract.get_stats(&admin).active_holders, 3);
+        
+        // Backfilled holders are tracked normally afterwards
+        contract.burn(&legacy_a, &100);
+        assert_eq!(contract.get_stats(&admin).active_holders, 2);
+    }
+
+    #[test]
+    fn test_initialize_report() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let pauser = Address::generate(&env);
+        let upgrader = Address::generate(&env);
+        let minter = Address::generate(&env);
+        let contract = MyStablecoinClient::new(&env, &env.register(MyStablecoin, ()));
+        
+        let report = contract.initialize(&admin, &pauser, &upgrader, &minter);
+        assert_eq!(report.admin, admin);
+        assert_eq!(report.pauser, pauser);
+        assert_eq!(report.upgrader, upgrader);
+        assert_eq!(report.minter, minter);
+        assert_eq!(report.name, contract.name());
+        assert_eq!(report.symbol, contract.symbol());
+        assert_eq!(report.decimals, contract.decimals());
+        assert_eq!(report.currency, contract.currency());
+        assert!(report.supply_limits);
+        assert!(report.operation_limits);
+        assert_eq!(report.strict_validation, contract.is_strict_validation());
+        
+        // The reported roles are the ones actually granted
+        assert!(contract.has_role_pauser(&report.pauser));
+        assert!(contract.has_role_upgrader(&report.upgrader));
+        assert!(contract.has_role_minter(&report.minter));
+        assert_eq!(contract.get_admin(), Some(report.admin));
+    }
+
+    #[test]
+    fn test_read_lockdown() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        
+        // Off by default
+        assert!(!contract.is_read_lockdown());
+        assert_eq!(contract.balance(&holder), 1000);
+        
+        // Lockdown makes balance and supply reads fail
+        contract.set_read_lockdown(&admin, &true);
+        assert_eq!(contract.try_balance(&holder), Err(Ok(StablecoinError::Paused)));
+        assert_eq!(contract.try_balance_of(&holder), Err(Ok(StablecoinError::Paused)));
+        assert_eq!(contract.try_spendable_balance(&holder), Err(Ok(StablecoinError::Paused)));
+        assert_eq!(contract.try_total_supply(), Err(Ok(StablecoinError::Paused)));
+        
+        // Normal mode returns data again
+        contract.set_read_lockdown(&admin, &false);
+        assert_eq!(contract.balance(&holder), 1000);
+        assert_eq!(contract.total_supply(), 1000);
+    }
+
+    #[test]
+    fn test_batch_mint_lenient_partial_success() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let denied = Address::generate(&env);
+        contract.add_to_denylist(&admin, &denied);
+        contract.set_minter_allowance(&admin, &minter, &Some(1000));
+        
+        let recipients = Vec::from_array(&env, [
+            (alice.clone(), 300),
+            (bob.clone(), 0),
+            (denied.clone(), 100),
+            (bob.clone(), 400),
+            (alice.clone(), 500),
+        ]);
+        let skipped = contract.batch_mint_lenient(&minter, &recipients);
+        
+        // Invalid amount, denylisted recipient and exhausted minter allowance are skipped
+        assert_eq!(
+            skipped,
+            Vec::from_array(&env, [
+                (1, StablecoinError::InvalidAmount),
+                (2, StablecoinError::AccountDenylisted),
+                (4, StablecoinError::InsufficientAllowance),
+            ])
+        );
+        
+        // Only the successful entries are reflected in balances and supply
+        assert_eq!(contract.balance(&alice), 300);
+        assert_eq!(contract.balance(&bob), 400);
+        assert_eq!(contract.total_supply(), 700);
+        assert_eq!(contract.minter_allowance(&minter), Some(300));
+        assert_eq!(contract.get_stats(&admin).total_minted, 700);
+    }
+
+    #[test]
+    fn test_mint_cooldown_per_recipient() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let other = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        
+        contract.set_mint_cooldown(&admin, &10);
+        assert_eq!(contract.get_mint_cooldown(), 10);
+        contract.mint(&minter, &holder, &100);
+        
+        // A second mint to the same recipient within the cooldown is rejected
+        env.ledger().with_mut(|li| li.sequence_number = 109);
+        let result = contract.try_mint(&minter, &holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::RateLimitExceeded)));
+        
+        // Other recipients are unaffected
+        contract.mint(&minter, &other, &100);
+        
+        // Once the cooldown has passed the recipient can be minted to again
+        env.ledger().with_mut(|li| li.sequence_number = 110);
+        contract.mint(&minter, &holder, &100);
+        assert_eq!(contract.balance(&holder), 200);
+        
+        // Zero disables the cooldown
+        contract.set_mint_cooldown(&admin, &0);
+        contract.mint(&minter, &holder, &100);
+        assert_eq!(contract.balance(&holder), 300);
+    }
+
+    #[test]
+    fn test_guardian_and_subscriber_changes_emit_config_changed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let guardian = Address::generate(&env);
+        let subscriber = env.register(MockPauseSubscriber, ());
+        let last_change = || {
+            let (_, _, data) = env.events().all().last().unwrap();
+            let (_, change): (u64, ConfigChanged) = data.into_val(&env);
+            change
+        };
+        
+        contract.add_guardian(&admin, &guardian);
+        assert_eq!(last_change(), ConfigChanged { key: Symbol::new(&env, "guardians"), by: admin.clone() });
+        contract.remove_guardian(&admin, &guardian);
+        assert_eq!(last_change(), ConfigChanged { key: Symbol::new(&env, "guardians"), by: admin.clone() });
+        
+        contract.add_pause_subscriber(&admin, &subscriber);
+        assert_eq!(last_change(), ConfigChanged { key: Symbol::new(&env, "pause_subscribers"), by: admin.clone() });
+        contract.remove_pause_subscriber(&admin, &subscriber);
+        assert_eq!(last_change(), ConfigChanged { key: Symbol::new(&env, "pause_subscribers"), by: admin.clone() });
+    }
+
+    #[test]
+    fn test_set_max_supply_emits_config_changed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        
+        contract.set_max_supply(&admin, &1000);
+        assert_eq!(contract.get_max_supply(), 1000);
+        
+        let last = env.events().all().last().unwrap();
+        assert_eq!(
+            vec![&env, last],
+            vec![
+                &env,
+                (
+                    contract.address.clone(),
+                    (Symbol::new(&env, "config_changed"),).into_val(&env),
+                    (
+                        contract.current_op_seq(),
+                        ConfigChanged { key: Symbol::new(&env, "max_supply"), by: admin.clone() },
+                    ).into_val(&env),
+                ),
+            ]
+        );
+        
+        // The new cap is enforced and cannot drop below the current supply
+        contract.mint(&minter, &holder, &1000);
+        let result = contract.try_mint(&minter, &holder, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::ExceedsMaxSupply)));
+        let result = contract.try_set_max_supply(&admin, &999);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_fee_routing_conserves_total_supply() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let collector = Address::generate(&env);
+        contract.set_fee_config(&admin, &250, &collector, &0);
+        contract.mint(&minter, &holder, &10_000);
+        
+        let supply_before = contract.total_supply();
+        contract.transfer(&holder, &recipient, &1000);
+        contract.approve(&holder, &spender, &1000, &1000);
+        contract.transfer_from(&spender, &holder, &recipient, &1000);
+        
+        // The collector was paid, yet no tokens were created
+        assert_eq!(contract.balance(&collector), 50);
+        assert_eq!(contract.total_supply(), supply_before);
+        assert_eq!(contract.get_stats(&admin).total_minted, supply_before);
+    }
+
+    #[test]
+    fn test_fee_disposition() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let collector = Address::generate(&env);
+        contract.set_fee_config(&admin, &250, &collector, &0);
+        contract.mint(&minter, &holder, &10_000);
+        
+        // Route mode credits the collector
+        assert_eq!(contract.get_fee_disposition(), FeeDisposition::Route);
+        contract.transfer(&holder, &recipient, &1000);
+        assert_eq!(contract.balance(&collector), 25);
+        assert_eq!(contract.total_supply(), 10_000);
+        
+        // Burn mode removes the fee from supply and counts it as burned
+        contract.set_fee_disposition(&admin, &FeeDisposition::Burn);
+        contract.transfer(&holder, &recipient, &1000);
+        assert_eq!(contract.balance(&recipient), 975 * 2);
+        assert_eq!(contract.balance(&collector), 25);
+        assert_eq!(contract.total_supply(), 10_000 - 25);
+        assert_eq!(contract.get_stats(&admin).total_burned, 25);
+        assert!(contract.reconcile_supply());
+    }
+
+    #[test]
+    fn test_format_display_amount() {
+        let env = Env::default();
+        let cases = [
+            (123_456, 2, 2, "1234.56"),
+            (5, 2, 2, "0.05"),
+            (0, 2, 2, "0.00"),
+            (123_456_789, 7, 2, "12.34"),
+            (123_456_789, 7, 7, "12.3456789"),
+            (99, 7, 2, "0.00"),
+            (1_500, 0, 0, "1500"),
+            (1_999, 3, 0, "1"),
+            (-12_345, 2, 2, "-123.45"),
+            (5, 6, 6, "0.000005"),
+        ];
+        for (amount, decimals, display_decimals, expected) in cases {
+            assert_eq!(
+                utils::format_display_amount(&env, amount, decimals, display_decimals),
+                String::from_str(&env, expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_major_units() {
+        let cases = [
+            (123_456, 2, (1234, 56)),
+            (5, 2, (0, 5)),
+            (0, 2, (0, 0)),
+            (123_456_789, 7, (12, 3_456_789)),
+            (1_500, 0, (1500, 0)),
+            (MAX_SUPPLY, DECIMALS, (MAX_SUPPLY / 100, 0)),
+            (10i128.pow(18) * 3 + 7, 18, (3, 7)),
+        ];
+        for (amount, decimals, expected) in cases {
+            assert_eq!(utils::split_major_units(amount, decimals), expected);
+        }
+    }
+
+    #[test]
+    fn test_total_supply_major() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        assert_eq!(contract.total_supply_major(), (0, 0));
+        
+        contract.mint(&minter, &holder, &123_456);
+        assert_eq!(contract.total_supply_major(), (1234, 56));
+        
+        contract.mint(&minter, &holder, &44);
+        assert_eq!(contract.total_supply_major(), (1235, 0));
+    }
+
+    #[test]
+    fn test_transfer_with_memo_display_amount() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &holder, &10_000);
+        
+        // A different currency and a whole-unit denomination floor
+        contract.set_currency(&admin, &String::from_str(&env, "USD"));
+        contract.set_display_decimals(&admin, &0);
+        assert_eq!(contract.get_display_decimals(), 0);
+        
+        let memo = String::from_str(&env, "INV-42");
+        contract.transfer_with_memo(&holder, &recipient, &1_299, &memo);
+        assert_eq!(contract.balance(&recipient), 1_299);
+        
+        let last = env.events().all().last().unwrap();
+        assert_eq!(
+            vec![&env, last],
+            vec![
+                &env,
+                (
+                    contract.address.clone(),
+                    (Symbol::new(&env, "transfer_with_memo"), holder.clone(), recipient.clone()).into_val(&env),
+                    (
+                        contract.current_op_seq(),
+                        (
+                            1_299_i128,
+                            memo.clone(),
+                            DisplayAmount { text: String::from_str(&env, "12"), currency: Symbol::new(&env, "USD") },
+                        ),
+                    ).into_val(&env),
+                ),
+            ]
+        );
+        
+        // The floor cannot exceed the token decimals
+        let result = contract.try_set_display_decimals(&admin, &3);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_contract_age() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        assert_eq!(contract.contract_age(), (0, 0));
+        
+        // Both counters grow while the contract sits idle
+        env.ledger().with_mut(|li| li.sequence_number = 150);
+        assert_eq!(contract.contract_age(), (50, 50));
+        
+        // An operation resets last activity but not the age
+        contract.mint(&minter, &holder, &100);
+        assert_eq!(contract.contract_age(), (50, 0));
+        
+        env.ledger().with_mut(|li| li.sequence_number = 160);
+        assert_eq!(contract.contract_age(), (60, 10));
+    }
+
+    #[test]
+    fn test_burn_policy_modes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let burner = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        contract.mint(&minter, &burner, &1000);
+        
+        // Self-serve by default: any holder may burn their own tokens
+        assert!(!contract.is_role_gated_burns());
+        contract.burn(&holder, &100);
+        assert_eq!(contract.balance(&holder), 900);
+        
+        // Role-gated: a roleless holder is rejected on every burn path
+        contract.set_role_gated_burns(&admin, &true);
+        let result = contract.try_burn(&holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        let result = contract.try_burn_with_memo(&holder, &100, &String::from_str(&env, "REF-1"));
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        let result = contract.try_governed_burn(&holder, &admin, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // A burner role holder may still redeem
+        contract.grant_role(&admin, &burner, &Symbol::new(&env, "burner"));
+        contract.burn(&burner, &100);
+        assert_eq!(contract.balance(&burner), 900);
+        assert_eq!(contract.total_supply(), 1800);
+    }
+
+    #[test]
+    fn test_burns_with_single_holder_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        let memo = String::from_str(&env, "REF-1");
+        
+        // Exactly one signature from the holder covers each burn path
+        contract
+            .mock_auths(&[MockAuth {
+                address: &holder,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "burn",
+                    args: (&holder, 100_i128).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .burn(&holder, &100);
+        contract
+            .mock_auths(&[MockAuth {
+                address: &holder,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "burn_with_memo",
+                    args: (&holder, 100_i128, memo.clone()).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .burn_with_memo(&holder, &100, &memo);
+        contract
+            .mock_auths(&[MockAuth {
+                address: &holder,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "governed_burn",
+                    args: (&holder, &admin, 100_i128).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .governed_burn(&holder, &admin, &100);
+        
+        assert_eq!(contract.balance(&holder), 700);
+        
+        // Without the holder's signature the burn is refused
+        env.set_auths(&[]);
+        assert!(contract.try_burn(&holder, &100).is_err());
+        assert_eq!(contract.balance(&holder), 700);
+    }
+
+    #[test]
+    fn test_account_compliance_report() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let account = Address::generate(&env);
+        let counterparty = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 10);
+        contract.mint(&minter, &account, &1000);
+        
+        // A clean account reports no restrictions
+        assert_eq!(
+            contract.account_compliance(&account),
+            AccountCompliance { frozen: false, denylisted: false, allowlisted: true, outgoing_restricted: false, locked: 0, spendable: 1000 }
+        );
+        
+        contract.lock_balance(&admin, &account, &300, &100);
+        contract.add_allowed_counterparty(&admin, &account, &counterparty);
+        assert_eq!(
+            contract.account_compliance(&account),
+            AccountCompliance { frozen: false, denylisted: false, allowlisted: true, outgoing_restricted: true, locked: 300, spendable: 700 }
+        );
+        
+        // Allowlist mode reports accounts that are not registered
+        contract.set_allowlist_mode(&admin, &true);
+        assert!(!contract.account_compliance(&account).allowlisted);
+        contract.allowlist_add(&admin, &account);
+        assert!(contract.account_compliance(&account).allowlisted);
+        
+        contract.freeze_account(&admin, &account);
+        contract.add_to_denylist(&admin, &account);
+        assert_eq!(
+            contract.account_compliance(&account),
+            AccountCompliance { frozen: true, denylisted: true, allowlisted: true, outgoing_restricted: true, locked: 300, spendable: 0 }
+        );
+    }
+
+    #[test]
+    fn test_call_hook_strict_and_best_effort() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, _minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let settlement_id = env.register(MockSettlement, ());
+        let failing_id = env.register(FailingSettlement, ());
+        let args = vec![&env, holder.into_val(&env), 100_i128.into_val(&env)];
+        
+        env.as_contract(&contract.address, || {
+            // A well-behaved hook succeeds in either mode
+            assert_eq!(utils::call_hook(&env, &settlement_id, "settle_redemption", args.clone(), true), Ok(()));
+            
+            // A panicking hook becomes a typed error when strict and is swallowed otherwise
+            assert_eq!(
+                utils::call_hook(&env, &failing_id, "settle_redemption", args.clone(), true),
+                Err(StablecoinError::HookFailed)
+            );
+            assert_eq!(utils::call_hook(&env, &failing_id, "settle_redemption", args.clone(), false), Ok(()));
+            
+            // So does calling a function the contract does not export
+            assert_eq!(
+                utils::call_hook(&env, &settlement_id, "missing_hook", args.clone(), true),
+                Err(StablecoinError::HookFailed)
+            );
+        });
+        
+        assert_eq!(MockSettlementClient::new(&env, &settlement_id).settlements().len(), 1);
+    }
+
+    #[test]
+    fn test_supply_deviation_from_target() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        
+        contract.set_target_supply(&admin, &10_000);
+        assert_eq!(contract.get_target_supply(), 10_000);
+        assert_eq!(contract.supply_deviation(), -10_000);
+        
+        // Under target after a partial mint
+        contract.mint(&minter, &holder, &8_000);
+        assert_eq!(contract.supply_deviation(), -2_000);
+        
+        // Over target, then back under after a burn
+        contract.mint(&minter, &holder, &3_000);
+        assert_eq!(contract.supply_deviation(), 1_000);
+        contract.burn(&holder, &1_500);
+        assert_eq!(contract.supply_deviation(), -500);
+        
+        // Negative targets are rejected
+        let result = contract.try_set_target_supply(&admin, &-1);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_system_overview() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, upgrader, minter) = setup(&env);
+        let tags = |names: &[&str]| {
+            let mut tags = Vec::new(&env);
+            for name in names {
+                tags.push_back(Symbol::new(&env, name));
+            }
+            tags
+        };
+        
+        // The roles seeded by initialize
+        assert_eq!(
+            contract.system_overview(),
+            Vec::from_array(&env, [
+                (admin.clone(), tags(&["admin"])),
+                (minter.clone(), tags(&["minter"])),
+                (pauser.clone(), tags(&["pauser"])),
+                (upgrader.clone(), tags(&["upgrader"])),
+            ])
+        );
+        
+        // The fee collector and extra roles are merged per address
+        let collector = Address::generate(&env);
+        contract.set_fee_config(&admin, &100, &collector, &0);
+        contract.grant_role(&admin, &minter, &Symbol::new(&env, "burner"));
+        assert_eq!(
+            contract.system_overview(),
+            Vec::from_array(&env, [
+                (admin.clone(), tags(&["admin"])),
+                (collector.clone(), tags(&["fee_collector"])),
+                (minter.clone(), tags(&["minter", "burner"])),
+                (pauser.clone(), tags(&["pauser"])),
+                (upgrader.clone(), tags(&["upgrader"])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_system_addresses() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        
+        // Only the admin is set by initialize
+        assert_eq!(
+            contract.system_addresses(&admin),
+            SystemAddresses {
+                admin: Some(admin.clone()),
+                pending_admin: None,
+                recovery: None,
+                fee_collector: None,
+                settlement: None,
+                denylist_oracle: None,
+                post_upgrade_hook: None,
+                migration_rescue: None,
+            }
+        );
+        
+        // Setters are reflected
+        let nominee = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let collector = Address::generate(&env);
+        let settlement = Address::generate(&env);
+        contract.transfer_admin(&admin, &nominee);
+        contract.set_recovery_config(&admin, &recovery, &MIN_RECOVERY_DELAY_LEDGERS);
+        contract.set_fee_config(&admin, &100, &collector, &0);
+        contract.set_settlement_hook(&admin, &Some(settlement.clone()), &false);
+        let hook = Address::generate(&env);
+        contract.set_post_upgrade_hook(&admin, &Some(hook.clone()));
+        assert_eq!(
+            contract.system_addresses(&admin),
+            SystemAddresses {
+                admin: Some(admin),
+                pending_admin: Some(nominee),
+                recovery: Some(recovery),
+                fee_collector: Some(collector),
+                settlement: Some(settlement),
+                denylist_oracle: None,
+                post_upgrade_hook: Some(hook),
+                migration_rescue: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_backing_methods_disabled_by_flag() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        
+        // Backing is compiled out of this deployment, so every method reports it cleanly
+        let result = contract.try_deposit(&admin, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        let result = contract.try_withdraw(&admin, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        let result = contract.try_backing_reserve();
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        
+        // Nothing was recorded
+        assert_eq!(contract.current_op_seq(), 1);
+    }
+
+    #[test]
+    fn test_exhausted_allowance_leaves_spender_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let burner = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &300, &1000);
+        contract.approve(&owner, &burner, &200, &1000);
+        
+        // A partial spend keeps the spender indexed
+        contract.transfer_from(&spender, &owner, &recipient, &100);
+        assert_eq!(contract.get_allowances(&owner).len(), 2);
+        
+        // Spending to exactly zero via transfer_from or burn_from drops the spender
+        contract.transfer_from(&spender, &owner, &recipient, &200);
+        assert_eq!(contract.get_allowances(&owner), Vec::from_array(&env, [(burner.clone(), 200)]));
+        contract.burn_from(&burner, &owner, &200);
+        assert_eq!(contract.get_allowances(&owner).len(), 0);
+        
+        env.as_contract(&contract.address, || {
+            assert_eq!(storage::get_spenders(&env, &owner).len(), 0);
+            assert_eq!(storage::get_allowance_expiration(&env, &owner, &spender), None);
+            assert_eq!(storage::get_allowance_expiration(&env, &owner, &burner), None);
+        });
+    }
+
+    #[test]
+    fn test_preview_mint() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let denied = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        contract.set_max_supply(&admin, &5000);
+        
+        // The projected supply, with nothing changed
+        assert_eq!(contract.preview_mint(&holder, &2500), 3500);
+        assert_eq!(contract.total_supply(), 1000);
+        assert_eq!(contract.preview_mint(&holder, &4000), 5000);
+        
+        // Cap and recipient compliance are respected
+        let result = contract.try_preview_mint(&holder, &4001);
+        assert_eq!(result, Err(Ok(StablecoinError::ExceedsMaxSupply)));
+        contract.add_to_denylist(&admin, &denied);
+        let result = contract.try_preview_mint(&denied, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+    }
+
+    #[test]
+    fn test_set_config_atomically() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let config = StablecoinConfig {
+            max_supply: 1_000_000,
+            target_supply: 500_000,
+            max_batch_size: 25,
+            mint_flow_cap: 10_000,
+            burn_flow_cap: 5_000,
+            mint_cooldown_ledgers: 12,
+            allowance_grace_ledgers: 60,
+            strict_validation: false,
+            allow_self_transfer: true,
+            reject_duplicate_mints: true,
+            role_gated_burns: true,
+        };
+        
+        contract.set_config(&admin, &config);
+        assert_eq!(contract.get_config(), config);
+        assert_eq!(contract.get_max_supply(), 1_000_000);
+        assert_eq!(contract.get_global_flow_caps(), (10_000, 5_000));
+        assert!(contract.is_role_gated_burns());
+        
+        // One batch event records the change
+        let last = env.events().all().last().unwrap();
+        assert_eq!(
+            vec![&env, last],
+            vec![
+                &env,
+                (
+                    contract.address.clone(),
+                    (Symbol::new(&env, "config_changed"),).into_val(&env),
+                    (
+                        contract.current_op_seq(),
+                        ConfigChanged { key: Symbol::new(&env, "config"), by: admin.clone() },
+                    ).into_val(&env),
+                ),
+            ]
+        );
+        
+        // A target above the max is rejected and nothing else is applied
+        let invalid = StablecoinConfig { target_supply: 2_000_000, max_batch_size: 50, ..config.clone() };
+        let result = contract.try_set_config(&admin, &invalid);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        let invalid = StablecoinConfig { max_batch_size: 50, allowance_grace_ledgers: 100_000, ..config.clone() };
+        let result = contract.try_set_config(&admin, &invalid);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert_eq!(contract.get_config(), config);
+    }
+
+    #[test]
+    fn test_mint_to_frozen_policy() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let frozen = Address::generate(&env);
+        let holder = Address::generate(&env);
+        contract.freeze_account(&admin, &frozen);
+        
+        // Allowed by default so balances can be corrected
+        assert!(!contract.blocks_mint_to_frozen());
+        contract.mint(&minter, &frozen, &100);
+        contract.batch_mint(&minter, &Vec::from_array(&env, [(frozen.clone(), 50)]));
+        assert_eq!(contract.balance(&frozen), 150);
+        
+        // The strict policy rejects single and batch mints to the frozen recipient
+        contract.set_block_mint_to_frozen(&admin, &true);
+        let result = contract.try_mint(&minter, &frozen, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountFrozen)));
+        let recipients = Vec::from_array(&env, [(holder.clone(), 100), (frozen.clone(), 100)]);
+        let result = contract.try_batch_mint(&minter, &recipients);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountFrozen)));
+        assert_eq!(contract.balance(&holder), 0);
+        assert_eq!(contract.balance(&frozen), 150);
+    }
+
+    #[test]
+    fn test_lock_upgrades() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, upgrader, _minter) = setup(&env);
+        let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+        assert!(!contract.upgrades_locked());
+        
+        // Only upgrader role holders may upgrade
+        let result = contract.try_upgrade(&pauser, &wasm_hash);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // Only the admin may lock
+        let result = contract.try_lock_upgrades(&upgrader);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // Once locked, even a valid upgrader is refused
+        contract.lock_upgrades(&admin);
+        assert!(contract.upgrades_locked());
+        assert!(contract.has_role_upgrader(&upgrader));
+        let result = contract.try_upgrade(&upgrader, &wasm_hash);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_recommended_allowance_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 1000);
+        
+        // Tracks the current ledger plus the default TTL
+        let ttl = contract.get_default_allowance_ttl();
+        assert_eq!(contract.recommended_allowance_expiration(), 1000 + ttl);
+        env.ledger().with_mut(|li| li.sequence_number = 1500);
+        assert_eq!(contract.recommended_allowance_expiration(), 1500 + ttl);
+        
+        // And follows a reconfigured TTL
+        contract.set_default_allowance_ttl(&admin, &500);
+        assert_eq!(contract.recommended_allowance_expiration(), 2000);
+        let result = contract.try_set_default_allowance_ttl(&admin, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // approve_for uses the recommendation
+        contract.mint(&minter, &owner, &1000);
+        contract.approve_for(&owner, &spender, &300);
+        assert_eq!(contract.allowance(&owner, &spender), 300);
+        env.ledger().with_mut(|li| li.sequence_number = 2001);
+        assert_eq!(contract.allowance(&owner, &spender), 0);
+    }
+
+    #[test]
+    fn test_private_stats_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let officer = Address::generate(&env);
+        contract.grant_role(&admin, &officer, &Symbol::new(&env, "compliance"));
+        contract.mint(&minter, &holder, &1000);
+        
+        // Public by default
+        assert_eq!(contract.get_stats(&holder).total_minted, 1000);
+        
+        // Private mode gates detailed stats to the admin and compliance role
+        contract.set_private_stats(&admin, &true);
+        assert!(contract.is_private_stats());
+        let result = contract.try_get_stats(&holder);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        assert_eq!(contract.get_stats(&admin).active_holders, 1);
+        assert_eq!(contract.get_stats(&officer).total_minted, 1000);
+        
+        // Total supply stays public
+        assert_eq!(contract.total_supply(), 1000);
+    }
+
+    #[test]
+    fn test_batch_compliance() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let officer = Address::generate(&env);
+        contract.grant_role(&admin, &officer, &Symbol::new(&env, "compliance"));
+        let sanctioned = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let cleared = Address::generate(&env);
+        contract.freeze_account(&admin, &cleared);
+        
+        let denylist = Symbol::new(&env, "denylist");
+        let actions = Vec::from_array(&env, [
+            (sanctioned[0].clone(), denylist.clone()),
+            (sanctioned[1].clone(), denylist.clone()),
+            (sanctioned[2].clone(), denylist.clone()),
+            (cleared.clone(), Symbol::new(&env, "unfreeze")),
+        ]);
+        contract.batch_compliance(&officer, &actions);
+        
+        for account in sanctioned.iter() {
+            assert!(contract.is_denylisted(account));
+        }
+        assert!(!contract.is_frozen(&cleared));
+        assert_eq!(contract.denylist_count(), 3);
+        
+        // One event per action
+        let action_events = env.events().all().iter().filter(|(_, topics, _)| {
+            let name: Symbol = topics.get_unchecked(0).into_val(&env);
+            name == Symbol::new(&env, "denylist_add") || name == Symbol::new(&env, "unfreeze")
+        }).count();
+        assert_eq!(action_events, 4);
+        
+        // Unknown actions reject the whole batch
+        let other = Address::generate(&env);
+        let actions = Vec::from_array(&env, [
+            (other.clone(), Symbol::new(&env, "freeze")),
+            (other.clone(), Symbol::new(&env, "seize")),
+        ]);
+        let result = contract.try_batch_compliance(&officer, &actions);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert!(!contract.is_frozen(&other));
+        
+        // Callers without the role are refused
+        let actions = Vec::from_array(&env, [(other.clone(), Symbol::new(&env, "freeze"))]);
+        let result = contract.try_batch_compliance(&other, &actions);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_pause_with_allowance_freeze_and_restore() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let other_owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        contract.mint(&minter, &other_owner, &1000);
+        contract.approve(&owner, &spender, &300, &1000);
+        contract.approve(&other_owner, &spender, &200, &1000);
+        
+        // Allowances are zeroed for the duration of the pause
+        assert_eq!(contract.pause_with_allowance_freeze(&pauser), 2);
+        assert!(contract.is_paused());
+        assert_eq!(contract.allowance(&owner, &spender), 0);
+        assert_eq!(contract.allowance(&other_owner, &spender), 0);
+        assert_eq!(contract.get_allowances(&owner).len(), 0);
+        
+        // Unpausing restores them from the snapshot
+        assert_eq!(contract.unpause_restore_allowances(&pauser), 2);
+        assert!(!contract.is_paused());
+        assert_eq!(contract.allowance(&owner, &spender), 300);
+        assert_eq!(contract.allowance(&other_owner, &spender), 200);
+        contract.transfer_from(&spender, &owner, &recipient, &300);
+        assert_eq!(contract.balance(&recipient), 300);
+        
+        // Without a snapshot there is nothing to restore
+        contract.pause(&pauser);
+        let result = contract.try_unpause_restore_allowances(&pauser);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_allowance_freeze_never_blocks_the_pause() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, pauser, _upgrader, minter) = setup(&env);
+        let mut owners = Vec::new(&env);
+        for _ in 0..3 {
+            let owner = Address::generate(&env);
+            contract.mint(&minter, &owner, &1000);
+            for _ in 0..40 {
+                contract.approve(&owner, &Address::generate(&env), &10, &1000);
+            }
+            owners.push_back(owner);
+        }
+        
+        // More allowances than the snapshot holds: the pause still lands and the snapshot is capped
+        assert_eq!(contract.pause_with_allowance_freeze(&pauser), MAX_ALLOWANCE_SNAPSHOT);
+        assert!(contract.is_paused());
+        let live: u32 = owners.iter().map(|owner| contract.get_allowances(&owner).len()).sum();
+        assert_eq!(live, 120 - MAX_ALLOWANCE_SNAPSHOT);
+        
+        assert_eq!(contract.unpause_restore_allowances(&pauser), MAX_ALLOWANCE_SNAPSHOT);
+        let live: u32 = owners.iter().map(|owner| contract.get_allowances(&owner).len()).sum();
+        assert_eq!(live, 120);
+    }
+
+    #[test]
+    fn test_precision_step() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        
+        // This token has 2 decimals, so a step of 10 enforces 10-cent granularity
+        contract.set_precision_step(&admin, &10);
+        assert_eq!(contract.get_precision_step(), 10);
+        contract.mint(&minter, &holder, &1000);
+        contract.transfer(&holder, &recipient, &250);
+        contract.burn(&holder, &100);
+        
+        // Finer amounts are rejected on every path
+        assert_eq!(contract.try_mint(&minter, &holder, &1005), Err(Ok(StablecoinError::InvalidAmount)));
+        assert_eq!(contract.try_transfer(&holder, &recipient, &1), Err(Ok(StablecoinError::InvalidAmount)));
+        assert_eq!(contract.try_burn(&holder, &99), Err(Ok(StablecoinError::InvalidAmount)));
+        
+        // Zero disables the step
+        contract.set_precision_step(&admin, &0);
+        contract.transfer(&holder, &recipient, &1);
+        assert_eq!(contract.balance(&recipient), 251);
+    }
+
+    #[test]
+    fn test_account_storage_footprint() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let account = Address::generate(&env);
+        let spender = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 10);
+        assert_eq!(contract.account_storage_footprint(&account), 0);
+        
+        // Balance plus holder mark
+        contract.mint(&minter, &account, &1000);
+        assert_eq!(contract.account_storage_footprint(&account), 2);
+        
+        // Flags and locks each add entries
+        contract.freeze_account(&admin, &account);
+        assert_eq!(contract.account_storage_footprint(&account), 3);
+        contract.lock_balance(&admin, &account, &100, &100);
+        assert_eq!(contract.account_storage_footprint(&account), 4);
+        contract.unfreeze_account(&admin, &account);
+        assert_eq!(contract.account_storage_footprint(&account), 3);
+        
+        // An allowance adds the spender index, owner index and per-spender entries
+        contract.approve(&account, &spender, &100, &1000);
+        assert_eq!(contract.account_storage_footprint(&account), 9);
+    }
+
+    #[test]
+    fn test_initialize_with_vested_treasury() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let contract = MyStablecoinClient::new(&env, &env.register(MyStablecoin, ()));
+        contract.initialize_with_treasury(
+            &admin,
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &treasury,
+            &10_000,
+            &100,
+            &1000,
+            &None,
+        );
+        assert_eq!(contract.balance(&treasury), 10_000);
+        assert_eq!(contract.total_supply(), 10_000);
+        
+        // Nothing is transferable before the cliff
+        env.ledger().with_mut(|li| li.sequence_number = 150);
+        assert_eq!(contract.vested_amount(&treasury), 0);
+        assert_eq!(contract.spendable_balance(&treasury), 0);
+        let result = contract.try_transfer(&treasury, &recipient, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // Linear vesting after the cliff
+        env.ledger().with_mut(|li| li.sequence_number = 600);
+        assert_eq!(contract.vested_amount(&treasury), 5_000);
+        assert_eq!(contract.spendable_balance(&treasury), 5_000);
+        contract.transfer(&treasury, &recipient, &5_000);
+        let result = contract.try_transfer(&treasury, &recipient, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // Fully vested at the end
+        env.ledger().with_mut(|li| li.sequence_number = 1100);
+        assert_eq!(contract.vested_amount(&treasury), 10_000);
+        assert_eq!(contract.spendable_balance(&treasury), 5_000);
+        contract.transfer(&treasury, &recipient, &5_000);
+        assert_eq!(contract.balance(&recipient), 10_000);
+    }
+
+    #[test]
+    fn test_quote_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let collector = Address::generate(&env);
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &sender, &1_000_000);
+        contract.mint(&minter, &collector, &1_000_000);
+        
+        // Without a fee config the recipient receives everything
+        assert_eq!(contract.quote_transfer(&sender, &recipient, &1000), (1000, 0));
+        
+        // 1% fee, capped at 50
+        contract.set_fee_config(&admin, &100, &collector, &50);
+        assert_eq!(contract.quote_transfer(&sender, &recipient, &1000), (990, 10));
+        
+        // A capped large transfer matches what is actually charged
+        let (net, fee) = contract.quote_transfer(&sender, &recipient, &100_000);
+        assert_eq!((net, fee), (99_950, 50));
+        contract.transfer(&sender, &recipient, &100_000);
+        assert_eq!(contract.balance(&recipient), net);
+        assert_eq!(contract.balance(&collector), 1_000_000 + fee);
+        
+        // The exempt collector sends for free
+        assert_eq!(contract.quote_transfer(&collector, &recipient, &100_000), (100_000, 0));
+        contract.transfer(&collector, &recipient, &100_000);
+        assert_eq!(contract.balance(&recipient), net + 100_000);
+    }
+
+    #[test]
+    fn test_transfer_from_requires_spender_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &500, &1000);
+        
+        // Drop the mocked auths: the spender has not signed
+        env.set_auths(&[]);
+        
+        assert!(contract.try_transfer_from(&spender, &owner, &recipient, &100).is_err());
+        assert!(contract.try_burn_from(&spender, &owner, &100).is_err());
+        assert_eq!(contract.balance(&owner), 1000);
+        assert_eq!(contract.balance(&recipient), 0);
+        assert_eq!(contract.allowance(&owner, &spender), 500);
+    }
+
+    #[test]
+    fn test_transfer_from_and_burn_from_with_single_spender_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &500, &1000);
+        
+        // Exactly one signature from the spender covers each delegated call
+        contract
+            .mock_auths(&[MockAuth {
+                address: &spender,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "transfer_from",
+                    args: (&spender, &owner, &recipient, 100_i128).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .transfer_from(&spender, &owner, &recipient, &100);
+        contract
+            .mock_auths(&[MockAuth {
+                address: &spender,
+                invoke: &MockAuthInvoke {
+                    contract: &contract.address,
+                    fn_name: "burn_from",
+                    args: (&spender, &owner, 100_i128).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .burn_from(&spender, &owner, &100);
+        
+        assert_eq!(contract.balance(&owner), 800);
+        assert_eq!(contract.balance(&recipient), 100);
+        assert_eq!(contract.allowance(&owner, &spender), 300);
+    }
+
+    #[test]
+    fn test_lock_cap_and_consolidation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let account = Address::generate(&env);
+        contract.mint(&minter, &account, &1000);
+        assert_eq!(contract.get_max_locks_per_account(), 16);
+        
+        contract.set_max_locks_per_account(&admin, &2);
+        contract.lock_balance(&admin, &account, &100, &10);
+        contract.lock_balance(&admin, &account, &100, &50);
+        
+        // The cap is reached
+        let result = contract.try_lock_balance(&admin, &account, &100, &50);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // A released lock still holds its slot until consolidated
+        env.ledger().with_mut(|li| li.sequence_number = 20);
+        let result = contract.try_lock_balance(&admin, &account, &100, &50);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        assert_eq!(contract.consolidate_locks(&account), 1);
+        assert_eq!(contract.get_locks(&account).len(), 1);
+        assert_eq!(contract.locked_balance(&account), 100);
+        
+        contract.lock_balance(&admin, &account, &100, &50);
+        assert_eq!(contract.locked_balance(&account), 200);
+        assert_eq!(contract.consolidate_locks(&account), 0);
+        
+        let result = contract.try_set_max_locks_per_account(&admin, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_health() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let uninitialized = MyStablecoinClient::new(&env, &env.register(MyStablecoin, ()));
+        assert_eq!(uninitialized.health(), Symbol::new(&env, "uninitialized"));
+        
+        let (contract, _admin, pauser, _upgrader, _minter) = setup(&env);
+        assert_eq!(contract.health(), Symbol::new(&env, "ok"));
+        
+        contract.pause(&pauser);
+        assert_eq!(contract.health(), Symbol::new(&env, "paused"));
+        contract.unpause(&pauser);
+        assert_eq!(contract.health(), Symbol::new(&env, "ok"));
+        
+        contract.pause_with_allowance_freeze(&pauser);
+        assert_eq!(contract.health(), Symbol::new(&env, "emergency"));
+        contract.unpause_restore_allowances(&pauser);
+        assert_eq!(contract.health(), Symbol::new(&env, "ok"));
+    }
+
+    #[test]
+    fn test_self_approval_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        
+        let result = contract.try_approve(&owner, &owner, &500, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::SelfTransfer)));
+        let result = contract.try_approve_for(&owner, &owner, &500);
+        assert_eq!(result, Err(Ok(StablecoinError::SelfTransfer)));
+        assert_eq!(contract.allowance(&owner, &owner), 0);
+    }
+
+    #[test]
+    fn test_batch_events_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let count_topic = |name: &str| {
+            env.events().all().iter().filter(|(_, topics, _)| {
+                let topic: Symbol = topics.get_unchecked(0).into_val(&env);
+                topic == Symbol::new(&env, name)
+            }).count()
+        };
+        
+        // Per-entry events by default
+        assert!(!contract.is_batch_events());
+        contract.batch_mint(&minter, &vec![&env, (alice.clone(), 100), (bob.clone(), 200)]);
+        assert_eq!(count_topic("mint"), 2);
+        assert_eq!(count_topic("batch_minted"), 0);
+        
+        // Exactly one transfer event per batch transfer entry
+        contract.batch_transfer(&alice, &vec![&env, (bob.clone(), 10), (admin.clone(), 10)]);
+        assert_eq!(count_topic("transfer"), 2);
+        assert_eq!(count_topic("batch_transferred"), 0);
+        
+        // A single aggregate event in batch-events mode
+        contract.set_batch_events(&admin, &true);
+        contract.batch_mint(&minter, &vec![&env, (alice.clone(), 100), (bob.clone(), 200)]);
+        assert_eq!(count_topic("mint"), 0);
+        assert_eq!(count_topic("batch_minted"), 1);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, summary): (u64, BatchSummary) = data.into_val(&env);
+        assert_eq!(summary, BatchSummary { count: 2, total: 300 });
+        
+        contract.batch_transfer(&alice, &vec![&env, (bob.clone(), 50), (admin.clone(), 25)]);
+        assert_eq!(count_topic("transfer"), 0);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, summary): (u64, BatchSummary) = data.into_val(&env);
+        assert_eq!(summary, BatchSummary { count: 2, total: 75 });
+        assert_eq!(contract.balance(&bob), 460);
+    }
+
+    #[test]
+    fn test_private_role_reads() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let outsider = Address::generate(&env);
+        let officer = Address::generate(&env);
+        contract.grant_role(&admin, &officer, &Symbol::new(&env, "compliance"));
+        
+        // Public by default
+        assert!(!contract.is_private_roles());
+        assert_eq!(contract.get_admin(), Some(admin.clone()));
+        assert!(contract.has_role_minter(&minter));
+        assert_eq!(contract.get_admin_as(&outsider), Some(admin.clone()));
+        
+        // Gated mode: the caller-less reads fail and only the admin and compliance
+        // officers may read through the named variants
+        contract.set_private_roles(&admin, &true);
+        assert!(contract.try_get_admin().is_err());
+        assert!(contract.try_has_role_minter(&minter).is_err());
+        assert!(contract.try_has_role_pauser(&minter).is_err());
+        assert!(contract.try_has_role_upgrader(&minter).is_err());
+        assert!(contract.try_system_overview().is_err());
+        let minter_role = Symbol::new(&env, "minter");
+        assert_eq!(contract.try_get_admin_as(&outsider), Err(Ok(StablecoinError::Unauthorized)));
+        assert_eq!(contract.try_has_role_as(&outsider, &minter, &minter_role), Err(Ok(StablecoinError::Unauthorized)));
+        assert_eq!(contract.try_system_overview_as(&outsider), Err(Ok(StablecoinError::Unauthorized)));
+        
+        assert_eq!(contract.get_admin_as(&admin), Some(admin.clone()));
+        assert!(contract.has_role_as(&officer, &minter, &minter_role));
+        assert!(!contract.system_overview_as(&officer).is_empty());
+        
+        contract.set_private_roles(&admin, &false);
+        assert!(contract.has_role_minter(&minter));
+    }
+
+    #[test]
+    fn test_mint_for_deposit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let customer = Address::generate(&env);
+        let bank_ref = BytesN::from_array(&env, &[7; 32]);
+        
+        assert!(!contract.deposit_processed(&bank_ref));
+        contract.mint_for_deposit(&minter, &customer, &500, &bank_ref);
+        assert!(contract.deposit_processed(&bank_ref));
+        assert_eq!(contract.balance(&customer), 500);
+        
+        // A replayed webhook cannot double-issue, even for a different amount or recipient
+        let result = contract.try_mint_for_deposit(&minter, &customer, &500, &bank_ref);
+        assert_eq!(result, Err(Ok(StablecoinError::DuplicateOperation)));
+        let result = contract.try_mint_for_deposit(&minter, &Address::generate(&env), &900, &bank_ref);
+        assert_eq!(result, Err(Ok(StablecoinError::DuplicateOperation)));
+        assert_eq!(contract.total_supply(), 500);
+        
+        // A new reference mints normally
+        contract.mint_for_deposit(&minter, &customer, &250, &BytesN::from_array(&env, &[8; 32]));
+        assert_eq!(contract.balance(&customer), 750);
+    }
+
+    #[test]
+    fn test_transfer_partial() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let payer = Address::generate(&env);
+        let payee = Address::generate(&env);
+        contract.mint(&minter, &payer, &1000);
+        
+        // Opt-in only
+        let result = contract.try_transfer_partial(&payer, &payee, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        contract.set_partial_transfers(&admin, &true);
+        
+        // The full amount moves when available
+        assert_eq!(contract.transfer_partial(&payer, &payee, &400), 400);
+        assert_eq!(contract.balance(&payee), 400);
+        
+        // Only the available part moves otherwise
+        assert_eq!(contract.transfer_partial(&payer, &payee, &1000), 600);
+        assert_eq!(contract.balance(&payer), 0);
+        assert_eq!(contract.balance(&payee), 1000);
+        
+        // Nothing available is a clean no-op
+        assert_eq!(contract.transfer_partial(&payer, &payee, &50), 0);
+        assert_eq!(contract.balance(&payee), 1000);
+        
+        // Strict transfer is unchanged
+        let result = contract.try_transfer(&payee, &payer, &2000);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+    }
+
+    #[test]
+    fn test_migrate_storage() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        assert_eq!(contract.storage_version(), 2);
+        
+        // Nothing to migrate on a current layout
+        let result = contract.try_migrate_storage(&admin, &2);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Simulate a deployment from before versioning
+        env.as_contract(&contract.address, || {
+            env.storage().instance().remove(&storage::StateKey::StorageVersion);
+            env.storage().instance().remove(&storage::StateKey::InitLedger);
+            env.storage().instance().remove(&storage::ConfigKey::MaxSupply);
+        });
+        assert_eq!(contract.storage_version(), 1);
+        
+        // The stated version must match the stored one
+        let result = contract.try_migrate_storage(&admin, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        env.ledger().with_mut(|li| li.sequence_number = 40);
+        assert_eq!(contract.migrate_storage(&admin, &1), 2);
+        assert_eq!(contract.storage_version(), 2);
+        env.as_contract(&contract.address, || {
+            assert_eq!(storage::get_init_ledger(&env), 40);
+            assert!(env.storage().instance().has(&storage::ConfigKey::MaxSupply));
+        });
+        
+        // A migration never runs twice
+        let result = contract.try_migrate_storage(&admin, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_pause_immune_roles() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let other = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        contract.mint(&minter, &other, &1000);
+        contract.freeze_account(&admin, &holder);
+        assert_eq!(contract.pause_immune_roles(), vec![&env, Symbol::new(&env, "compliance")]);
+        
+        // Clawback keeps working during a normal pause; transfers and mints do not
+        contract.pause(&pauser);
+        contract.clawback(&admin, &holder, &100);
+        assert_eq!(contract.balance(&holder), 900);
+        let result = contract.try_transfer(&other, &holder, &10);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        let result = contract.try_mint(&minter, &other, &10);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        contract.unpause(&pauser);
+        
+        // Nothing is immune to an emergency freeze
+        contract.pause_with_allowance_freeze(&pauser);
+        let result = contract.try_clawback(&admin, &holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        contract.unpause_restore_allowances(&pauser);
+        
+        // The immune set is configurable, but minting always stops with the pause
+        contract.set_pause_immune_roles(&admin, &vec![&env, Symbol::new(&env, "minter")]);
+        contract.pause(&pauser);
+        let result = contract.try_clawback(&admin, &holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        let result = contract.try_mint(&minter, &other, &10);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        
+        let result = contract.try_set_pause_immune_roles(&admin, &vec![&env, Symbol::new(&env, "nobody")]);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidRole)));
+    }
+
+    #[test]
+    fn test_recent_operations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        assert_eq!(contract.recent_operations(&5).len(), 0);
+        
+        contract.mint(&minter, &alice, &1000);
+        contract.transfer(&alice, &bob, &300);
+        contract.burn(&bob, &100);
+        
+        // Newest first
+        let ops = contract.recent_operations(&5);
+        assert_eq!(ops.len(), 3);
+        assert_eq!(ops.get_unchecked(0), OpSummary {
+            op: Symbol::new(&env, "burn"),
+            from: Some(bob.clone()),
+            to: None,
+            amount: 100,
+            ledger: env.ledger().sequence(),
+        });
+        assert_eq!(ops.get_unchecked(1).op, Symbol::new(&env, "transfer"));
+        assert_eq!(ops.get_unchecked(1).to, Some(bob.clone()));
+        assert_eq!(ops.get_unchecked(2).op, Symbol::new(&env, "mint"));
+        assert_eq!(ops.get_unchecked(2).from, None);
+        assert_eq!(contract.recent_operations(&2).len(), 2);
+        
+        // The oldest entries are overwritten once the buffer is full
+        for amount in 1..=25 {
+            contract.transfer(&alice, &bob, &amount);
+        }
+        let ops = contract.recent_operations(&100);
+        assert_eq!(ops.len(), 20);
+        assert_eq!(ops.get_unchecked(0).amount, 25);
+        assert_eq!(ops.get_unchecked(19).amount, 6);
+    }
+
+    #[test]
+    fn test_backing_decimal_conversion() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, _minter) = setup(&env);
+        assert_eq!(contract.backing_decimals(), 2);
+        
+        // Upscaling never loses precision; downscaling must divide exactly
+        assert_eq!(utils::convert_decimals(5, 0, 2), Ok(500));
+        assert_eq!(utils::convert_decimals(1_230_000, 6, 2), Ok(123));
+        assert_eq!(utils::convert_decimals(1_234_500, 6, 2), Err(StablecoinError::InvalidAmount));
+        
+        env.as_contract(&contract.address, || {
+            // A 6-decimal backing asset
+            storage::set_backing_decimals(&env, 6);
+            assert_eq!(utils::deposit_backing(&env, 2_500_000), Ok(()));
+            assert_eq!(storage::get_backing_reserve(&env), 250);
+            assert_eq!(utils::deposit_backing(&env, 1_234_567), Err(StablecoinError::InvalidAmount));
+            assert_eq!(utils::withdraw_backing(&env, 1_000_001), Err(StablecoinError::InvalidAmount));
+            assert_eq!(utils::withdraw_backing(&env, 1_000_000), Ok(()));
+            assert_eq!(storage::get_backing_reserve(&env), 150);
+            
+            // A 0-decimal backing asset is upscaled
+            storage::set_backing_decimals(&env, 0);
+            assert_eq!(utils::deposit_backing(&env, 3), Ok(()));
+            assert_eq!(storage::get_backing_reserve(&env), 450);
+            assert_eq!(utils::withdraw_backing(&env, 5), Err(StablecoinError::InsufficientBalance));
+            assert_eq!(utils::withdraw_backing(&env, 4), Ok(()));
+            assert_eq!(storage::get_backing_reserve(&env), 50);
+        });
+    }
+
+    #[test]
+    fn test_strict_backing() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let treasury = Address::generate(&env);
+        let user = Address::generate(&env);
+        
+        // The admin switch is behind the backing feature flag
+        let result = contract.try_set_strict_backing(&admin, &true);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        
+        // Permissive mode allows treasury pre-minting without reserves
+        contract.mint(&minter, &treasury, &100);
+        
+        env.as_contract(&contract.address, || {
+            // Strict mode cannot be enabled while existing supply is unbacked
+            assert_eq!(utils::update_strict_backing(&env, true), Err(StablecoinError::Undercollateralized));
+            assert_eq!(utils::deposit_backing(&env, 100), Ok(()));
+            assert_eq!(utils::update_strict_backing(&env, true), Ok(()));
+        });
+        assert!(contract.is_strict_backing());
+        
+        // An unbacked mint fails, in batches too
+        let result = contract.try_mint(&minter, &user, &50);
+        assert_eq!(result, Err(Ok(StablecoinError::Undercollateralized)));
+        let result = contract.try_airdrop(&minter, &25, &vec![&env, user.clone(), treasury.clone()]);
+        assert_eq!(result, Err(Ok(StablecoinError::Undercollateralized)));
+        
+        // Deposit, then mint
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::deposit_backing(&env, 50), Ok(()));
+        });
+        contract.mint(&minter, &user, &50);
+        assert_eq!(contract.balance(&user), 50);
+        
+        // Reserves cannot be withdrawn below the supply
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::withdraw_backing(&env, 1), Err(StablecoinError::Undercollateralized));
+        });
+    }
+
+    #[test]
+    fn test_genesis_backing() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let treasury = Address::generate(&env);
+        let genesis = |contract: &MyStablecoinClient, backing: Option<GenesisBacking>| {
+            contract.try_initialize_with_treasury(
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &treasury,
+                &1000,
+                &0,
+                &100,
+                &backing,
+            )
+        };
+        
+        // Genesis backing is behind the backing feature flag
+        let contract = MyStablecoinClient::new(&env, &env.register(MyStablecoin, ()));
+        let result = genesis(&contract, Some(GenesisBacking { strict: true, reserve: 1000 }));
+        assert_eq!(result.map(|_| ()), Err(Ok(StablecoinError::FeatureDisabled)));
+        
+        // Without backing the genesis mint stays permissive
+        assert!(genesis(&contract, None).is_ok());
+        assert!(!contract.is_strict_backing());
+        assert_eq!(contract.total_supply(), 1000);
+        
+        // A strict genesis mint must fit the reserve recorded with it
+        let (strict, _admin, _pauser, _upgrader, _minter) = setup(&env);
+        env.as_contract(&strict.address, || {
+            let backing = GenesisBacking { strict: true, reserve: -1 };
+            assert_eq!(utils::apply_genesis_backing(&env, &backing), Err(StablecoinError::InvalidAmount));
+            
+            let backing = GenesisBacking { strict: true, reserve: 999 };
+            assert_eq!(utils::apply_genesis_backing(&env, &backing), Ok(()));
+            assert_eq!(utils::mint_vested_treasury(&env, &treasury, 1000, 0, 100), Err(StablecoinError::Undercollateralized));
+            assert_eq!(utils::deposit_backing(&env, 1), Ok(()));
+            assert_eq!(utils::mint_vested_treasury(&env, &treasury, 1000, 0, 100), Ok(()));
+        });
+        assert!(strict.is_strict_backing());
+        assert_eq!(strict.total_supply(), 1000);
+    }
+
+    #[test]
+    fn test_transfer_and_call() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let sender = Address::generate(&env);
+        contract.mint(&minter, &sender, &1000);
+        let data = Bytes::from_array(&env, &[1, 2, 3]);
+        
+        // The receiver is credited and notified in the same transaction
+        let receiver_id = env.register(MockReceiver, ());
+        let receiver = MockReceiverClient::new(&env, &receiver_id);
+        contract.transfer_and_call(&sender, &receiver_id, &400, &data);
+        assert_eq!(contract.balance(&receiver_id), 400);
+        assert_eq!(receiver.received(), vec![&env, (sender.clone(), 400, data.clone())]);
+        
+        // A rejecting receiver reverts the whole transfer
+        let rejecting_id = env.register(RejectingReceiver, ());
+        let result = contract.try_transfer_and_call(&sender, &rejecting_id, &100, &data);
+        assert_eq!(result, Err(Ok(StablecoinError::HookFailed)));
+        assert_eq!(contract.balance(&rejecting_id), 0);
+        assert_eq!(contract.balance(&sender), 600);
+    }
+
+    #[test]
+    fn test_timelocked_actions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        contract.set_timelock_delay(&admin, &100);
+        let args: Vec<Val> = vec![&env, 5_000_000_i128.into_val(&env)];
+        let hash = contract.action_hash(&Symbol::new(&env, "set_max_supply"), &args);
+        
+        // The eta must leave at least the configured delay
+        let result = contract.try_schedule_action(&admin, &hash, &50);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        contract.schedule_action(&admin, &hash, &100);
+        
+        // Executing before the eta fails
+        env.ledger().with_mut(|li| li.sequence_number = 99);
+        let result = contract.try_execute_action(&admin, &hash);
+        assert_eq!(result, Err(Ok(StablecoinError::TimelockNotExpired)));
+        
+        // Executing after the eta succeeds and releases exactly one matching call
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        contract.execute_action(&admin, &hash);
+        assert_eq!(contract.get_scheduled_action(&hash).map(|action| action.executed), Some(true));
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::consume_executed_action(&env, &hash), Ok(()));
+            assert_eq!(utils::consume_executed_action(&env, &hash), Err(StablecoinError::Unauthorized));
+        });
+        
+        // Cancelled actions can no longer be executed
+        contract.schedule_action(&admin, &hash, &300);
+        contract.cancel_action(&admin, &hash);
+        env.ledger().with_mut(|li| li.sequence_number = 300);
+        let result = contract.try_execute_action(&admin, &hash);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_timelock_guards_its_own_delay() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        contract.set_timelock_delay(&admin, &100);
+        
+        // Lowering the delay is itself a guarded call that must be scheduled first
+        let args: Vec<Val> = vec![&env, 0_u32.into_val(&env)];
+        env.as_contract(&contract.address, || {
+            let result = utils::consume_timelocked_call(&env, "set_timelock_delay", args.clone());
+            assert_eq!(result, Err(StablecoinError::Unauthorized));
+        });
+        
+        let hash = contract.action_hash(&Symbol::new(&env, "set_timelock_delay"), &args);
+        contract.schedule_action(&admin, &hash, &100);
+        env.as_contract(&contract.address, || {
+            let result = utils::consume_timelocked_call(&env, "set_timelock_delay", args.clone());
+            assert_eq!(result, Err(StablecoinError::TimelockNotExpired));
+        });
+        
+        // Once executed, the matching call goes through exactly once; other arguments never do
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        contract.execute_action(&admin, &hash);
+        env.as_contract(&contract.address, || {
+            let other: Vec<Val> = vec![&env, 1_u32.into_val(&env)];
+            assert_eq!(utils::consume_timelocked_call(&env, "set_timelock_delay", other), Err(StablecoinError::Unauthorized));
+            assert_eq!(utils::consume_timelocked_call(&env, "set_timelock_delay", args.clone()), Ok(()));
+            assert_eq!(utils::consume_timelocked_call(&env, "set_timelock_delay", args.clone()), Err(StablecoinError::Unauthorized));
+        });
+    }
+
+    #[test]
+    fn test_is_admin_and_admin_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, _minter) = setup(&env);
+        let successor = Address::generate(&env);
+        assert!(contract.is_admin(&admin));
+        assert!(!contract.is_admin(&pauser));
+        assert!(!contract.is_admin(&successor));
+        
+        // Nominating does not hand over the role
+        contract.transfer_admin(&admin, &successor);
+        assert_eq!(contract.pending_admin(), Some(successor.clone()));
+        assert!(contract.is_admin(&admin));
+        assert!(!contract.is_admin(&successor));
+        
+        // Only the nominee can accept
+        let result = contract.try_accept_admin(&pauser);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        contract.accept_admin(&successor);
+        assert!(contract.is_admin(&successor));
+        assert!(!contract.is_admin(&admin));
+        assert_eq!(contract.pending_admin(), None);
+        let result = contract.try_set_max_batch_size(&admin, &10);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_low_balance_alert() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let custodian = Address::generate(&env);
+        let payee = Address::generate(&env);
+        contract.mint(&minter, &custodian, &1000);
+        contract.set_balance_alert(&custodian, &custodian, &500);
+        let alerts = || env.events().all().iter().filter(|(_, topics, _)| {
+            let name: Symbol = topics.get_unchecked(0).into_val(&env);
+            name == Symbol::new(&env, "low_balance")
+        }).count();
+        
+        // Staying above the threshold is quiet
+        contract.transfer(&custodian, &payee, &400);
+        assert_eq!(alerts(), 0);
+        
+        // Crossing downward fires once
+        contract.transfer(&custodian, &payee, &200);
+        assert_eq!(alerts(), 1);
+        let (_, _, data) = env.events().all().iter().find(|(_, topics, _)| {
+            let name: Symbol = topics.get_unchecked(0).into_val(&env);
+            name == Symbol::new(&env, "low_balance")
+        }).unwrap();
+        let (op_seq, event): (u64, LowBalance) = data.into_val(&env);
+        assert_eq!(event, LowBalance { account: custodian.clone(), balance: 400 });
+        
+        // The alert carries the operation sequence of the transfer that triggered it
+        assert_eq!(op_seq, contract.current_op_seq());
+        assert!(!contract.get_balance_alert(&custodian).unwrap().armed);
+        
+        // Further debits below the threshold do not fire again
+        contract.transfer(&custodian, &payee, &100);
+        assert_eq!(alerts(), 0);
+        
+        // A re-credit re-arms the alert, so the next crossing fires
+        contract.transfer(&payee, &custodian, &300);
+        assert!(contract.get_balance_alert(&custodian).unwrap().armed);
+        contract.burn(&custodian, &200);
+        assert_eq!(alerts(), 1);
+    }
+
+    // Limits are expressed in base units: at DECIMALS = 2 one token is 100 base units
+
+    #[test]
+    fn test_limits_min_amount_at_two_decimals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        assert_eq!(DECIMALS, 2);
+        assert_eq!(contract.decimals(), 2);
+        
+        // The smallest amount is a single base unit, i.e. 0.01 tokens
+        assert_eq!(utils::format_display_amount(&env, MIN_AMOUNT, DECIMALS, DECIMALS), String::from_str(&env, "0.01"));
+        contract.mint(&minter, &holder, &MIN_AMOUNT);
+        contract.transfer(&holder, &payee, &MIN_AMOUNT);
+        assert_eq!(contract.balance(&payee), MIN_AMOUNT);
+        
+        // Anything below it is rejected
+        let result = contract.try_mint(&minter, &holder, &(MIN_AMOUNT - 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+        let result = contract.try_transfer(&payee, &holder, &(MIN_AMOUNT - 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+        let result = contract.try_burn(&payee, &(MIN_AMOUNT - 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_limits_max_single_operation_at_two_decimals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        
+        // One billion tokens per operation
+        assert_eq!(MAX_SINGLE_OPERATION, 1_000_000_000 * 10i128.pow(DECIMALS));
+        contract.mint(&minter, &holder, &MAX_SINGLE_OPERATION);
+        contract.mint(&minter, &holder, &MAX_SINGLE_OPERATION);
+        contract.transfer(&holder, &payee, &MAX_SINGLE_OPERATION);
+        
+        let result = contract.try_mint(&minter, &holder, &(MAX_SINGLE_OPERATION + 1));
+        assert_eq!(result, Err(Ok(StablecoinError::AmountTooLarge)));
+        let result = contract.try_transfer(&holder, &payee, &(MAX_SINGLE_OPERATION + 1));
+        assert_eq!(result, Err(Ok(StablecoinError::AmountTooLarge)));
+        let result = contract.try_burn(&holder, &(MAX_SINGLE_OPERATION + 1));
+        assert_eq!(result, Err(Ok(StablecoinError::AmountTooLarge)));
+    }
+
+    #[test]
+    fn test_limits_max_supply_at_two_decimals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        
+        // Ten trillion tokens in total
+        assert_eq!(MAX_SUPPLY, 10_000_000_000_000 * 10i128.pow(DECIMALS));
+        assert_eq!(contract.get_max_supply(), MAX_SUPPLY);
+        let result = contract.try_set_max_supply(&admin, &(MAX_SUPPLY + 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Reaching the cap takes thousands of capped mints, so seed supply just below it
+        env.as_contract(&contract.address, || {
+            stellar_fungible::Base::mint(&env, &holder, MAX_SUPPLY - MIN_AMOUNT);
+        });
+        contract.mint(&minter, &holder, &MIN_AMOUNT);
+        assert_eq!(contract.total_supply(), MAX_SUPPLY);
+        
+        let result = contract.try_mint(&minter, &holder, &MIN_AMOUNT);
+        assert_eq!(result, Err(Ok(StablecoinError::ExceedsMaxSupply)));
+    }
+
+    #[test]
+    fn test_compliance_clawback_bypasses_operation_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        let large = MAX_SINGLE_OPERATION + 1;
+        contract.mint(&minter, &holder, &MAX_SINGLE_OPERATION);
+        contract.mint(&minter, &holder, &MAX_SINGLE_OPERATION);
+        
+        // A user transfer of this size is over the per-operation limit
+        let result = contract.try_transfer(&holder, &payee, &large);
+        assert_eq!(result, Err(Ok(StablecoinError::AmountTooLarge)));
+        
+        // The same amount clawed back by compliance goes through
+        contract.freeze_account(&admin, &holder);
+        contract.clawback(&admin, &holder, &large);
+        assert_eq!(contract.balance(&holder), MAX_SINGLE_OPERATION * 2 - large);
+        assert_eq!(contract.total_supply(), MAX_SINGLE_OPERATION * 2 - large);
+        
+        // The minimum amount still applies
+        let result = contract.try_clawback(&admin, &holder, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_finalized_burn_events_by_origin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        let count = |name: &str| env.events().all().iter().filter(|(_, topics, _)| {
+            let topic: Symbol = topics.get_unchecked(0).into_val(&env);
+            topic == Symbol::new(&env, name)
+        }).count();
+        let last_data = || env.events().all().last().unwrap().2;
+        
+        // Holder burns, with or without a memo, are user burns
+        contract.burn(&holder, &100);
+        assert_eq!((count("user_burn"), count("protocol_burn")), (1, 0));
+        let (_, event): (u64, UserBurn) = last_data().into_val(&env);
+        assert_eq!(event, UserBurn { from: holder.clone(), amount: 100 });
+        
+        contract.burn_with_memo(&holder, &50, &String::from_str(&env, "REF-1"));
+        assert_eq!((count("user_burn"), count("protocol_burn")), (1, 0));
+        
+        contract.governed_burn(&holder, &admin, &50);
+        assert_eq!((count("user_burn"), count("protocol_burn")), (1, 0));
+        
+        // An ordinary spender burning under an allowance acts for the holder
+        contract.approve(&holder, &spender, &100, &1000);
+        contract.burn_from(&spender, &holder, &100);
+        assert_eq!((count("user_burn"), count("protocol_burn")), (1, 0));
+        
+        // The admin burning under an allowance is a protocol burn
+        contract.approve(&holder, &admin, &100, &1000);
+        contract.burn_from(&admin, &holder, &100);
+        assert_eq!((count("user_burn"), count("protocol_burn")), (0, 1));
+        let (_, event): (u64, ProtocolBurn) = last_data().into_val(&env);
+        assert_eq!(event, ProtocolBurn { amount: 100, by: admin.clone() });
+        
+        // So is a compliance clawback
+        contract.freeze_account(&admin, &holder);
+        contract.clawback(&admin, &holder, &100);
+        assert_eq!((count("user_burn"), count("protocol_burn")), (0, 1));
+        
+        // And the admin reducing its own treasury balance
+        contract.mint(&minter, &admin, &500);
+        contract.burn(&admin, &100);
+        assert_eq!((count("user_burn"), count("protocol_burn")), (0, 1));
+        let (_, event): (u64, ProtocolBurn) = last_data().into_val(&env);
+        assert_eq!(event, ProtocolBurn { amount: 100, by: admin.clone() });
+    }
+
+    #[test]
+    fn test_hold_period_after_receipt() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        let spender = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        
+        // Off by default
+        assert_eq!(contract.get_hold_period(), 0);
+        contract.mint(&minter, &holder, &1000);
+        contract.transfer(&holder, &payee, &100);
+        contract.transfer(&payee, &holder, &50);
+        assert_eq!(contract.locked_balance(&holder), 0);
+        
+        contract.set_hold_period(&admin, &10);
+        assert_eq!(contract.get_hold_period(), 10);
+        
+        // Only the amount just received is held, for direct and delegated transfers alike
+        contract.mint(&minter, &holder, &500);
+        assert_eq!(contract.locked_balance(&holder), 500);
+        let result = contract.try_transfer(&holder, &payee, &951);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        contract.approve(&holder, &spender, &1000, &1000);
+        let result = contract.try_transfer_from(&spender, &holder, &payee, &951);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // Dust sent to a holder holds only the dust, and receipts in one ledger share a tranche
+        contract.transfer(&payee, &holder, &1);
+        contract.transfer(&payee, &holder, &1);
+        assert_eq!(contract.get_locks(&holder).len(), 1);
+        assert_eq!(contract.spendable_balance(&holder), 950);
+        contract.transfer(&holder, &spender, &900);
+        
+        env.ledger().with_mut(|li| li.sequence_number = 109);
+        let result = contract.try_transfer(&holder, &payee, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // Movable once the period elapses; the recipient's receipt is then held
+        env.ledger().with_mut(|li| li.sequence_number = 110);
+        contract.transfer_from(&spender, &holder, &payee, &100);
+        assert_eq!(contract.locked_balance(&payee), 100);
+        let result = contract.try_transfer(&payee, &holder, &49);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        contract.transfer(&payee, &holder, &48);
+    }
+
+    #[test]
+    fn test_effective_limits_minter_vs_user() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        contract.set_minter_allowance(&admin, &minter, &Some(500));
+        contract.set_global_flow_caps(&admin, &1000, &300);
+        contract.mint(&minter, &user, &200);
+        
+        assert_eq!(contract.effective_limits(&minter), EffectiveLimits {
+            max_single_operation: Some(MAX_SINGLE_OPERATION),
+            compliance_exempt: false,
+            mint_allowance: Some(300),
+            mint_headroom: Some(800),
+            burn_headroom: Some(300),
+            outflow_headroom: 0,
+        });
+        assert_eq!(contract.effective_limits(&user), EffectiveLimits {
+            max_single_operation: Some(MAX_SINGLE_OPERATION),
+            compliance_exempt: false,
+            mint_allowance: Some(0),
+            mint_headroom: Some(0),
+            burn_headroom: Some(300),
+            outflow_headroom: 200,
+        });
+        
+        // Locks and burns shrink the user's headroom; the admin is compliance-exempt
+        contract.burn(&user, &50);
+        contract.freeze_account(&admin, &user);
+        let limits = contract.effective_limits(&user);
+        assert_eq!((limits.burn_headroom, limits.outflow_headroom), (Some(250), 0));
+        assert!(contract.effective_limits(&admin).compliance_exempt);
+    }
+
+    #[test]
+    fn test_ttl_maintenance_extends_after_threshold() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        let collector = Address::generate(&env);
+        let treasurer = Address::generate(&env);
+        contract.mint(&minter, &holder, &10_000);
+        contract.set_fee_config(&admin, &100, &collector, &0);
+        let instance_ttl = || env.as_contract(&contract.address, || env.storage().instance().get_ttl());
+        
+        let config = TtlMaintenance { every_ops: 3, extend_to: 100_000, fee_share_bps: 5_000 };
+        let result = contract.try_set_ttl_maintenance(&admin, &Some(TtlMaintenance { every_ops: 0, ..config.clone() }));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        contract.set_ttl_maintenance(&admin, &Some(config.clone()));
+        assert_eq!(contract.get_ttl_maintenance(), Some(config));
+        let ttl_before = instance_ttl();
+        assert!(ttl_before < 100_000);
+        
+        // Below the operation threshold nothing is extended
+        contract.transfer(&holder, &payee, &1000);
+        contract.transfer(&holder, &payee, &1000);
+        assert_eq!(instance_ttl(), ttl_before);
+        
+        // Crossing it extends the instance TTL
+        contract.transfer(&holder, &payee, &1000);
+        assert_eq!(instance_ttl(), 100_000);
+        
+        // Half of each 10 unit fee went to the rent reserve held by the contract
+        assert_eq!(contract.rent_reserve(), 15);
+        assert_eq!(contract.balance(&contract.address), 15);
+        assert_eq!(contract.balance(&collector), 15);
+        
+        let result = contract.try_withdraw_rent_reserve(&admin, &treasurer, &16);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // Flagged recipients cannot be paid from the reserve
+        contract.add_to_denylist(&admin, &treasurer);
+        let result = contract.try_withdraw_rent_reserve(&admin, &treasurer, &15);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        contract.remove_from_denylist(&admin, &treasurer);
+        
+        contract.withdraw_rent_reserve(&admin, &treasurer, &15);
+        assert_eq!(contract.rent_reserve(), 0);
+        assert_eq!(contract.balance(&treasurer), 15);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, withdrawn): (u64, i128) = data.into_val(&env);
+        assert_eq!(withdrawn, 15);
+    }
+
+    #[test]
+    fn test_ttl_maintenance_fee_events_and_persistent_entries() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        let collector = Address::generate(&env);
+        contract.mint(&minter, &holder, &10_000);
+        contract.set_fee_config(&admin, &100, &collector, &0);
+        contract.set_ttl_maintenance(&admin, &Some(TtlMaintenance { every_ops: 1, extend_to: 100_000, fee_share_bps: 5_000 }));
+        
+        // The reserve cut and the collector's share are reported separately, stamped with
+        // the transfer's own operation sequence
+        contract.transfer(&holder, &payee, &1000);
+        let mut shares = Vec::new(&env);
+        for (_, topics, data) in env.events().all().iter() {
+            let name: Symbol = topics.get_unchecked(0).into_val(&env);
+            if name == Symbol::new(&env, "rent_reserve") || name == Symbol::new(&env, "fee") {
+                let (op_seq, amount): (u64, i128) = data.into_val(&env);
+                assert_eq!(op_seq, contract.current_op_seq());
+                shares.push_back(amount);
+            }
+        }
+        assert_eq!(shares, Vec::from_array(&env, [5_i128, 5]));
+        
+        // Shared persistent entries are extended along with the instance
+        let mint_log_ttl = env.as_contract(&contract.address, || {
+            env.storage().persistent().get_ttl(&storage::RecordKey::MintLogSlot(0))
+        });
+        assert_eq!(mint_log_ttl, 100_000);
+    }
+
+    #[test]
+    fn test_revoke_last_critical_role_requires_force() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, _minter) = setup(&env);
+        let pauser_role = Symbol::new(&env, "pauser");
+        let backup = Address::generate(&env);
+        
+        // The last pauser cannot be revoked without force
+        let result = contract.try_revoke_role(&admin, &pauser, &pauser_role, &false);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert!(contract.has_role_pauser(&pauser));
+        
+        // With another pauser in place it can
+        contract.grant_role(&admin, &backup, &pauser_role);
+        contract.revoke_role(&admin, &pauser, &pauser_role, &false);
+        assert!(!contract.has_role_pauser(&pauser));
+        
+        // Force allows revoking the final member
+        contract.revoke_role(&admin, &backup, &pauser_role, &true);
+        assert!(!contract.has_role_pauser(&backup));
+        
+        // Revoking a role the account does not hold is rejected
+        let result = contract.try_revoke_role(&admin, &backup, &pauser_role, &true);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_allowances_disabled_keeps_direct_transfers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        contract.approve(&owner, &spender, &500, &1000);
+        
+        assert!(contract.allowances_enabled());
+        contract.set_allowances_enabled(&admin, &false);
+        assert!(!contract.allowances_enabled());
+        
+        // Every delegated spending path is disabled, even with an existing allowance
+        let result = contract.try_approve(&owner, &spender, &100, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        let result = contract.try_approve_for(&owner, &spender, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        let result = contract.try_transfer_from(&spender, &owner, &recipient, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        let result = contract.try_burn_from(&spender, &owner, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        
+        // Direct transfers and burns work normally
+        contract.transfer(&owner, &recipient, &100);
+        contract.burn(&owner, &100);
+        assert_eq!(contract.balance(&owner), 800);
+        
+        // Re-enabling restores the existing allowance
+        contract.set_allowances_enabled(&admin, &true);
+        contract.transfer_from(&spender, &owner, &recipient, &100);
+        assert_eq!(contract.balance(&recipient), 200);
+    }
+
+    #[test]
+    fn test_emergency_action_event_alongside_specific_events() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, minter) = setup(&env);
+        let replacement = Address::generate(&env);
+        env.ledger().with_mut(|li| li.sequence_number = 42);
+        let emitted = |name: &str| env.events().all().iter().filter(|(_, topics, _)| {
+            let topic: Symbol = topics.get_unchecked(0).into_val(&env);
+            topic == Symbol::new(&env, name)
+        }).count();
+        let last_action = || {
+            let (_, _, data) = env.events().all().last().unwrap();
+            let (_, action): (u64, EmergencyAction) = data.into_val(&env);
+            action
+        };
+        
+        contract.emergency_rotate_minter(&admin, &minter, &replacement);
+        assert_eq!((emitted("minter_emergency_rotated"), emitted("emergency_action")), (1, 1));
+        assert_eq!(last_action(), EmergencyAction {
+            kind: Symbol::new(&env, "minter_rotation"),
+            by: admin.clone(),
+            at: 42,
+        });
+        
+        contract.pause_with_allowance_freeze(&pauser);
+        assert_eq!((emitted("pause"), emitted("emergency_action")), (1, 1));
+        assert_eq!(last_action(), EmergencyAction {
+            kind: Symbol::new(&env, "emergency_freeze"),
+            by: pauser.clone(),
+            at: 42,
+        });
+        
+        // Routine pauses are not emergencies
+        contract.unpause_restore_allowances(&pauser);
+        contract.pause(&pauser);
+        assert_eq!((emitted("pause"), emitted("emergency_action")), (1, 0));
+    }
+
+    #[test]
+    fn test_can_transact() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        
+        // A clean pair may transact, with no balance needed
+        assert!(contract.can_transact(&alice, &bob));
+        
+        // Counterparty restrictions are directional
+        contract.add_allowed_counterparty(&admin, &alice, &carol);
+        assert!(!contract.can_transact(&alice, &bob));
+        assert!(contract.can_transact(&alice, &carol));
+        assert!(contract.can_transact(&bob, &alice));
+        
+        // A denylisted recipient or frozen sender blocks the pair
+        contract.add_to_denylist(&admin, &carol);
+        assert!(!contract.can_transact(&alice, &carol));
+        contract.freeze_account(&admin, &bob);
+        assert!(!contract.can_transact(&bob, &alice));
+        assert!(!contract.can_transact(&carol, &bob));
+        
+        // Under allowlist mode only allowlisted recipients qualify, matching transfers
+        let dave = Address::generate(&env);
+        contract.set_allowlist_mode(&admin, &true);
+        assert!(!contract.can_transact(&dave, &alice));
+        contract.allowlist_add(&admin, &alice);
+        assert!(contract.can_transact(&dave, &alice));
+    }
+
+    #[test]
+    fn test_zero_amount_policy() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        assert!(utils::allows_zero_amount("approve"));
+        assert!(!utils::allows_zero_amount("transfer"));
+        
+        // A zero approval clears an allowance
+        contract.approve(&owner, &spender, &500, &1000);
+        contract.approve(&owner, &spender, &0, &0);
+        assert_eq!(contract.allowance(&owner, &spender), 0);
+        assert_eq!(contract.get_allowances(&owner).len(), 0);
+        
+        // Negative approvals and zero transfers and mints are still rejected
+        let result = contract.try_approve(&owner, &spender, &-1, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+        let result = contract.try_transfer(&owner, &spender, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+        let result = contract.try_mint(&minter, &owner, &0);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_airdrop() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let mut recipients = Vec::new(&env);
+        for _ in 0..50 {
+            recipients.push_back(Address::generate(&env));
+        }
+        
+        contract.airdrop(&minter, &250, &recipients);
+        for account in recipients.iter() {
+            assert_eq!(contract.balance(&account), 250);
+        }
+        let stats = contract.get_stats(&admin);
+        assert_eq!((stats.total_supply, stats.total_minted, stats.active_holders), (12_500, 12_500, 50));
+        
+        // The aggregate must fit the supply headroom, or nothing is minted
+        contract.set_max_supply(&admin, &20_000);
+        let result = contract.try_airdrop(&minter, &200, &recipients);
+        assert_eq!(result, Err(Ok(StablecoinError::ExceedsMaxSupply)));
+        assert_eq!(contract.total_supply(), 12_500);
+        
+        // Only minters may airdrop
+        let result = contract.try_airdrop(&admin, &1, &recipients);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_supply_freeze_blocks_mint_and_burn() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let payee = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        
+        assert!(!contract.is_supply_frozen());
+        contract.freeze_supply(&admin);
+        assert!(contract.is_supply_frozen());
+        let result = contract.try_freeze_supply(&admin);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Supply cannot change in either direction
+        let result = contract.try_mint(&minter, &holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::SupplyFrozen)));
+        let result = contract.try_airdrop(&minter, &100, &vec![&env, payee.clone()]);
+        assert_eq!(result, Err(Ok(StablecoinError::SupplyFrozen)));
+        let result = contract.try_burn(&holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::SupplyFrozen)));
+        
+        // Transfers continue, and the contract is not paused
+        contract.transfer(&holder, &payee, &300);
+        assert!(!contract.is_paused());
+        assert_eq!(contract.total_supply(), 1000);
+        
+        contract.thaw_supply(&admin);
+        contract.burn(&holder, &100);
+        contract.mint(&minter, &holder, &100);
+        assert_eq!(contract.total_supply(), 1000);
+    }
+
+    #[test]
+    fn test_allowlist_mode_applies_to_batch_recipients() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let sender = Address::generate(&env);
+        let registered = Address::generate(&env);
+        let unregistered = Address::generate(&env);
+        contract.mint(&minter, &sender, &1000);
+        
+        contract.set_allowlist_mode(&admin, &true);
+        contract.allowlist_add(&admin, &sender);
+        contract.allowlist_add(&admin, &registered);
+        assert!(contract.is_allowlisted(&registered));
+        assert!(!contract.is_allowlisted(&unregistered));
+        
+        // One unregistered recipient rejects each batch wholesale
+        let entries = vec![&env, (registered.clone(), 100), (unregistered.clone(), 100)];
+        let result = contract.try_batch_mint(&minter, &entries);
+        assert_eq!(result, Err(Ok(StablecoinError::RecipientNotAllowlisted)));
+        let result = contract.try_batch_transfer(&sender, &entries);
+        assert_eq!(result, Err(Ok(StablecoinError::RecipientNotAllowlisted)));
+        let recipients = vec![&env, registered.clone(), unregistered.clone()];
+        let result = contract.try_airdrop(&minter, &100, &recipients);
+        assert_eq!(result, Err(Ok(StablecoinError::RecipientNotAllowlisted)));
+        assert_eq!(contract.balance(&registered), 0);
+        assert_eq!(contract.total_supply(), 1000);
+        
+        // Once every recipient is registered the batches go through
+        contract.allowlist_add(&admin, &unregistered);
+        contract.batch_mint(&minter, &entries);
+        contract.batch_transfer(&sender, &entries);
+        contract.airdrop(&minter, &100, &recipients);
+        assert_eq!(contract.balance(&registered), 300);
+        assert_eq!(contract.balance(&unregistered), 300);
+        
+        // Delisting applies to direct transfers too
+        contract.allowlist_remove(&admin, &unregistered);
+        let result = contract.try_transfer(&sender, &unregistered, &1);
+        assert_eq!(result, Err(Ok(StablecoinError::RecipientNotAllowlisted)));
+    }
+
+    #[test]
+    fn test_token_uri() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        assert_eq!(contract.token_uri(), String::from_str(&env, ""));
+        
+        let uri = String::from_str(&env, "https://pacto.lat/crcx/metadata.json");
+        contract.set_token_uri(&admin, &uri);
+        assert_eq!(contract.token_uri(), uri);
+        
+        // Empty and over-length URIs are rejected, keeping the previous value
+        let result = contract.try_set_token_uri(&admin, &String::from_str(&env, ""));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        let too_long = String::from_bytes(&env, &[b'a'; 257]);
+        let result = contract.try_set_token_uri(&admin, &too_long);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert_eq!(contract.token_uri(), uri);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #2)")]
+    fn test_sep41_transfer_panics_with_error_code() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        contract.mint(&minter, &alice, &100);
+        
+        // A SEP-41 caller sees the failure as a panic carrying the contract error code
+        token::Client::new(&env, &contract.address).transfer(&alice, &bob, &101);
+    }
+
+    #[test]
+    fn test_extended_transfer_returns_typed_error() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        contract.mint(&minter, &alice, &100);
+        
+        // The extended client surfaces the same failure as a typed error
+        let result = contract.try_transfer(&alice, &bob, &101);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        // The generic client's try_ form sees the same contract error code
+        let result = token::Client::new(&env, &contract.address).try_transfer(&alice, &bob, &101);
+        assert_eq!(
+            result.err().unwrap().unwrap(),
+            soroban_sdk::Error::from_contract_error(StablecoinError::InsufficientBalance as u32)
+        );
+    }
+
+    #[test]
+    fn test_max_allowance_ttl() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        
+        // Disabled by default
+        assert_eq!(contract.get_max_allowance_ttl(), 0);
+        contract.approve(&owner, &spender, &100, &1_000_000);
+        
+        // The horizon cannot undercut the default lifetime used by approve_for
+        let default_ttl = contract.get_default_allowance_ttl();
+        let result = contract.try_set_max_allowance_ttl(&admin, &(default_ttl - 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        contract.set_max_allowance_ttl(&admin, &default_ttl);
+        
+        // Over-horizon expirations are rejected, in-horizon ones accepted
+        let result = contract.try_approve(&owner, &spender, &100, &(100 + default_ttl + 1));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        contract.approve(&owner, &spender, &200, &(100 + default_ttl));
+        assert_eq!(contract.allowance(&owner, &spender), 200);
+        contract.approve_for(&owner, &spender, &300);
+        assert_eq!(contract.allowance(&owner, &spender), 300);
+        
+        // Clearing is unaffected
+        contract.approve(&owner, &spender, &0, &1_000_000);
+        assert_eq!(contract.allowance(&owner, &spender), 0);
+    }
+
+    #[test]
+    fn test_pull_requires_opt_in_and_spender_role() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let protocol = Address::generate(&env);
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        let merchant = Address::generate(&env);
+        contract.mint(&minter, &user, &1000);
+        contract.mint(&minter, &other, &1000);
+        
+        // Consent alone is not enough without the spender role
+        contract.opt_in_spender(&user, &protocol);
+        assert!(contract.is_opted_in_spender(&user, &protocol));
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, opted_in): (u64, bool) = data.into_val(&env);
+        assert!(opted_in);
+        let result = contract.try_pull(&protocol, &user, &merchant, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // With the role, only opted-in accounts can be pulled from
+        contract.grant_role(&admin, &protocol, &Symbol::new(&env, "spender"));
+        let result = contract.try_pull(&protocol, &other, &merchant, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        contract.pull(&protocol, &user, &merchant, &100);
+        let standard_transfer = (
+            contract.address.clone(),
+            (Symbol::new(&env, "transfer"), user.clone(), merchant.clone()).into_val(&env),
+            100_i128.into_val(&env),
+        );
+        assert_eq!(
+            env.events().all().iter().filter(|event| vec![&env, event.clone()] == vec![&env, standard_transfer.clone()]).count(),
+            1
+        );
+        assert_eq!(contract.balance(&user), 900);
+        assert_eq!(contract.balance(&merchant), 100);
+        assert_eq!(contract.allowance(&user, &protocol), 0);
+        
+        // A flagged spender cannot pull even with consent and the role
+        contract.add_to_denylist(&admin, &protocol);
+        let result = contract.try_pull(&protocol, &user, &merchant, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        contract.remove_from_denylist(&admin, &protocol);
+        
+        // Opting out withdraws consent
+        contract.opt_out_spender(&user, &protocol);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, opted_in): (u64, bool) = data.into_val(&env);
+        assert!(!opted_in);
+        let result = contract.try_pull(&protocol, &user, &merchant, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_restrict_during_recovery() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let guardian = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let user = Address::generate(&env);
+        
+        contract.set_recovery_config(&admin, &recovery, &MIN_RECOVERY_DELAY_LEDGERS);
+        contract.add_guardian(&admin, &guardian);
+        
+        // Without the flag a pending recovery does not block minting
+        contract.initiate_recovery(&guardian);
+        contract.mint(&minter, &user, &100);
+        
+        // With the flag, minting and role changes are rejected while recovery is pending
+        contract.set_restrict_during_recovery(&admin, &true);
+        assert!(contract.restrict_during_recovery());
+        let result = contract.try_mint(&minter, &user, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::RecoveryPending)));
+        let result = contract.try_grant_role(&admin, &user, &Symbol::new(&env, "minter"));
+        assert_eq!(result, Err(Ok(StablecoinError::RecoveryPending)));
+        
+        // Cancelling the recovery lifts the restriction
+        contract.cancel_recovery(&admin);
+        contract.mint(&minter, &user, &100);
+        assert_eq!(contract.balance(&user), 200);
+        
+        // A claimed recovery lifts it too
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+        let claimable_at = contract.initiate_recovery(&guardian);
+        let result = contract.try_mint(&minter, &user, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::RecoveryPending)));
+        env.ledger().with_mut(|li| li.sequence_number = claimable_at);
+        contract.claim_admin(&recovery);
+        contract.mint(&minter, &user, &100);
+        assert_eq!(contract.balance(&user), 300);
+    }
+
+    #[test]
+    fn test_transfer_volume_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        contract.mint(&minter, &alice, &1000);
+        
+        let start = VOLUME_WINDOW_LEDGERS * 3;
+        env.ledger().with_mut(|li| li.sequence_number = start + 1);
+        assert_eq!(contract.transfer_volume_window(), (0, start));
+        
+        // Transfers within a window accumulate; mints do not count
+        contract.transfer(&alice, &bob, &100);
+        env.ledger().with_mut(|li| li.sequence_number = start + VOLUME_WINDOW_LEDGERS - 1);
+        contract.transfer(&bob, &alice, &40);
+        contract.mint(&minter, &alice, &500);
+        assert_eq!(contract.transfer_volume_window(), (140, start));
+        
+        // The volume resets at the window boundary
+        let next = start + VOLUME_WINDOW_LEDGERS;
+        env.ledger().with_mut(|li| li.sequence_number = next);
+        assert_eq!(contract.transfer_volume_window(), (0, next));
+        contract.transfer(&alice, &bob, &25);
+        assert_eq!(contract.transfer_volume_window(), (25, next));
+    }
+
+    #[test]
+    fn test_batch_grant_roles() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let frozen = Address::generate(&env);
+        let minter_role = Symbol::new(&env, "minter");
+        let pauser_role = Symbol::new(&env, "pauser");
+        
+        // Unknown roles, duplicates and flagged grantees reject the whole batch
+        let result = contract.try_batch_grant_roles(&admin, &vec![
+            &env,
+            (first.clone(), minter_role.clone()),
+            (second.clone(), Symbol::new(&env, "operator")),
+        ]);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidRole)));
+        let result = contract.try_batch_grant_roles(&admin, &vec![
+            &env,
+            (first.clone(), minter_role.clone()),
+            (first.clone(), minter_role.clone()),
+        ]);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        contract.freeze_account(&admin, &frozen);
+        let result = contract.try_batch_grant_roles(&admin, &vec![
+            &env,
+            (first.clone(), minter_role.clone()),
+            (frozen.clone(), pauser_role.clone()),
+        ]);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert!(!contract.has_role_minter(&first));
+        
+        // Minter and pauser for two operators in one call
+        contract.batch_grant_roles(&admin, &vec![
+            &env,
+            (first.clone(), minter_role.clone()),
+            (first.clone(), pauser_role.clone()),
+            (second.clone(), minter_role.clone()),
+            (second.clone(), pauser_role.clone()),
+        ]);
+        assert!(contract.has_role_minter(&first));
+        assert!(contract.has_role_pauser(&first));
+        assert!(contract.has_role_minter(&second));
+        assert!(contract.has_role_pauser(&second));
+    }
+
+    #[test]
+    fn test_operation_bounds() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let mint = Symbol::new(&env, "mint");
+        let transfer = Symbol::new(&env, "transfer");
+        let burn = Symbol::new(&env, "burn");
+        contract.mint(&minter, &user, &1000);
+        
+        // A regular user cannot mint; transfers and burns are bounded by their balance
+        assert_eq!(contract.operation_bounds(&mint, &user), (MIN_AMOUNT, 0));
+        assert_eq!(contract.operation_bounds(&transfer, &user), (MIN_AMOUNT, 1000));
+        assert_eq!(contract.operation_bounds(&burn, &user), (MIN_AMOUNT, 1000));
+        
+        // A minter is bounded by the per-operation limit, then by its allowance
+        assert_eq!(contract.operation_bounds(&mint, &minter), (MIN_AMOUNT, MAX_SINGLE_OPERATION));
+        contract.set_minter_allowance(&admin, &minter, &Some(5000));
+        assert_eq!(contract.operation_bounds(&mint, &minter), (MIN_AMOUNT, 5000));
+        assert_eq!(contract.operation_bounds(&transfer, &minter), (MIN_AMOUNT, 0));
+        
+        // Per-ledger caps (this ledger has already minted 1000) and the precision step
+        // tighten the bounds
+        contract.set_global_flow_caps(&admin, &3000, &250);
+        contract.set_precision_step(&admin, &100);
+        assert_eq!(contract.operation_bounds(&mint, &minter), (100, 2000));
+        assert_eq!(contract.operation_bounds(&burn, &user), (100, 200));
+        
+        // Unknown operations are rejected
+        let result = contract.try_operation_bounds(&Symbol::new(&env, "approve"), &user);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+    }
+
+    #[test]
+    fn test_migration_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        let rescue = Address::generate(&env);
+        contract.mint(&minter, &user, &1000);
+        
+        // Without migration mode a pause halts every transfer
+        contract.pause(&pauser);
+        let result = contract.try_transfer(&user, &rescue, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        
+        // In migration mode only transfers to the rescue address go through
+        contract.migration_mode(&admin, &rescue);
+        assert_eq!(contract.migration_rescue(), Some(rescue.clone()));
+        contract.transfer(&user, &rescue, &400);
+        assert_eq!(contract.balance(&rescue), 400);
+        let result = contract.try_transfer(&user, &other, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        
+        // The other transfer methods honor the rescue address the same way
+        contract.transfer_with_memo(&user, &rescue, &100, &String::from_str(&env, "REF-1"));
+        contract.batch_transfer(&user, &vec![&env, (rescue.clone(), 50), (rescue.clone(), 50)]);
+        contract.set_partial_transfers(&admin, &true);
+        contract.transfer_partial(&user, &rescue, &100);
+        assert_eq!(contract.balance(&rescue), 700);
+        let result = contract.try_transfer_with_memo(&user, &other, &100, &String::from_str(&env, "REF-2"));
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        let result = contract.try_transfer_partial(&user, &other, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        let result = contract.try_transfer_and_call(&user, &other, &100, &Bytes::new(&env));
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        let result = contract.try_batch_transfer(&user, &vec![&env, (rescue.clone(), 50), (other.clone(), 50)]);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+        assert_eq!(contract.balance(&rescue), 700);
+        
+        // Ending migration mode halts those too
+        contract.end_migration_mode(&admin);
+        let result = contract.try_transfer(&user, &rescue, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+    }
+
+    #[test]
+    fn test_replayed_mint_suppresses_event() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let key = BytesN::from_array(&env, &[7u8; 32]);
+        let count = |topic: &str| env.events().all().iter().filter(|(_, topics, _)| {
+            let name: Symbol = topics.get_unchecked(0).into_val(&env);
+            name == Symbol::new(&env, topic)
+        }).count();
+        
+        contract.mint_idempotent(&minter, &user, &1000, &key);
+        assert_eq!(count("mint"), 1);
+        assert_eq!(count("dup_suppressed"), 0);
+        
+        // The replay publishes only the suppression marker
+        contract.mint_idempotent(&minter, &user, &1000, &key);
+        assert_eq!(count("mint"), 0);
+        assert_eq!(count("dup_suppressed"), 1);
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, event): (u64, DuplicateSuppressed) = data.into_val(&env);
+        assert_eq!(event, DuplicateSuppressed { key });
+    }
+
+    #[test]
+    fn test_admin_approve() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let managed = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        contract.mint(&minter, &managed, &1000);
+        let expiration = contract.recommended_allowance_expiration();
+        
+        // Off by default
+        assert!(!contract.managed_approvals_enabled());
+        let result = contract.try_admin_approve(&admin, &managed, &spender, &300, &expiration);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        
+        contract.set_managed_approvals(&admin, &true);
+        let result = contract.try_admin_approve(&outsider, &managed, &spender, &300, &expiration);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // Only registered managed accounts are open to it, even with the flag on
+        let result = contract.try_admin_approve(&admin, &managed, &spender, &300, &expiration);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        let result = contract.try_set_managed_account(&outsider, &managed, &true);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        contract.set_managed_account(&managed, &managed, &true);
+        assert!(contract.is_managed_account(&managed));
+        
+        // The admin sets the allowance without the owner's signature
+        contract.admin_approve(&admin, &managed, &spender, &300, &expiration);
+        let auths = env.auths();
+        assert!(auths.iter().any(|(address, _)| *address == admin));
+        assert!(!auths.iter().any(|(address, _)| *address == managed));
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, event): (u64, AdminApproval) = data.into_val(&env);
+        assert_eq!(event, AdminApproval {
+            by: admin.clone(),
+            from: managed.clone(),
+            spender: spender.clone(),
+            amount: 300,
+            expiration_ledger: expiration,
+        });
+        assert_eq!(contract.allowance(&managed, &spender), 300);
+        
+        contract.transfer_from(&spender, &managed, &outsider, &300);
+        assert_eq!(contract.balance(&outsider), 300);
+    }
+
+    #[test]
+    fn test_tiered_fees() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let collector = Address::generate(&env);
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        contract.mint(&minter, &sender, &10_000_000);
+        
+        // 1% flat, 0.5% from 10,000 and 0.1% from 1,000,000
+        contract.set_fee_config(&admin, &100, &collector, &0);
+        contract.set_fee_tiers(&admin, &vec![&env, (10_000, 50), (1_000_000, 10)]);
+        
+        // Each band pays its own rate; a threshold belongs to the band it opens
+        assert_eq!(contract.quote_transfer_fee(&9_999), (9_900, 99));
+        assert_eq!(contract.quote_transfer_fee(&10_000), (9_950, 50));
+        assert_eq!(contract.quote_transfer_fee(&999_999), (995_000, 4_999));
+        assert_eq!(contract.quote_transfer_fee(&1_000_000), (999_000, 1_000));
+        
+        // Transfers apply the tier
+        contract.transfer(&sender, &recipient, &20_000);
+        assert_eq!(contract.balance(&recipient), 19_900);
+        assert_eq!(contract.balance(&collector), 100);
+        
+        // Unordered thresholds and excessive rates are rejected
+        let result = contract.try_set_fee_tiers(&admin, &vec![&env, (1_000_000, 10), (10_000, 50)]);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        let result = contract.try_set_fee_tiers(&admin, &vec![&env, (10_000, 1_001)]);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // An empty schedule returns to the flat rate
+        contract.set_fee_tiers(&admin, &Vec::new(&env));
+        assert_eq!(contract.quote_transfer_fee(&1_000_000), (990_000, 10_000));
+    }
+
+    #[test]
+    fn test_whoami() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let operator = Address::generate(&env);
+        let user = Address::generate(&env);
+        
+        assert_eq!(contract.whoami(&user), (user.clone(), Vec::new(&env)));
+        assert_eq!(contract.whoami(&admin), (admin.clone(), vec![&env, Symbol::new(&env, "admin")]));
+        assert_eq!(contract.whoami(&minter), (minter.clone(), vec![&env, Symbol::new(&env, "minter")]));
+        
+        // Granted roles are reported in a fixed order
+        contract.grant_role(&admin, &operator, &Symbol::new(&env, "burner"));
+        contract.grant_role(&admin, &operator, &Symbol::new(&env, "pauser"));
+        assert_eq!(
+            contract.whoami(&operator),
+            (operator.clone(), vec![&env, Symbol::new(&env, "pauser"), Symbol::new(&env, "burner")])
+        );
+        assert_eq!(env.auths()[0].0, operator);
+    }
+
+    #[test]
+    fn test_reindex_allowances() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let owner = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let stale = Address::generate(&env);
+        
+        // Allowances written before the spender index existed
+        env.as_contract(&contract.address, || {
+            stellar_fungible::Base::set_allowance(&env, &owner, &first, 300, 1000);
+            stellar_fungible::Base::set_allowance(&env, &owner, &second, 200, 1000);
+        });
+        assert_eq!(contract.allowance(&owner, &first), 300);
+        assert_eq!(contract.get_allowances(&owner), Vec::new(&env));
+        assert_eq!(contract.total_approved(&owner), 0);
+        
+        // Reindexing reconstructs the enumeration; pairs without an allowance are skipped
+        let entries = vec![&env, (owner.clone(), first.clone()), (owner.clone(), second.clone()), (owner.clone(), stale.clone())];
+        assert_eq!(contract.reindex_allowances(&admin, &entries), 2);
+        assert_eq!(contract.get_allowances(&owner), vec![&env, (first.clone(), 300), (second.clone(), 200)]);
+        assert_eq!(contract.total_approved(&owner), 500);
+        assert_eq!(contract.get_stats(&admin).total_outstanding_allowances, 500);
+        
+        // Running it again changes nothing
+        assert_eq!(contract.reindex_allowances(&admin, &entries), 2);
+        assert_eq!(contract.get_allowances(&owner).len(), 2);
+        assert_eq!(contract.get_stats(&admin).total_outstanding_allowances, 500);
+    }
+
+    #[test]
+    fn test_single_pause_action_per_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, _minter) = setup(&env);
+        let second_pauser = Address::generate(&env);
+        contract.grant_role(&admin, &second_pauser, &Symbol::new(&env, "pauser"));
+        
+        // Without the rule, several pause actions can land in one ledger
+        env.ledger().with_mut(|li| li.sequence_number = 10);
+        contract.pause(&pauser);
+        contract.unpause(&second_pauser);
+        
+        // With it, a second action in the same ledger is rejected, even by another pauser
+        contract.set_single_pause_action(&admin, &true);
+        env.ledger().with_mut(|li| li.sequence_number = 11);
+        contract.pause(&pauser);
+        let result = contract.try_unpause(&second_pauser);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        let result = contract.try_pause_method(&pauser, &Symbol::new(&env, "transfer"));
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        assert!(contract.is_paused());
+        
+        // In the next ledger the action goes through
+        env.ledger().with_mut(|li| li.sequence_number = 12);
+        contract.unpause(&second_pauser);
+        assert!(!contract.is_paused());
+    }
+
+    #[test]
+    fn test_total_locked_and_escrowed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        let other = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+        contract.mint(&minter, &holder, &10_000);
+        contract.mint(&minter, &other, &10_000);
+        assert_eq!(contract.total_locked(), 0);
+        assert_eq!(contract.total_escrowed(), 0);
+        
+        // Locks add up across accounts and drop out once released and consolidated
+        contract.lock_balance(&admin, &holder, &1000, &100);
+        contract.lock_balance(&admin, &holder, &500, &200);
+        contract.lock_balance(&admin, &other, &250, &100);
+        assert_eq!(contract.total_locked(), 1750);
+        env.ledger().with_mut(|li| li.sequence_number = 150);
+        assert_eq!(contract.consolidate_locks(&holder), 1);
+        assert_eq!(contract.total_locked(), 750);
+        assert_eq!(contract.consolidate_locks(&other), 1);
+        assert_eq!(contract.total_locked(), 500);
+        
+        // Escrows add up and drop out on release or refund
+        let first = contract.escrow_deposit(&holder, &beneficiary, &300, &200);
+        let second = contract.escrow_deposit(&other, &beneficiary, &700, &300);
+        assert_eq!(contract.total_escrowed(), 1000);
+        contract.escrow_refund(&second);
+        assert_eq!(contract.total_escrowed(), 300);
+        env.ledger().with_mut(|li| li.sequence_number = 200);
+        contract.escrow_release(&first);
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[test]
+    fn test_supports_muxed() {
+        let env = Env::default();
+        let (contract, _admin, _pauser, _upgrader, _minter) = setup(&env);
+        assert!(!contract.supports_muxed());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_muxed_recipient_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let sender = Address::generate(&env);
+        contract.mint(&minter, &sender, &1000);
+        
+        // A muxed account with memo id 1 cannot be turned into an `Address` argument
+        let muxed = Address::from_str(&env, "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVAAAAAAAAAAAAAJLK");
+        contract.transfer(&sender, &muxed, &100);
+    }
+
+    #[test]
+    fn test_post_upgrade_hook_runs_migration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, upgrader, _minter) = setup(&env);
+        let hook = env.register(MockUpgradeHook, ());
+        let hook_client = MockUpgradeHookClient::new(&env, &hook);
+        
+        // Only the admin may configure the hook
+        let result = contract.try_set_post_upgrade_hook(&upgrader, &Some(hook.clone()));
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        assert_eq!(contract.get_post_upgrade_hook(), None);
+        contract.set_post_upgrade_hook(&admin, &Some(hook.clone()));
+        assert_eq!(contract.get_post_upgrade_hook(), Some(hook.clone()));
+        
+        // No wasm can be uploaded here, so run the step `upgrade` performs after swapping code
+        let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::run_post_upgrade_hook(&env, &wasm_hash), Ok(()));
+        });
+        assert_eq!(hook_client.migrations(), Vec::from_array(&env, [wasm_hash]));
+        
+        // Cleared hooks are not called
+        contract.set_post_upgrade_hook(&admin, &None);
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::run_post_upgrade_hook(&env, &BytesN::from_array(&env, &[8; 32])), Ok(()));
+        });
+        assert_eq!(hook_client.migrations().len(), 1);
+    }
+
+    #[test]
+    fn test_failing_post_upgrade_hook_reverts_upgrade() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, _minter) = setup(&env);
+        let hook = env.register(FailingUpgradeHook, ());
+        contract.set_post_upgrade_hook(&admin, &Some(hook));
+        
+        // The failure surfaces as HookFailed, which `upgrade` returns to revert the code swap
+        let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+        env.as_contract(&contract.address, || {
+            assert_eq!(
+                utils::run_post_upgrade_hook(&env, &wasm_hash),
+                Err(StablecoinError::HookFailed)
+            );
+        });
+    }
+
+    #[test]
+    fn test_burn_from_rejects_self_spender() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let holder = Address::generate(&env);
+        contract.mint(&minter, &holder, &1000);
+        
+        let result = contract.try_burn_from(&holder, &holder, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::SelfTransfer)));
+        assert_eq!(contract.balance(&holder), 1000);
+        
+        // The plain burn path still works
+        contract.burn(&holder, &100);
+        assert_eq!(contract.balance(&holder), 900);
+    }
+
+    #[test]
+    fn test_circulating_supply_excludes_treasury() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let treasury = Address::generate(&env);
+        let user = Address::generate(&env);
+        contract.mint(&minter, &treasury, &5000);
+        contract.mint(&minter, &user, &1000);
+        assert_eq!(contract.circulating_supply(), 6000);
+        
+        // Only the admin may exclude accounts
+        let result = contract.try_set_supply_excluded(&minter, &treasury, &true);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        // Excluding the treasury removes its balance from circulation
+        contract.set_supply_excluded(&admin, &treasury, &true);
+        assert!(contract.is_supply_excluded(&treasury));
+        assert_eq!(contract.circulating_supply(), 1000);
+        
+        // Flows in and out of the treasury are tracked incrementally
+        contract.transfer(&treasury, &user, &2000);
+        assert_eq!(contract.circulating_supply(), 3000);
+        contract.transfer(&user, &treasury, &500);
+        assert_eq!(contract.circulating_supply(), 2500);
+        contract.mint(&minter, &treasury, &1000);
+        assert_eq!(contract.circulating_supply(), 2500);
+        contract.burn(&treasury, &500);
+        assert_eq!(contract.circulating_supply(), 2500);
+        assert_eq!(contract.total_supply(), contract.circulating_supply() + contract.balance(&treasury));
+        
+        // Including it again restores the full supply
+        contract.set_supply_excluded(&admin, &treasury, &false);
+        assert_eq!(contract.circulating_supply(), contract.total_supply());
+    }
+
+    #[test]
+    fn test_recover_account() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let old = Address::generate(&env);
+        let new = Address::generate(&env);
+        let spender = Address::generate(&env);
+        contract.mint(&minter, &old, &1000);
+        contract.lock_balance(&admin, &old, &300, &500);
+        contract.approve(&old, &spender, &200, &1000);
+        
+        // Only the admin or compliance may recover accounts
+        let result = contract.try_recover_account(&minter, &old, &new);
+        assert_eq!(result, Err(Ok(StablecoinError::Unauthorized)));
+        
+        assert_eq!(contract.recover_account(&admin, &old, &new), 1000);
+        
+        // Balance, locks and allowances move across
+        assert_eq!(contract.balance(&old), 0);
+        assert_eq!(contract.balance(&new), 1000);
+        assert_eq!(contract.get_locks(&old).len(), 0);
+        assert_eq!(contract.get_locks(&new), Vec::from_array(&env, [BalanceLock { amount: 300, release_ledger: 500 }]));
+        assert_eq!(contract.locked_balance(&new), 300);
+        assert_eq!(contract.allowance(&old, &spender), 0);
+        assert_eq!(contract.allowance(&new, &spender), 200);
+        
+        // The old address is denylisted
+        assert!(contract.is_denylisted(&old));
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (_, event): (u64, AccountRecovered) = data.into_val(&env);
+        assert_eq!(event, AccountRecovered { old: old.clone(), new: new.clone() });
+    }
+
+    #[test]
+    fn test_recover_account_requires_unused_target() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, pauser, _upgrader, minter) = setup(&env);
+        let old = Address::generate(&env);
+        let new = Address::generate(&env);
+        contract.mint(&minter, &old, &1000);
+        contract.mint(&minter, &new, &1);
+        
+        // Balances are never merged
+        let result = contract.try_recover_account(&admin, &old, &new);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Flagged targets are refused
+        let flagged = Address::generate(&env);
+        contract.add_to_denylist(&admin, &flagged);
+        let result = contract.try_recover_account(&admin, &old, &flagged);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        let result = contract.try_recover_account(&admin, &old, &old);
+        assert_eq!(result, Err(Ok(StablecoinError::SelfTransfer)));
+        assert_eq!(contract.balance(&old), 1000);
+        assert!(!contract.is_denylisted(&old));
+        
+        // Under allowlist mode the target must be allowlisted
+        contract.set_allowlist_mode(&admin, &true);
+        let target = Address::generate(&env);
+        let result = contract.try_recover_account(&admin, &old, &target);
+        assert_eq!(result, Err(Ok(StablecoinError::RecipientNotAllowlisted)));
+        contract.set_allowlist_mode(&admin, &false);
+        
+        // Recovery can be paused on its own
+        contract.pause_method(&pauser, &Symbol::new(&env, "recover_account"));
+        let result = contract.try_recover_account(&admin, &old, &target);
+        assert_eq!(result, Err(Ok(StablecoinError::Paused)));
+    }
+
+    #[test]
+    fn test_recover_account_skips_refused_allowances() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let old = Address::generate(&env);
+        let new = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let sanctioned = Address::generate(&env);
+        contract.mint(&minter, &old, &1000);
+        contract.approve(&old, &spender, &200, &1000);
+        contract.approve(&old, &sanctioned, &300, &1000);
+        contract.add_to_denylist(&admin, &sanctioned);
+        
+        // The recovery goes through; only the allowance `new` could still grant moves
+        assert_eq!(contract.recover_account(&admin, &old, &new), 1000);
+        assert_eq!(contract.allowance(&new, &spender), 200);
+        assert_eq!(contract.allowance(&new, &sanctioned), 0);
+        assert_eq!(contract.get_allowances(&new).len(), 1);
+    }
+
+    #[test]
+    fn test_min_reserve_ratio() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        
+        // The admin switch is behind the backing feature flag
+        let result = contract.try_set_min_reserve_ratio(&admin, &5_000);
+        assert_eq!(result, Err(Ok(StablecoinError::FeatureDisabled)));
+        assert_eq!(contract.current_reserve_ratio_bps(), i128::MAX);
+        
+        contract.mint(&minter, &user, &1000);
+        assert_eq!(contract.current_reserve_ratio_bps(), 0);
+        
+        env.as_contract(&contract.address, || {
+            // A ratio the reserves do not already meet cannot be set
+            assert_eq!(utils::configure_min_reserve_ratio(&env, 5_000), Err(StablecoinError::Undercollateralized));
+            assert_eq!(utils::deposit_backing(&env, 600), Ok(()));
+            assert_eq!(utils::configure_min_reserve_ratio(&env, 5_000), Ok(()));
+        });
+        assert_eq!(contract.min_reserve_ratio_bps(), 5_000);
+        assert_eq!(contract.current_reserve_ratio_bps(), 6_000);
+        
+        // 600 reserves cover 50% of 1200 but not of 1201
+        let (_, max) = contract.operation_bounds(&Symbol::new(&env, "mint"), &minter);
+        assert_eq!(max, 200);
+        let result = contract.try_mint(&minter, &user, &201);
+        assert_eq!(result, Err(Ok(StablecoinError::Undercollateralized)));
+        contract.mint(&minter, &user, &200);
+        assert_eq!(contract.current_reserve_ratio_bps(), 5_000);
+        
+        // Withdrawals cannot take the reserves below the ratio either
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::withdraw_backing(&env, 1), Err(StablecoinError::Undercollateralized));
+        });
+        
+        // More reserves, or a lower ratio, allow minting again
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::deposit_backing(&env, 100), Ok(()));
+        });
+        contract.mint(&minter, &user, &200);
+        env.as_contract(&contract.address, || {
+            assert_eq!(utils::configure_min_reserve_ratio(&env, 0), Ok(()));
+        });
+        contract.mint(&minter, &user, &1000);
+        assert_eq!(contract.balance(&user), 2400);
+    }
+
+    #[test]
+    fn test_upgrade_consent_tally() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, _admin, _pauser, _upgrader, minter) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        for holder in [&alice, &bob, &carol] {
+            contract.mint(&minter, holder, &100);
+        }
+        assert_eq!(contract.upgrade_consent_tally(), (0, 0));
+        
+        // Only holders may signal
+        let result = contract.try_set_upgrade_consent(&outsider, &false);
+        assert_eq!(result, Err(Ok(StablecoinError::InsufficientBalance)));
+        
+        contract.set_upgrade_consent(&alice, &true);
+        contract.set_upgrade_consent(&bob, &false);
+        contract.set_upgrade_consent(&carol, &false);
+        assert_eq!(contract.upgrade_consent_tally(), (1, 2));
+        assert_eq!(contract.upgrade_consent(&bob), Some(false));
+        assert_eq!(contract.upgrade_consent(&outsider), None);
+        
+        // Changing a signal moves the holder between counts; repeating it does not
+        contract.set_upgrade_consent(&carol, &true);
+        contract.set_upgrade_consent(&carol, &true);
+        assert_eq!(contract.upgrade_consent_tally(), (2, 1));
+    }
+
+    #[test]
+    fn test_denylist_oracle_blocks_sanctioned_recipient() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let sanctioned = Address::generate(&env);
+        let oracle = env.register(MockSanctionsOracle, ());
+        let oracle_client = MockSanctionsOracleClient::new(&env, &oracle);
+        contract.mint(&minter, &user, &1000);
+        
+        oracle_client.set_sanctioned(&sanctioned, &true);
+        assert!(!contract.is_denylisted(&sanctioned));
+        
+        contract.set_denylist_oracle(&admin, &Some(oracle.clone()));
+        assert_eq!(contract.get_denylist_oracle(), Some(oracle.clone()));
+        assert_eq!(contract.system_addresses(&admin).denylist_oracle, Some(oracle.clone()));
+        
+        // Transfers to the flagged address are blocked without a local entry
+        let result = contract.try_transfer(&user, &sanctioned, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        assert!(contract.is_denylisted(&sanctioned));
+        assert_eq!(contract.denylist_count(), 0);
+        
+        // Unflagged addresses still receive
+        let other = Address::generate(&env);
+        contract.transfer(&user, &other, &100);
+        assert_eq!(contract.balance(&other), 100);
+        
+        // The answer is cached until its TTL passes
+        oracle_client.set_sanctioned(&sanctioned, &false);
+        assert!(contract.is_denylisted(&sanctioned));
+        env.ledger().with_mut(|li| li.sequence_number += SANCTIONS_CACHE_TTL_LEDGERS);
+        assert!(!contract.is_denylisted(&sanctioned));
+        contract.transfer(&user, &sanctioned, &100);
+        assert_eq!(contract.balance(&sanctioned), 100);
+        
+        // Local entries can still be added for oracle-flagged accounts
+        oracle_client.set_sanctioned(&other, &true);
+        contract.add_to_denylist(&admin, &other);
+        assert_eq!(contract.denylist_count(), 1);
+    }
+
+    #[test]
+    fn test_denylist_oracle_failures() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let user = Address::generate(&env);
+        let known = Address::generate(&env);
+        let unknown = Address::generate(&env);
+        let oracle = env.register(MockSanctionsOracle, ());
+        let oracle_client = MockSanctionsOracleClient::new(&env, &oracle);
+        contract.mint(&minter, &user, &1000);
+        contract.set_denylist_oracle(&admin, &Some(oracle.clone()));
+        contract.transfer(&user, &known, &100);
+        
+        // Once answers go stale, a failing oracle falls back to the last answer held
+        oracle_client.set_failing(&true);
+        env.ledger().with_mut(|li| li.sequence_number += SANCTIONS_CACHE_TTL_LEDGERS);
+        contract.transfer(&user, &known, &100);
+        assert_eq!(contract.balance(&known), 200);
+        
+        // Accounts it never answered for are refused
+        let result = contract.try_transfer(&user, &unknown, &100);
+        assert_eq!(result, Err(Ok(StablecoinError::AccountDenylisted)));
+        
+        // Read paths only consult the cache, so they never reach the failing oracle
+        assert!(contract.can_transact(&user, &unknown));
+        assert!(!contract.account_compliance(&unknown).denylisted);
+        
+        // Nothing is held from the refusal: the recovered oracle is asked again right away
+        oracle_client.set_failing(&false);
+        contract.transfer(&user, &unknown, &100);
+        assert_eq!(contract.balance(&unknown), 100);
+    }
+
+    #[test]
+    fn test_lifecycle_state() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let uninitialized = MyStablecoinClient::new(&env, &env.register(MyStablecoin, ()));
+        assert_eq!(uninitialized.lifecycle_state(), Symbol::new(&env, "uninitialized"));
+        
+        let (contract, admin, pauser, _upgrader, _minter) = setup(&env);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "active"));
+        
+        // Upgrade locking is the lowest-precedence mode
+        contract.lock_upgrades(&admin);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "upgrade_locked"));
+        
+        // A pending recovery outranks it
+        let guardian = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        contract.set_recovery_config(&admin, &recovery, &MIN_RECOVERY_DELAY_LEDGERS);
+        contract.add_guardian(&admin, &guardian);
+        contract.initiate_recovery(&guardian);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "recovery_pending"));
+        
+        contract.freeze_supply(&admin);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "supply_frozen"));
+        
+        contract.pause(&pauser);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "paused"));
+        contract.unpause(&pauser);
+        
+        contract.pause_with_allowance_freeze(&pauser);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "emergency"));
+        contract.unpause_restore_allowances(&pauser);
+        
+        // Clearing modes walks back down the order
+        contract.thaw_supply(&admin);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "recovery_pending"));
+        contract.cancel_recovery(&admin);
+        assert_eq!(contract.lifecycle_state(), Symbol::new(&env, "upgrade_locked"));
+    }
+
+    #[test]
+    fn test_spender_index_prunes_lapsed_allowances() {
+        let env = Env::default();
+        env.mock_all_auths();
+        
+        let (contract, admin, _pauser, _upgrader, minter) = setup(&env);
+        let owner = Address::generate(&env);
+        contract.mint(&minter, &owner, &1000);
+        
+        // Fill the index with short-lived allowances
+        for _ in 0..MAX_SPENDERS_PER_OWNER {
+            contract.approve(&owner, &Address::generate(&env), &10, &50);
+        }
+        let result = contract.try_approve(&owner, &Address::generate(&env), &10, &1000);
+        assert_eq!(result, Err(Ok(StablecoinError::InvalidParameters)));
+        
+        // Once they lapse, their slots are reclaimed for new spenders
+        env.ledger().with_mut(|li| li.sequence_number = 51);
+        let spender = Address::generate(&env);
+        contract.approve(&owner, &spender, &10, &1000);
+        assert_eq!(contract.get_allowances(&owner).len(), 1);
+        assert_eq!(contract.get_stats(&admin).total_outstanding_allowances, 10);
+    }
+}
@@ -1,27 +1,146 @@
 // SPDX-License-Identifier: MIT
 // Compatible with OpenZeppelin Stellar Soroban Contracts ^0.3.0
 
-use soroban_sdk::{Env, String, Symbol, contracterror, contracttype};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, contracterror, contracttype};
 
 /// Stablecoin metadata constants
 pub const DECIMALS: u32 = 2;
 pub const NAME: &str = "Costa Rica Colon";
 pub const SYMBOL: &str = "CRCX";
+pub const CURRENCY_CODE: &str = "CRC"; // ISO-4217 code of the backing fiat currency
 
 /// Role constants for access control
 pub const PAUSER_ROLE: &str = "pauser";
 pub const UPGRADER_ROLE: &str = "upgrader";
 pub const MINTER_ROLE: &str = "minter";
+pub const COMPLIANCE_ROLE: &str = "compliance";
+pub const BURNER_ROLE: &str = "burner";
+pub const SPENDER_ROLE: &str = "spender";
 
-/// Operational limits for validation
-pub const MAX_SUPPLY: i128 = 1_000_000_000_000_000; // 1 trillion tokens
-pub const MAX_SINGLE_OPERATION: i128 = 100_000_000_000; // 100 billion tokens max per operation
-pub const MIN_AMOUNT: i128 = 1; // Minimum 1 whole token - smallest transferable amount
+/// Compliance actions accepted by `batch_compliance`
+pub const FREEZE_ACTION: &str = "freeze";
+pub const UNFREEZE_ACTION: &str = "unfreeze";
+pub const DENYLIST_ACTION: &str = "denylist";
+pub const ALLOW_ACTION: &str = "allow";
+
+/// Labels for system addresses that are not role members
+pub const ADMIN_LABEL: &str = "admin";
+pub const FEE_COLLECTOR_LABEL: &str = "fee_collector";
+
+/// States reported by `health`
+pub const HEALTH_OK: &str = "ok";
+pub const HEALTH_PAUSED: &str = "paused";
+pub const HEALTH_EMERGENCY: &str = "emergency";
+pub const HEALTH_UNINITIALIZED: &str = "uninitialized";
+
+/// States reported by `lifecycle_state`, highest precedence first
+pub const LIFECYCLE_UNINITIALIZED: &str = "uninitialized";
+pub const LIFECYCLE_EMERGENCY: &str = "emergency";
+pub const LIFECYCLE_PAUSED: &str = "paused";
+pub const LIFECYCLE_SUPPLY_FROZEN: &str = "supply_frozen";
+pub const LIFECYCLE_RECOVERY_PENDING: &str = "recovery_pending";
+pub const LIFECYCLE_UPGRADE_LOCKED: &str = "upgrade_locked";
+pub const LIFECYCLE_ACTIVE: &str = "active";
+
+/// Operational limits for validation, in base units (10^DECIMALS base units per token,
+/// so 1 base unit is 0.01 tokens at DECIMALS = 2)
+pub const MAX_SUPPLY: i128 = 1_000_000_000_000_000; // 10 trillion tokens at 2 decimals
+pub const MAX_SINGLE_OPERATION: i128 = 100_000_000_000; // 1 billion tokens max per operation at 2 decimals
+pub const MIN_AMOUNT: i128 = 1; // 1 base unit (0.01 tokens at 2 decimals) - smallest transferable amount
+
+/// Methods where a zero amount is meaningful (clearing an allowance); every other
+/// amount-taking method rejects zero
+pub const ZERO_AMOUNT_METHODS: [&str; 1] = ["approve"];
+
+/// Methods that can be paused individually with `pause_method`; each checks its own name
+pub const PAUSABLE_METHODS: [&str; 25] = [
+    "mint", "mint_idempotent", "mint_for_deposit", "batch_mint", "batch_mint_lenient", "airdrop",
+    "transfer", "transfer_and_call", "transfer_partial", "transfer_with_memo", "transfer_from",
+    "batch_transfer", "pull", "burn", "burn_with_memo", "governed_burn", "burn_from",
+    "approve", "approve_for", "ensure_allowance", "admin_approve",
+    "escrow_deposit", "escrow_release", "escrow_refund", "recover_account",
+];
+
+/// Initialization constraints
+pub const MAX_NAME_LENGTH: u32 = 32;
+pub const MAX_SYMBOL_LENGTH: u32 = 12;
+pub const MAX_DECIMALS: u32 = 18;
+pub const REQUIRE_DISTINCT_ROLE_HOLDERS: bool = false; // Require admin/pauser/upgrader/minter to differ
 
 /// Validation configuration
 pub const ENABLE_SUPPLY_LIMITS: bool = true;
 pub const ENABLE_OPERATION_LIMITS: bool = true;
-pub const ENABLE_STRICT_VALIDATION: bool = true;
+pub const ENABLE_STRICT_VALIDATION: bool = true; // Default for the runtime strict-validation toggle
+pub const ENABLE_ALLOWANCES: bool = true; // Default for the runtime delegated-spending toggle
+pub const SUPPORTS_MUXED_ADDRESSES: bool = false; // Muxed (M...) recipients are refused by the host before the contract runs
+
+/// Optional features, compiled in but switched off until enabled here
+pub const ENABLE_BACKING: bool = false; // Attested fiat reserve deposits and withdrawals
+pub const ENABLE_TIMELOCK: bool = false; // Sensitive admin actions must be scheduled in advance
+
+/// Batch configuration
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = 100; // Default cap on entries per batch call
+
+/// Storage layout version written by this code; contracts without a stored version
+/// predate versioning and use layout 1
+pub const STORAGE_VERSION: u32 = 2;
+pub const LEGACY_STORAGE_VERSION: u32 = 1;
+
+/// Lock configuration
+pub const DEFAULT_MAX_LOCKS_PER_ACCOUNT: u32 = 16; // Default cap on lock entries stored per account
+
+/// Idempotency configuration
+pub const IDEMPOTENCY_KEY_TTL_LEDGERS: u32 = 120_960; // ~7 days at 5s per ledger
+
+/// Pause subscriber configuration
+pub const MAX_PAUSE_SUBSCRIBERS: u32 = 10;
+pub const PAUSE_CHANGED_HOOK: &str = "on_pause_changed";
+
+/// Transfer fee configuration
+pub const MAX_FEE_BPS: u32 = 1_000; // Fees can never exceed 10% of a transfer
+pub const BPS_DENOMINATOR: i128 = 10_000;
+pub const MAX_FEE_TIERS: u32 = 10; // Bound on amount bands in a tiered fee schedule
+
+/// Redemption settlement configuration
+pub const SETTLEMENT_HOOK: &str = "settle_redemption";
+
+/// Transfer-and-call receiver callback
+pub const TOKEN_RECEIVED_HOOK: &str = "on_token_transfer";
+
+/// Post-upgrade finalization callback
+pub const POST_UPGRADE_HOOK: &str = "on_upgrade";
+
+/// Sanctions oracle configuration
+pub const SANCTIONS_HOOK: &str = "is_sanctioned";
+pub const SANCTIONS_CACHE_TTL_LEDGERS: u32 = 720; // ~1 hour at 5s per ledger
+
+/// Mint log configuration
+pub const MINT_LOG_CAPACITY: u32 = 1_000; // Rolling window of retained mint log entries
+pub const RECENT_OPS_CAPACITY: u32 = 20; // Rolling window of recent operation summaries
+pub const MAX_PAGE_SIZE: u32 = 100; // Maximum entries returned by a paginated read
+pub const VOLUME_WINDOW_LEDGERS: u32 = 720; // ~1 hour at 5s per ledger
+
+/// Allowance index configuration
+pub const MAX_SPENDERS_PER_OWNER: u32 = 50; // Bound on distinct spenders tracked per owner
+pub const MAX_ALLOWANCE_GRACE_LEDGERS: u32 = 720; // ~1 hour at 5s per ledger
+pub const DEFAULT_ALLOWANCE_TTL_LEDGERS: u32 = 17_280; // ~1 day at 5s per ledger
+pub const MAX_ALLOWANCE_SNAPSHOT: u32 = 100; // Bound on allowances zeroed by a freezing pause
+
+/// Admin action timelock configuration
+pub const DEFAULT_TIMELOCK_DELAY_LEDGERS: u32 = 17_280; // ~1 day at 5s per ledger
+
+/// Admin recovery configuration
+pub const MIN_RECOVERY_DELAY_LEDGERS: u32 = 17_280; // ~1 day at 5s per ledger
+pub const MAX_GUARDIANS: u32 = 10;
+
+/// Counterparty restriction configuration
+pub const MAX_ALLOWED_COUNTERPARTIES: u32 = 50; // Bound on approved counterparties per account
+
+/// Redemption memo configuration
+pub const MAX_MEMO_LENGTH: u32 = 64; // Enough for bank references and IBAN-style identifiers
+
+/// Token metadata configuration
+pub const MAX_TOKEN_URI_LENGTH: u32 = 256; // Room for an https or ipfs URI to the metadata JSON
 
 /// Events
 pub const MINT_EVENT: &str = "mint";
@@ -29,6 +148,49 @@ pub const BURN_EVENT: &str = "burn";
 pub const TRANSFER_EVENT: &str = "transfer";
 pub const PAUSE_EVENT: &str = "pause";
 pub const UNPAUSE_EVENT: &str = "unpause";
+pub const FREEZE_EVENT: &str = "freeze";
+pub const UNFREEZE_EVENT: &str = "unfreeze";
+pub const LOCK_EVENT: &str = "lock";
+pub const DENYLIST_ADD_EVENT: &str = "denylist_add";
+pub const DENYLIST_REMOVE_EVENT: &str = "denylist_remove";
+pub const ALLOWLIST_ADD_EVENT: &str = "allowlist_add";
+pub const ALLOWLIST_REMOVE_EVENT: &str = "allowlist_remove";
+pub const RECOVERY_INITIATED_EVENT: &str = "recovery_initiated";
+pub const RECOVERY_CANCELLED_EVENT: &str = "recovery_cancelled";
+pub const RECOVERY_CLAIMED_EVENT: &str = "recovery_claimed";
+pub const ADMIN_TRANSFER_STARTED_EVENT: &str = "admin_transfer_started";
+pub const ADMIN_TRANSFERRED_EVENT: &str = "admin_transferred";
+pub const LOW_BALANCE_EVENT: &str = "low_balance";
+pub const USER_BURN_EVENT: &str = "user_burn";
+pub const PROTOCOL_BURN_EVENT: &str = "protocol_burn";
+pub const EMERGENCY_ACTION_EVENT: &str = "emergency_action";
+pub const ADMIN_APPROVAL_EVENT: &str = "admin_approval";
+pub const ACCOUNT_RECOVERED_EVENT: &str = "account_recovered";
+pub const SPENDER_CONSENT_EVENT: &str = "spender_consent";
+
+/// Emergency action kinds carried by `EmergencyAction` events
+pub const EMERGENCY_FREEZE_KIND: &str = "emergency_freeze";
+pub const MINTER_ROTATION_KIND: &str = "minter_rotation";
+pub const RECOVERY_CLAIM_KIND: &str = "recovery_claim";
+pub const BURN_WITH_MEMO_EVENT: &str = "burn_with_memo";
+pub const TRANSFER_WITH_MEMO_EVENT: &str = "transfer_with_memo";
+pub const CLAWBACK_EVENT: &str = "clawback";
+pub const MINTER_ROTATED_EVENT: &str = "minter_emergency_rotated";
+pub const FEE_EVENT: &str = "fee";
+pub const FEE_BURNED_EVENT: &str = "fee_burned";
+pub const RENT_RESERVE_EVENT: &str = "rent_reserve";
+pub const RENT_RESERVE_WITHDRAWN_EVENT: &str = "rent_reserve_withdrawn";
+pub const ESCROW_DEPOSIT_EVENT: &str = "escrow_deposit";
+pub const ESCROW_RELEASE_EVENT: &str = "escrow_release";
+pub const ESCROW_REFUND_EVENT: &str = "escrow_refund";
+pub const ACTION_SCHEDULED_EVENT: &str = "action_scheduled";
+pub const ACTION_EXECUTED_EVENT: &str = "action_executed";
+pub const ACTION_CANCELLED_EVENT: &str = "action_cancelled";
+pub const CONFIG_CHANGED_EVENT: &str = "config_changed";
+pub const DEPOSIT_MINTED_EVENT: &str = "deposit_minted";
+pub const DUPLICATE_SUPPRESSED_EVENT: &str = "dup_suppressed";
+pub const BATCH_MINTED_EVENT: &str = "batch_minted";
+pub const BATCH_TRANSFERRED_EVENT: &str = "batch_transferred";
 
 /// Error types for the stablecoin contract
 #[contracterror]
@@ -48,6 +210,333 @@ pub enum StablecoinError {
     SelfTransfer = 12,
     InvalidRole = 13,
     ContractNotInitialized = 14,
+    DuplicateOperation = 15,
+    AccountFrozen = 16,
+    AccountDenylisted = 17,
+    CounterpartyNotAllowed = 18,
+    TimelockNotExpired = 19,
+    AllowanceExpired = 20,
+    SettlementFailed = 21,
+    RateLimitExceeded = 22,
+    SupplyMismatch = 23,
+    HookFailed = 24,
+    FeatureDisabled = 25,
+    HoldPeriodActive = 26,
+    SupplyFrozen = 27,
+    RecipientNotAllowlisted = 28,
+    RecoveryPending = 29,
+    Undercollateralized = 30,
+}
+
+/// Summary of the configuration applied by `initialize`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitReport {
+    pub admin: Address,
+    pub pauser: Address,
+    pub upgrader: Address,
+    pub minter: Address,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub currency: Symbol,
+    pub supply_limits: bool,
+    pub operation_limits: bool,
+    pub strict_validation: bool,
+}
+
+/// Backing applied at genesis, before the treasury mint: the attested `reserve` (in the
+/// backing asset's decimals) and whether strict backing is on from the start
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenesisBacking {
+    pub strict: bool,
+    pub reserve: i128,
+}
+
+/// A portion of an account's balance that cannot be moved until `release_ledger`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceLock {
+    pub amount: i128,
+    pub release_ledger: u32,
+}
+
+/// Vesting schedule for a genesis treasury mint: nothing vests before `cliff_ledger`,
+/// then `total` vests linearly from `start_ledger` until it is fully vested at `end_ledger`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VestingSchedule {
+    pub total: i128,
+    pub start_ledger: u32,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// An allowance zeroed by a freezing pause, kept so it can be restored on unpause
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllowanceSnapshotEntry {
+    pub owner: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// A single mint recorded for off-chain reconciliation
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MintLogEntry {
+    pub ledger: u32,
+    pub minter: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// A recent mint, burn or transfer, kept for clients without an indexer.
+/// `from` is `None` for mints and `to` is `None` for burns.
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpSummary {
+    pub op: Symbol,
+    pub from: Option<Address>,
+    pub to: Option<Address>,
+    pub amount: i128,
+    pub ledger: u32,
+}
+
+/// Low balance alert for an account: fires once when the balance drops below `threshold`,
+/// then stays disarmed until the balance is back at or above it
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceAlert {
+    pub threshold: i128,
+    pub armed: bool,
+}
+
+/// Event data published when an account's balance drops below its alert threshold
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowBalance {
+    pub account: Address,
+    pub balance: i128,
+}
+
+/// Event data published on every emergency path, alongside its specific event, so security
+/// teams can monitor a single stream
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmergencyAction {
+    pub kind: Symbol,
+    pub by: Address,
+    pub at: u32,
+}
+
+/// Event data for an allowance set on a managed account's behalf by the admin or compliance
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdminApproval {
+    pub by: Address,
+    pub from: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Event data for a lost-key holder migrated from `old` to `new`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountRecovered {
+    pub old: Address,
+    pub new: Address,
+}
+
+/// Event data published once a holder-initiated burn (a voluntary redemption) is final
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserBurn {
+    pub from: Address,
+    pub amount: i128,
+}
+
+/// Event data published once a burn by the admin, a burner or compliance (a monetary
+/// policy or enforcement reduction) is final
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtocolBurn {
+    pub amount: i128,
+    pub by: Address,
+}
+
+/// A timelocked admin action: executable from `eta`, then consumed by the guarded method
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledAction {
+    pub eta: u32,
+    pub executed: bool,
+}
+
+/// Tokens held by the contract until `release_ledger`, then payable to `beneficiary`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Escrow {
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub release_ledger: u32,
+}
+
+/// What happens to collected transfer fees: routed to the collector or burned
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeeDisposition {
+    Route,
+    Burn,
+}
+
+/// Percentage transfer fee, optionally capped at an absolute amount (0 meaning uncapped)
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeConfig {
+    pub bps: u32,
+    pub collector: Address,
+    pub max_fee_absolute: i128,
+}
+
+/// Automatic TTL maintenance: every `every_ops` operations the instance storage and code
+/// TTL is extended to `extend_to` ledgers, and `fee_share_bps` of each transfer fee is set
+/// aside in a rent reserve held by the contract
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtlMaintenance {
+    pub every_ops: u32,
+    pub extend_to: u32,
+    pub fee_share_bps: u32,
+}
+
+/// Settlement contract called after burns, and whether a failed call reverts the burn
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettlementConfig {
+    pub contract: Address,
+    pub strict: bool,
+}
+
+/// Every privileged or integration address configured on the contract; `None` when unset
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemAddresses {
+    pub admin: Option<Address>,
+    pub pending_admin: Option<Address>,
+    pub recovery: Option<Address>,
+    pub fee_collector: Option<Address>,
+    pub settlement: Option<Address>,
+    pub denylist_oracle: Option<Address>,
+    pub post_upgrade_hook: Option<Address>,
+    pub migration_rescue: Option<Address>,
+}
+
+/// Protocol-wide amounts minted and burned within a single ledger
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerFlow {
+    pub ledger: u32,
+    pub minted: i128,
+    pub burned: i128,
+}
+
+/// The limits that apply to a specific caller right now. `None` means unlimited; a caller
+/// without the minter role has no mint allowance or mint headroom.
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveLimits {
+    /// Largest amount a single operation may move, if operation limits are enabled
+    pub max_single_operation: Option<i128>,
+    /// Whether the caller's compliance movements (clawbacks) skip the operation limit
+    pub compliance_exempt: bool,
+    /// Remaining minter allowance
+    pub mint_allowance: Option<i128>,
+    /// Room left under this ledger's global mint cap
+    pub mint_headroom: Option<i128>,
+    /// Room left under this ledger's global burn cap
+    pub burn_headroom: Option<i128>,
+    /// Amount the caller can transfer or burn from their own balance right now
+    pub outflow_headroom: i128,
+}
+
+/// Human-readable amount carried by memo events: `text` is the amount in whole
+/// units, floored to the display decimals (e.g. "1234.56"), alongside its currency code
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayAmount {
+    pub text: String,
+    pub currency: Symbol,
+}
+
+/// Every admin-tunable setting, applied together by `set_config`
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StablecoinConfig {
+    pub max_supply: i128,
+    pub target_supply: i128,
+    pub max_batch_size: u32,
+    pub mint_flow_cap: i128,
+    pub burn_flow_cap: i128,
+    pub mint_cooldown_ledgers: u32,
+    pub allowance_grace_ledgers: u32,
+    pub strict_validation: bool,
+    pub allow_self_transfer: bool,
+    pub reject_duplicate_mints: bool,
+    pub role_gated_burns: bool,
+}
+
+/// Aggregate event data published once per bulk operation in batch-events mode
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchSummary {
+    pub count: u32,
+    pub total: i128,
+}
+
+/// Event data for a mint backing a bank deposit, identified by the bank's reference
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepositMinted {
+    pub bank_ref: BytesN<32>,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Event data for a replayed idempotency key, published instead of the primary event
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateSuppressed {
+    pub key: BytesN<32>,
+}
+
+/// Audit record published whenever an admin changes a tunable setting
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChanged {
+    pub key: Symbol,
+    pub by: Address,
+}
+
+/// Every compliance restriction on a single account, for dashboards that need one read per account
+#[contracttype]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountCompliance {
+    pub frozen: bool,
+    /// Whether the account is on the local denylist or flagged by a cached oracle answer
+    pub denylisted: bool,
+    /// Whether the account may receive under the allowlist; always true while allowlist mode is off
+    pub allowlisted: bool,
+    /// Whether the account may only pay its approved counterparties
+    pub outgoing_restricted: bool,
+    /// Portion of the balance held by unreleased locks
+    pub locked: i128,
+    /// Amount the account can send right now after every restriction
+    pub spendable: i128,
 }
 
 /// Token statistics for monitoring
@@ -57,7 +546,8 @@ pub struct TokenStats {
     pub total_supply: i128,
     pub total_minted: i128,
     pub total_burned: i128,
-    pub holders_count: u32,
+    pub active_holders: u32,
+    pub total_outstanding_allowances: i128,
 }
 
 /// Helper function to create role symbols
@@ -95,5 +585,21 @@ pub fn error_to_message(error: StablecoinError) -> &'static str {
         StablecoinError::SelfTransfer => "Cannot transfer to same address",
         StablecoinError::InvalidRole => "Invalid or unrecognized role",
         StablecoinError::ContractNotInitialized => "Contract not properly initialized",
+        StablecoinError::DuplicateOperation => "Operation with this idempotency key was already processed",
+        StablecoinError::AccountFrozen => "Account is frozen",
+        StablecoinError::AccountDenylisted => "Account is denylisted",
+        StablecoinError::CounterpartyNotAllowed => "Recipient is not an allowed counterparty of the sender",
+        StablecoinError::TimelockNotExpired => "Timelock has not expired yet",
+        StablecoinError::AllowanceExpired => "Allowance has expired",
+        StablecoinError::SettlementFailed => "Redemption settlement call failed",
+        StablecoinError::RateLimitExceeded => "Rate limit exceeded for this ledger",
+        StablecoinError::SupplyMismatch => "Total supply does not match tracked mints less burns",
+        StablecoinError::HookFailed => "External hook call failed",
+        StablecoinError::FeatureDisabled => "This feature is disabled in this deployment",
+        StablecoinError::HoldPeriodActive => "Received tokens are still within the holding period",
+        StablecoinError::SupplyFrozen => "Total supply is frozen; minting and burning are disabled",
+        StablecoinError::RecipientNotAllowlisted => "Recipient is not on the allowlist",
+        StablecoinError::RecoveryPending => "Privileged operation blocked while an admin recovery is pending",
+        StablecoinError::Undercollateralized => "Attested reserves do not cover the supply",
     }
 } 
\ No newline at end of file
@@ -1,18 +1,56 @@
 // SPDX-License-Identifier: MIT
 // Compatible with OpenZeppelin Stellar Soroban Contracts ^0.3.0
 
-use soroban_sdk::{Env, Address, Symbol, String};
-use stellar_access_control::{self as access_control};
+use soroban_sdk::{panic_with_error, vec, xdr::ToXdr, BytesN, Env, Address, IntoVal, Symbol, String, Val, Vec};
+use stellar_access_control::{self as access_control, AccessControlStorageKey};
 use stellar_fungible::Base;
+use stellar_pausable as pausable;
+use crate::events;
+use crate::storage;
 use crate::types::{
-    StablecoinError, DECIMALS, NAME, SYMBOL, MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE,
-    MAX_SUPPLY, MAX_SINGLE_OPERATION, MIN_AMOUNT, ENABLE_SUPPLY_LIMITS, ENABLE_OPERATION_LIMITS
+    BalanceLock, EffectiveLimits, Escrow, FeeConfig, FeeDisposition, GenesisBacking, TtlMaintenance, LedgerFlow, MintLogEntry, SystemAddresses, TokenStats, StablecoinError, DECIMALS, NAME, SYMBOL, CURRENCY_CODE,
+    MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE, COMPLIANCE_ROLE, BURNER_ROLE, SPENDER_ROLE, ADMIN_LABEL, FEE_COLLECTOR_LABEL,
+    HEALTH_OK, HEALTH_PAUSED, HEALTH_EMERGENCY, HEALTH_UNINITIALIZED, STORAGE_VERSION,
+    LIFECYCLE_UNINITIALIZED, LIFECYCLE_EMERGENCY, LIFECYCLE_PAUSED, LIFECYCLE_SUPPLY_FROZEN,
+    LIFECYCLE_RECOVERY_PENDING, LIFECYCLE_UPGRADE_LOCKED, LIFECYCLE_ACTIVE,
+    ENABLE_TIMELOCK, ScheduledAction, BalanceAlert, LowBalance, LOW_BALANCE_EVENT,
+    UserBurn, ProtocolBurn, USER_BURN_EVENT, PROTOCOL_BURN_EVENT, EmergencyAction, EMERGENCY_ACTION_EVENT,
+    MAX_SUPPLY, MAX_SINGLE_OPERATION, MIN_AMOUNT, ZERO_AMOUNT_METHODS, PAUSABLE_METHODS, ENABLE_SUPPLY_LIMITS, ENABLE_OPERATION_LIMITS,
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_DECIMALS, REQUIRE_DISTINCT_ROLE_HOLDERS,
+    MAX_PAUSE_SUBSCRIBERS, PAUSE_CHANGED_HOOK, MIN_RECOVERY_DELAY_LEDGERS, MAX_GUARDIANS,
+    MINT_LOG_CAPACITY, RECENT_OPS_CAPACITY, RENT_RESERVE_EVENT, OpSummary, BURN_EVENT, MAX_PAGE_SIZE, VOLUME_WINDOW_LEDGERS, MAX_SPENDERS_PER_OWNER, MAX_ALLOWED_COUNTERPARTIES, MAX_ALLOWANCE_GRACE_LEDGERS,
+    MAX_MEMO_LENGTH, MAX_TOKEN_URI_LENGTH, SETTLEMENT_HOOK, POST_UPGRADE_HOOK, SANCTIONS_HOOK, SANCTIONS_CACHE_TTL_LEDGERS, MAX_FEE_BPS, MAX_FEE_TIERS, BPS_DENOMINATOR, FEE_EVENT, FEE_BURNED_EVENT,
+    CONFIG_CHANGED_EVENT, ConfigChanged, DisplayAmount, AccountCompliance, StablecoinConfig,
+    FREEZE_ACTION, UNFREEZE_ACTION, DENYLIST_ACTION, ALLOW_ACTION,
+    FREEZE_EVENT, UNFREEZE_EVENT, DENYLIST_ADD_EVENT, DENYLIST_REMOVE_EVENT,
+    AllowanceSnapshotEntry, MAX_ALLOWANCE_SNAPSHOT, VestingSchedule, MINT_EVENT,
 };
 
 /// Initialize token metadata
 pub fn initialize_token(env: &Env) {
     // Set token metadata using the stellar-fungible library
     Base::set_metadata(env, DECIMALS, String::from_str(env, NAME), String::from_str(env, SYMBOL));
+    
+    // Seed the display currency code
+    storage::set_currency(env, &Symbol::new(env, CURRENCY_CODE));
+}
+
+/// Validate a 3-4 letter uppercase currency code and convert it to a symbol
+pub fn parse_currency_code(env: &Env, code: &String) -> Result<Symbol, StablecoinError> {
+    let len = code.len() as usize;
+    if !(3..=4).contains(&len) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let mut buf = [0u8; 4];
+    code.copy_into_slice(&mut buf[..len]);
+    
+    if !buf[..len].iter().all(|c| c.is_ascii_uppercase()) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let code_str = core::str::from_utf8(&buf[..len]).map_err(|_| StablecoinError::InvalidParameters)?;
+    Ok(Symbol::new(env, code_str))
 }
 
 /// Initialize access control with all required roles
@@ -32,6 +70,49 @@ pub fn initialize_access_control(
     access_control::grant_role_no_auth(env, admin, minter, &Symbol::new(env, MINTER_ROLE));
 }
 
+/// Validate initialization parameters without touching storage
+#[allow(clippy::too_many_arguments)]
+pub fn validate_init_params(
+    env: &Env,
+    admin: &Address,
+    pauser: &Address,
+    upgrader: &Address,
+    minter: &Address,
+    name: &String,
+    symbol: &String,
+    decimals: u32,
+) -> Result<(), StablecoinError> {
+    // Every role holder must be a valid address
+    for address in [admin, pauser, upgrader, minter] {
+        validate_address_comprehensive(env, address)?;
+    }
+    
+    // Role holders must differ from each other when required
+    if REQUIRE_DISTINCT_ROLE_HOLDERS {
+        let holders = [admin, pauser, upgrader, minter];
+        for (i, a) in holders.iter().enumerate() {
+            if holders[i + 1..].contains(a) {
+                return Err(StablecoinError::InvalidParameters);
+            }
+        }
+    }
+    
+    // Metadata must be present and bounded
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LENGTH {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    if decimals > MAX_DECIMALS {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    Ok(())
+}
+
 /// Validate that an address is not the zero address or invalid address
 pub fn validate_address(address: &Address) -> Result<(), StablecoinError> {
     
@@ -44,10 +125,13 @@ pub fn validate_address(address: &Address) -> Result<(), StablecoinError> {
     Ok(())
 }
 
-/// Validate that an address is not the contract's own address
-pub fn validate_not_self_address(_env: &Env, _address: &Address) -> Result<(), StablecoinError> {
-    // Skip this validation to avoid potential panics in some environments
-    Ok(())
+/// Validate that an address is not the contract's own address. Only enforced in
+/// strict mode; permissive mode accepts any address (useful for testing).
+pub fn validate_not_self_address(env: &Env, address: &Address) -> Result<(), StablecoinError> {
+    if !storage::get_strict_validation(env) {
+        return Ok(());
+    }
+    validate_not_specific_address(address, &env.current_contract_address())
 }
 
 /// Validate that an address is not the same as a specific contract address (for testing)
@@ -125,6 +209,42 @@ pub fn validate_amount_range(amount: i128) -> Result<(), StablecoinError> {
     Ok(())
 }
 
+/// Whether `method` accepts a zero amount under the zero-amount policy
+pub fn allows_zero_amount(method: &str) -> bool {
+    ZERO_AMOUNT_METHODS.contains(&method)
+}
+
+/// Whether movements initiated by `caller` are exempt from operation and rate limits.
+/// Compliance enforcement (and the admin acting as compliance) can involve large amounts;
+/// supply caps still apply.
+pub fn is_limit_exempt(env: &Env, caller: &Address) -> bool {
+    access_control::get_admin(env).as_ref() == Some(caller)
+        || access_control::has_role(env, caller, &Symbol::new(env, COMPLIANCE_ROLE)).is_some()
+}
+
+/// Validate an amount moved on behalf of `caller`, skipping the per-operation limit for
+/// limit-exempt callers
+pub fn validate_amount_for(env: &Env, caller: &Address, amount: i128) -> Result<(), StablecoinError> {
+    if !is_limit_exempt(env, caller) {
+        return validate_amount_range(amount);
+    }
+    
+    if amount < MIN_AMOUNT {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    
+    Ok(())
+}
+
+/// Validate that an amount is a multiple of the configured precision step, if any
+pub fn validate_precision(env: &Env, amount: i128) -> Result<(), StablecoinError> {
+    let step = storage::get_precision_step(env);
+    if step > 0 && amount % step != 0 {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    Ok(())
+}
+
 /// Validate that a mint operation doesn't exceed max supply
 pub fn validate_supply_limits(env: &Env, mint_amount: i128) -> Result<(), StablecoinError> {
     if !ENABLE_SUPPLY_LIMITS {
@@ -135,16 +255,73 @@ pub fn validate_supply_limits(env: &Env, mint_amount: i128) -> Result<(), Stable
     let new_supply = current_supply.checked_add(mint_amount)
         .ok_or(StablecoinError::AmountTooLarge)?;
     
-    if new_supply > MAX_SUPPLY {
+    if new_supply > storage::get_max_supply(env) {
         return Err(StablecoinError::ExceedsMaxSupply);
     }
     
     Ok(())
 }
 
-/// Validate that from != to in transfers
-pub fn validate_transfer_addresses(from: &Address, to: &Address) -> Result<(), StablecoinError> {
-    if from == to {
+/// Update the maximum total supply; it must be positive, no higher than MAX_SUPPLY
+/// and no lower than the current supply
+pub fn update_max_supply(env: &Env, max_supply: i128) -> Result<(), StablecoinError> {
+    if max_supply <= 0 || max_supply > MAX_SUPPLY || max_supply < Base::total_supply(env) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_max_supply(env, max_supply);
+    Ok(())
+}
+
+/// Snapshot every admin-tunable setting
+pub fn current_config(env: &Env) -> StablecoinConfig {
+    let (mint_flow_cap, burn_flow_cap) = storage::get_flow_caps(env);
+    StablecoinConfig {
+        max_supply: storage::get_max_supply(env),
+        target_supply: storage::get_target_supply(env),
+        max_batch_size: storage::get_max_batch_size(env),
+        mint_flow_cap,
+        burn_flow_cap,
+        mint_cooldown_ledgers: storage::get_mint_cooldown_ledgers(env),
+        allowance_grace_ledgers: storage::get_allowance_grace_ledgers(env),
+        strict_validation: storage::get_strict_validation(env),
+        allow_self_transfer: storage::get_allow_self_transfer(env),
+        reject_duplicate_mints: storage::get_reject_duplicate_mints(env),
+        role_gated_burns: storage::get_role_gated_burns(env),
+    }
+}
+
+/// Apply a full configuration. Cross-field constraints are checked before anything is
+/// written and any invalid field fails the whole call, so no partial config survives.
+pub fn apply_config(env: &Env, config: &StablecoinConfig) -> Result<(), StablecoinError> {
+    if config.target_supply < 0 || config.target_supply > config.max_supply {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    update_max_supply(env, config.max_supply)?;
+    update_max_batch_size(env, config.max_batch_size)?;
+    configure_flow_caps(env, config.mint_flow_cap, config.burn_flow_cap)?;
+    update_allowance_grace(env, config.allowance_grace_ledgers)?;
+    storage::set_target_supply(env, config.target_supply);
+    storage::set_mint_cooldown_ledgers(env, config.mint_cooldown_ledgers);
+    storage::set_strict_validation(env, config.strict_validation);
+    storage::set_allow_self_transfer(env, config.allow_self_transfer);
+    storage::set_reject_duplicate_mints(env, config.reject_duplicate_mints);
+    storage::set_role_gated_burns(env, config.role_gated_burns);
+    Ok(())
+}
+
+/// Publish a `ConfigChanged` audit event naming the setting `by` just changed
+pub fn emit_config_changed(env: &Env, key: &str, by: &Address) {
+    events::publish(
+        env,
+        (Symbol::new(env, CONFIG_CHANGED_EVENT),),
+        ConfigChanged { key: Symbol::new(env, key), by: by.clone() }
+    );
+}
+
+/// Validate that from != to in transfers, unless self-transfers have been enabled
+pub fn validate_transfer_addresses(env: &Env, from: &Address, to: &Address) -> Result<(), StablecoinError> {
+    if from == to && !storage::get_allow_self_transfer(env) {
         return Err(StablecoinError::SelfTransfer);
     }
     Ok(())
@@ -164,11 +341,296 @@ pub fn validate_balance(env: &Env, address: &Address, required_amount: i128) ->
 /// Validate that a role string is valid
 pub fn validate_role(role: &str) -> Result<(), StablecoinError> {
     match role {
-        MINTER_ROLE | PAUSER_ROLE | UPGRADER_ROLE => Ok(()),
+        MINTER_ROLE | PAUSER_ROLE | UPGRADER_ROLE | BURNER_ROLE | SPENDER_ROLE => Ok(()),
         _ => Err(StablecoinError::InvalidRole),
     }
 }
 
+/// Validate that a role symbol names one of the roles this token recognises
+pub fn validate_role_symbol(env: &Env, role: &Symbol) -> Result<(), StablecoinError> {
+    for known in [MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE, COMPLIANCE_ROLE, BURNER_ROLE, SPENDER_ROLE] {
+        if *role == Symbol::new(env, known) {
+            return Ok(());
+        }
+    }
+    Err(StablecoinError::InvalidRole)
+}
+
+/// Validate that an account may receive a role: accounts with active
+/// compliance flags (frozen or denylisted) cannot be granted any role
+pub fn validate_role_grantee(env: &Env, account: &Address) -> Result<(), StablecoinError> {
+    if storage::is_frozen(env, account) || is_denylisted(env, account) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Validate a batch of role grants as a whole: every role must be known, every grantee
+/// free of compliance flags and no (account, role) pair may appear twice
+pub fn validate_role_grants(env: &Env, grants: &Vec<(Address, Symbol)>) -> Result<(), StablecoinError> {
+    validate_batch_size(env, grants.len())?;
+    
+    for (i, (account, role)) in grants.iter().enumerate() {
+        validate_role_symbol(env, &role)?;
+        validate_role_grantee(env, &account)?;
+        if grants.iter().skip(i + 1).any(|(other, other_role)| other == account && other_role == role) {
+            return Err(StablecoinError::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
+/// List every system address with its labels: the admin, the fee collector and every
+/// member of each known role. An address holding several roles appears once.
+pub fn system_overview(env: &Env) -> Vec<(Address, Vec<Symbol>)> {
+    let mut overview: Vec<(Address, Vec<Symbol>)> = Vec::new(env);
+    let mut label = |address: Address, tag: &str| {
+        let tag = Symbol::new(env, tag);
+        for i in 0..overview.len() {
+            let (existing, mut tags) = overview.get_unchecked(i);
+            if existing == address {
+                tags.push_back(tag);
+                overview.set(i, (existing, tags));
+                return;
+            }
+        }
+        overview.push_back((address, vec![env, tag]));
+    };
+    
+    if let Some(admin) = access_control::get_admin(env) {
+        label(admin, ADMIN_LABEL);
+    }
+    if let Some(config) = storage::get_fee_config(env) {
+        label(config.collector, FEE_COLLECTOR_LABEL);
+    }
+    for role in [MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE, COMPLIANCE_ROLE, BURNER_ROLE, SPENDER_ROLE] {
+        let role_symbol = Symbol::new(env, role);
+        for index in 0..access_control::get_role_member_count(env, &role_symbol) {
+            label(access_control::get_role_member(env, &role_symbol, index), role);
+        }
+    }
+    
+    overview
+}
+
+/// List the labels `account` holds: the admin label, then each known role it is a member of
+pub fn roles_of(env: &Env, account: &Address) -> Vec<Symbol> {
+    let mut roles = Vec::new(env);
+    if access_control::get_admin(env).as_ref() == Some(account) {
+        roles.push_back(Symbol::new(env, ADMIN_LABEL));
+    }
+    for role in [MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE, COMPLIANCE_ROLE, BURNER_ROLE, SPENDER_ROLE] {
+        if access_control::has_role(env, account, &Symbol::new(env, role)).is_some() {
+            roles.push_back(Symbol::new(env, role));
+        }
+    }
+    roles
+}
+
+/// Collect every configured privileged and integration address
+pub fn system_addresses(env: &Env) -> SystemAddresses {
+    SystemAddresses {
+        admin: access_control::get_admin(env),
+        pending_admin: storage::get_pending_admin(env),
+        recovery: storage::get_recovery_config(env).map(|(recovery, _)| recovery),
+        fee_collector: storage::get_fee_config(env).map(|config| config.collector),
+        settlement: storage::get_settlement(env).map(|config| config.contract),
+        denylist_oracle: storage::get_denylist_oracle(env),
+        post_upgrade_hook: storage::get_post_upgrade_hook(env),
+        migration_rescue: storage::get_migration_rescue(env),
+    }
+}
+
+/// Reject calls to an optional feature's methods while its flag is off
+pub fn require_feature(enabled: bool) -> Result<(), StablecoinError> {
+    if !enabled {
+        return Err(StablecoinError::FeatureDisabled);
+    }
+    Ok(())
+}
+
+/// Publish the uniform emergency audit event for an emergency action of `kind` taken by `by`
+pub fn emit_emergency_action(env: &Env, kind: &str, by: &Address) {
+    events::publish(
+        env,
+        (Symbol::new(env, EMERGENCY_ACTION_EVENT), by),
+        EmergencyAction {
+            kind: Symbol::new(env, kind),
+            by: by.clone(),
+            at: env.ledger().sequence(),
+        }
+    );
+}
+
+/// An emergency freeze is a pause taken with allowances frozen
+pub fn is_emergency_frozen(env: &Env) -> bool {
+    pausable::paused(env) && storage::get_allowance_snapshot(env).is_some()
+}
+
+/// Reject a method gated by `role` while paused, unless the role is pause-immune.
+/// No role is immune to an emergency freeze.
+pub fn ensure_not_paused_for_role(env: &Env, role: &str) -> Result<(), StablecoinError> {
+    if !pausable::paused(env) {
+        return Ok(());
+    }
+    if !is_emergency_frozen(env) && storage::get_pause_immune_roles(env).contains(Symbol::new(env, role)) {
+        return Ok(());
+    }
+    Err(StablecoinError::Paused)
+}
+
+/// Validate and store the roles whose methods keep working during a general pause
+pub fn update_pause_immune_roles(env: &Env, roles: &Vec<Symbol>) -> Result<(), StablecoinError> {
+    for role in roles.iter() {
+        validate_role_symbol(env, &role)?;
+    }
+    storage::set_pause_immune_roles(env, roles);
+    Ok(())
+}
+
+/// Summarize the contract state for monitoring: "uninitialized" before initialize,
+/// "emergency" while paused with allowances frozen, "paused" while paused, otherwise "ok"
+pub fn health(env: &Env) -> Symbol {
+    let state = if access_control::get_admin(env).is_none() {
+        HEALTH_UNINITIALIZED
+    } else if is_emergency_frozen(env) {
+        HEALTH_EMERGENCY
+    } else if pausable::paused(env) {
+        HEALTH_PAUSED
+    } else {
+        HEALTH_OK
+    };
+    Symbol::new(env, state)
+}
+
+/// Report the single lifecycle position the contract is in, taking the first state that
+/// applies in precedence order: "uninitialized", "emergency" (paused with allowances
+/// frozen), "paused", "supply_frozen", "recovery_pending" (an admin recovery has been
+/// initiated), "upgrade_locked", otherwise "active"
+pub fn lifecycle_state(env: &Env) -> Symbol {
+    let state = if access_control::get_admin(env).is_none() {
+        LIFECYCLE_UNINITIALIZED
+    } else if is_emergency_frozen(env) {
+        LIFECYCLE_EMERGENCY
+    } else if pausable::paused(env) {
+        LIFECYCLE_PAUSED
+    } else if storage::get_supply_frozen(env) {
+        LIFECYCLE_SUPPLY_FROZEN
+    } else if storage::get_recovery_claimable_at(env).is_some() {
+        LIFECYCLE_RECOVERY_PENDING
+    } else if storage::get_upgrades_locked(env) {
+        LIFECYCLE_UPGRADE_LOCKED
+    } else {
+        LIFECYCLE_ACTIVE
+    };
+    Symbol::new(env, state)
+}
+
+/// ==================== TIMELOCK ====================
+
+/// Hash identifying a timelocked admin action: sha256 of the XDR of `(action, args)`,
+/// where `args` are the guarded method's arguments after the caller
+pub fn action_hash(env: &Env, action: &Symbol, args: Vec<Val>) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(action.clone(), args).to_xdr(env))
+        .to_bytes()
+}
+
+/// Schedule an action for `eta`, which must leave at least the configured delay
+pub fn schedule_action(env: &Env, action_hash: &BytesN<32>, eta: u32) -> Result<(), StablecoinError> {
+    let earliest = env.ledger().sequence().saturating_add(storage::get_timelock_delay(env));
+    if eta < earliest || storage::get_scheduled_action(env, action_hash).is_some() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    storage::set_scheduled_action(env, action_hash, Some(&ScheduledAction { eta, executed: false }));
+    Ok(())
+}
+
+/// Mark a scheduled action as executed once its eta has passed, releasing it to the
+/// guarded method
+pub fn execute_action(env: &Env, action_hash: &BytesN<32>) -> Result<(), StablecoinError> {
+    let Some(mut action) = storage::get_scheduled_action(env, action_hash) else {
+        return Err(StablecoinError::InvalidParameters);
+    };
+    if action.executed {
+        return Err(StablecoinError::DuplicateOperation);
+    }
+    if env.ledger().sequence() < action.eta {
+        return Err(StablecoinError::TimelockNotExpired);
+    }
+    
+    action.executed = true;
+    storage::set_scheduled_action(env, action_hash, Some(&action));
+    Ok(())
+}
+
+/// Cancel a scheduled action that has not been consumed yet
+pub fn cancel_action(env: &Env, action_hash: &BytesN<32>) -> Result<(), StablecoinError> {
+    if storage::get_scheduled_action(env, action_hash).is_none() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_scheduled_action(env, action_hash, None);
+    Ok(())
+}
+
+/// Consume an executed action, so each scheduling authorizes exactly one call
+pub fn consume_executed_action(env: &Env, action_hash: &BytesN<32>) -> Result<(), StablecoinError> {
+    match storage::get_scheduled_action(env, action_hash) {
+        Some(action) if action.executed => {
+            storage::set_scheduled_action(env, action_hash, None);
+            Ok(())
+        }
+        Some(_) => Err(StablecoinError::TimelockNotExpired),
+        None => Err(StablecoinError::Unauthorized),
+    }
+}
+
+/// Guard for sensitive admin methods: with the timelock enabled, the call must match an
+/// executed action. A no-op while ENABLE_TIMELOCK is off.
+pub fn require_timelock(env: &Env, action: &str, args: Vec<Val>) -> Result<(), StablecoinError> {
+    if !ENABLE_TIMELOCK {
+        return Ok(());
+    }
+    consume_timelocked_call(env, action, args)
+}
+
+/// Consume the executed action matching a call to `action` with `args`
+pub fn consume_timelocked_call(env: &Env, action: &str, args: Vec<Val>) -> Result<(), StablecoinError> {
+    consume_executed_action(env, &action_hash(env, &Symbol::new(env, action), args))
+}
+
+/// ==================== STORAGE MIGRATIONS ====================
+
+/// Upgrade the storage layout from `from_version` to STORAGE_VERSION, one known step at a
+/// time. `from_version` must match the stored version, so a migration never runs twice.
+/// Returns the new version.
+pub fn migrate_storage(env: &Env, from_version: u32) -> Result<u32, StablecoinError> {
+    if from_version != storage::get_storage_version(env) || from_version >= STORAGE_VERSION {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let mut version = from_version;
+    while version < STORAGE_VERSION {
+        if version == 1 {
+            migrate_v1_to_v2(env);
+        }
+        version += 1;
+    }
+    
+    storage::set_storage_version(env, version);
+    Ok(version)
+}
+
+/// Version 2 seeds config that version 1 left implicit: the initialization ledger
+/// (best known value: now) and the admin-tunable max supply
+fn migrate_v1_to_v2(env: &Env) {
+    if !storage::has_init_ledger(env) {
+        storage::set_init_ledger(env, env.ledger().sequence());
+    }
+    storage::set_max_supply(env, storage::get_max_supply(env));
+}
+
 /// Validate contract is properly initialized
 pub fn validate_contract_initialized(env: &Env) -> Result<(), StablecoinError> {
     // Check if basic metadata is set
@@ -189,9 +651,52 @@ pub fn validate_mint_comprehensive(env: &Env, to: &Address, amount: i128) -> Res
     validate_contract_initialized(env)?;
     validate_address_comprehensive(env, to)?;
     validate_amount_range(amount)?;
+    validate_precision(env, amount)?;
     
     // Supply limits
+    validate_supply_not_frozen(env)?;
+    validate_no_pending_recovery(env)?;
     validate_supply_limits(env, amount)?;
+    validate_backing(env, amount)?;
+    
+    validate_mint_recipient(env, to)
+}
+
+/// Validate the recipient side of a mint: compliance restrictions and the mint cooldown
+pub fn validate_mint_recipient(env: &Env, to: &Address) -> Result<(), StablecoinError> {
+    // Compliance restrictions on the recipient
+    validate_not_denylisted(env, to)?;
+    validate_allowlisted(env, to)?;
+    if storage::get_block_mint_to_frozen(env) && storage::is_frozen(env, to) {
+        return Err(StablecoinError::AccountFrozen);
+    }
+    
+    // Per-recipient mint cooldown
+    validate_mint_cooldown(env, to)?;
+    
+    Ok(())
+}
+
+/// Validate that total supply is not frozen for an attestation window
+pub fn validate_supply_not_frozen(env: &Env) -> Result<(), StablecoinError> {
+    if storage::get_supply_frozen(env) {
+        return Err(StablecoinError::SupplyFrozen);
+    }
+    Ok(())
+}
+
+/// Validate that the recipient's mint cooldown, if configured, has elapsed
+pub fn validate_mint_cooldown(env: &Env, to: &Address) -> Result<(), StablecoinError> {
+    let cooldown = storage::get_mint_cooldown_ledgers(env);
+    if cooldown == 0 {
+        return Ok(());
+    }
+    
+    if let Some(last) = storage::get_last_mint_ledger(env, to) {
+        if env.ledger().sequence() < last.saturating_add(cooldown) {
+            return Err(StablecoinError::RateLimitExceeded);
+        }
+    }
     
     Ok(())
 }
@@ -205,14 +710,18 @@ pub fn validate_transfer_comprehensive(
 ) -> Result<(), StablecoinError> {
     // Basic validations
     validate_contract_initialized(env)?;
-    validate_address_comprehensive(env, from)?;
-    validate_address_comprehensive(env, to)?;
-    validate_transfer_addresses(from, to)?;
     validate_amount_range(amount)?;
+    validate_precision(env, amount)?;
     
     // Balance validation
     validate_balance(env, from, amount)?;
     
+    // Address, compliance and counterparty rules for the pair, as reported by `can_transact`
+    validate_pair(env, from, to)?;
+    
+    // Lock restrictions on the sender, including held receipts
+    validate_account_restrictions(env, from, amount)?;
+    
     Ok(())
 }
 
@@ -222,10 +731,15 @@ pub fn validate_burn_comprehensive(env: &Env, from: &Address, amount: i128) -> R
     validate_contract_initialized(env)?;
     validate_address_comprehensive(env, from)?;
     validate_amount_range(amount)?;
+    validate_precision(env, amount)?;
+    validate_supply_not_frozen(env)?;
     
     // Balance validation
     validate_balance(env, from, amount)?;
     
+    // Freeze and lock restrictions on the holder
+    validate_account_restrictions(env, from, amount)?;
+    
     Ok(())
 }
 
@@ -242,4 +756,2433 @@ pub fn validate_parameters(parameters: &[&str]) -> Result<(), StablecoinError> {
     }
     
     Ok(())
-} 
\ No newline at end of file
+}
+
+/// ==================== ADMIN & CONFIGURATION ====================
+
+/// Require that the caller is the contract admin and has authorized the call
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), StablecoinError> {
+    caller.require_auth();
+    
+    match access_control::get_admin(env) {
+        Some(admin) if admin == *caller => Ok(()),
+        _ => Err(StablecoinError::Unauthorized),
+    }
+}
+
+/// Require that upgrades are still possible and that the caller holds the upgrader role
+/// and has authorized the call. Once upgrades are locked every caller is refused.
+pub fn require_upgrader(env: &Env, caller: &Address) -> Result<(), StablecoinError> {
+    if storage::get_upgrades_locked(env) {
+        return Err(StablecoinError::Unauthorized);
+    }
+    
+    caller.require_auth();
+    
+    if access_control::has_role(env, caller, &Symbol::new(env, UPGRADER_ROLE)).is_none() {
+        return Err(StablecoinError::Unauthorized);
+    }
+    
+    Ok(())
+}
+
+/// Record a holder's upgrade consent signal and keep the (consent, dissent) tally in sync.
+/// Only accounts holding a balance may signal; a holder can change their signal at any time.
+pub fn record_upgrade_consent(env: &Env, holder: &Address, consent: bool) -> Result<(), StablecoinError> {
+    if Base::balance(env, holder) <= 0 {
+        return Err(StablecoinError::InsufficientBalance);
+    }
+    
+    let previous = storage::get_upgrade_consent(env, holder);
+    if previous == Some(consent) {
+        return Ok(());
+    }
+    
+    let (mut consenting, mut dissenting) = storage::get_upgrade_consent_tally(env);
+    match previous {
+        Some(true) => consenting = consenting.saturating_sub(1),
+        Some(false) => dissenting = dissenting.saturating_sub(1),
+        None => {}
+    }
+    if consent {
+        consenting += 1;
+    } else {
+        dissenting += 1;
+    }
+    
+    storage::set_upgrade_consent(env, holder, consent);
+    storage::set_upgrade_consent_tally(env, (consenting, dissenting));
+    Ok(())
+}
+
+/// Apply the burn policy: when burns are role-gated, the holder must hold the burner
+/// role. The holder's signature is required by `Base::burn` itself.
+pub fn require_burn_policy(env: &Env, from: &Address) -> Result<(), StablecoinError> {
+    if storage::get_role_gated_burns(env)
+        && access_control::has_role(env, from, &Symbol::new(env, BURNER_ROLE)).is_none()
+    {
+        return Err(StablecoinError::Unauthorized);
+    }
+    
+    Ok(())
+}
+
+/// Require that the caller is the admin or holds the compliance role and has authorized the call
+pub fn require_compliance(env: &Env, caller: &Address) -> Result<(), StablecoinError> {
+    caller.require_auth();
+    
+    if access_control::get_admin(env).as_ref() == Some(caller) {
+        return Ok(());
+    }
+    
+    if access_control::has_role(env, caller, &Symbol::new(env, COMPLIANCE_ROLE)).is_some() {
+        return Ok(());
+    }
+    
+    Err(StablecoinError::Unauthorized)
+}
+
+/// Gate for admin and role membership reads: open to anyone unless private roles mode is on,
+/// in which case the caller must be the admin or a compliance officer
+pub fn require_role_reader(env: &Env, caller: &Address) -> Result<(), StablecoinError> {
+    if storage::get_private_roles(env) {
+        require_compliance(env, caller)?;
+    }
+    Ok(())
+}
+
+/// Gate for the caller-less role reads kept for existing integrators: they have no reader
+/// to check, so in private roles mode they fail with `Unauthorized` outright
+pub fn ensure_roles_public(env: &Env) {
+    if storage::get_private_roles(env) {
+        panic_with_error!(env, StablecoinError::Unauthorized);
+    }
+}
+
+/// Require that the caller is the account itself or a compliance officer and has authorized the call
+pub fn require_self_or_compliance(env: &Env, caller: &Address, account: &Address) -> Result<(), StablecoinError> {
+    if caller == account {
+        caller.require_auth();
+        return Ok(());
+    }
+    
+    require_compliance(env, caller)
+}
+
+/// Require that the caller is the account itself or the admin and has authorized the call
+pub fn require_self_or_admin(env: &Env, caller: &Address, account: &Address) -> Result<(), StablecoinError> {
+    if caller == account {
+        caller.require_auth();
+        return Ok(());
+    }
+    
+    require_admin(env, caller)
+}
+
+/// Update the maximum number of entries accepted by a single batch call
+pub fn update_max_batch_size(env: &Env, max_batch_size: u32) -> Result<(), StablecoinError> {
+    if max_batch_size == 0 {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    storage::set_max_batch_size(env, max_batch_size);
+    Ok(())
+}
+
+/// Validate that a batch does not exceed the configured maximum size
+pub fn validate_batch_size(env: &Env, batch_len: u32) -> Result<(), StablecoinError> {
+    if batch_len > storage::get_max_batch_size(env) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    Ok(())
+}
+
+/// ==================== PAUSE CONTROLS ====================
+
+/// Validate that a specific method has not been individually paused
+pub fn ensure_method_not_paused(env: &Env, method: &str) -> Result<(), StablecoinError> {
+    if storage::get_paused_methods(env).contains(Symbol::new(env, method)) {
+        return Err(StablecoinError::Paused);
+    }
+    
+    Ok(())
+}
+
+/// Validate that a transfer to `to` may proceed: while paused, only transfers to the
+/// migration rescue address are allowed, and only when migration mode is on
+pub fn ensure_transfer_not_paused(env: &Env, to: &Address) -> Result<(), StablecoinError> {
+    if pausable::paused(env) && storage::get_migration_rescue(env).as_ref() != Some(to) {
+        return Err(StablecoinError::Paused);
+    }
+    
+    Ok(())
+}
+
+/// Claim this ledger's pause action when only one pause, unpause or method pause change
+/// may be applied per ledger; a second attempt in the same ledger fails
+pub fn claim_pause_action(env: &Env) -> Result<(), StablecoinError> {
+    if !storage::get_single_pause_action(env) {
+        return Ok(());
+    }
+    
+    let ledger = env.ledger().sequence();
+    if storage::get_last_pause_action_ledger(env) == Some(ledger) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_last_pause_action_ledger(env, ledger);
+    Ok(())
+}
+
+/// Validate that balance and supply reads are available, i.e. no read lockdown is active
+pub fn ensure_reads_available(env: &Env) -> Result<(), StablecoinError> {
+    if storage::get_read_lockdown(env) {
+        return Err(StablecoinError::Paused);
+    }
+    
+    Ok(())
+}
+
+/// Pause a single method by name; only the names in PAUSABLE_METHODS are accepted
+pub fn pause_method(env: &Env, method: &Symbol) -> Result<(), StablecoinError> {
+    if !PAUSABLE_METHODS.iter().any(|name| *method == Symbol::new(env, name)) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let mut methods = storage::get_paused_methods(env);
+    
+    if methods.contains(method) {
+        return Err(StablecoinError::Paused);
+    }
+    
+    methods.push_back(method.clone());
+    storage::set_paused_methods(env, &methods);
+    Ok(())
+}
+
+/// Unpause a single method by name
+pub fn unpause_method(env: &Env, method: &Symbol) -> Result<(), StablecoinError> {
+    let mut methods = storage::get_paused_methods(env);
+    
+    let index = methods
+        .first_index_of(method)
+        .ok_or(StablecoinError::NotPaused)?;
+    
+    methods.remove(index);
+    storage::set_paused_methods(env, &methods);
+    Ok(())
+}
+
+
+/// Register a contract to be notified of pause state changes
+pub fn add_pause_subscriber(env: &Env, subscriber: &Address) -> Result<(), StablecoinError> {
+    let mut subscribers = storage::get_pause_subscribers(env);
+    
+    if subscribers.contains(subscriber) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    if subscribers.len() >= MAX_PAUSE_SUBSCRIBERS {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    subscribers.push_back(subscriber.clone());
+    storage::set_pause_subscribers(env, &subscribers);
+    Ok(())
+}
+
+/// Unregister a pause subscriber
+pub fn remove_pause_subscriber(env: &Env, subscriber: &Address) -> Result<(), StablecoinError> {
+    let mut subscribers = storage::get_pause_subscribers(env);
+    
+    let index = subscribers
+        .first_index_of(subscriber)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    
+    subscribers.remove(index);
+    storage::set_pause_subscribers(env, &subscribers);
+    Ok(())
+}
+
+/// Notify every subscriber of a pause state change.
+/// Calls are best-effort: a failing subscriber never blocks the pause itself.
+pub fn notify_pause_subscribers(env: &Env, paused: bool) {
+    for subscriber in storage::get_pause_subscribers(env).iter() {
+        let _ = call_hook(env, &subscriber, PAUSE_CHANGED_HOOK, vec![env, paused.into_val(env)], false);
+    }
+}
+
+/// ==================== BACKING ====================
+
+/// Rescale `amount` from `from_decimals` to `to_decimals`. Downscaling that would drop
+/// a non-zero remainder fails with `InvalidAmount` rather than losing precision.
+pub fn convert_decimals(amount: i128, from_decimals: u32, to_decimals: u32) -> Result<i128, StablecoinError> {
+    if from_decimals <= to_decimals {
+        return 10i128
+            .checked_pow(to_decimals - from_decimals)
+            .and_then(|factor| amount.checked_mul(factor))
+            .ok_or(StablecoinError::AmountTooLarge);
+    }
+    
+    let factor = 10i128
+        .checked_pow(from_decimals - to_decimals)
+        .ok_or(StablecoinError::InvalidAmount)?;
+    if amount % factor != 0 {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    Ok(amount / factor)
+}
+
+/// Convert an amount of the backing asset into token units
+fn backing_to_token_units(env: &Env, amount: i128) -> Result<i128, StablecoinError> {
+    convert_decimals(amount, storage::get_backing_decimals(env), DECIMALS)
+}
+
+/// Record an attested deposit into the fiat reserves. `amount` is in the backing
+/// asset's decimals; the reserve is kept in token units.
+pub fn deposit_backing(env: &Env, amount: i128) -> Result<(), StablecoinError> {
+    validate_amount_range(amount)?;
+    let amount = backing_to_token_units(env, amount)?;
+    
+    let reserve = storage::get_backing_reserve(env)
+        .checked_add(amount)
+        .ok_or(StablecoinError::AmountTooLarge)?;
+    storage::set_backing_reserve(env, reserve);
+    Ok(())
+}
+
+/// Record an attested withdrawal from the fiat reserves. `amount` is in the backing
+/// asset's decimals; the reserve is kept in token units.
+pub fn withdraw_backing(env: &Env, amount: i128) -> Result<(), StablecoinError> {
+    validate_amount_range(amount)?;
+    let amount = backing_to_token_units(env, amount)?;
+    
+    let reserve = storage::get_backing_reserve(env);
+    if amount > reserve {
+        return Err(StablecoinError::InsufficientBalance);
+    }
+    let supply = Base::total_supply(env);
+    if storage::get_strict_backing(env) && reserve - amount < supply {
+        return Err(StablecoinError::Undercollateralized);
+    }
+    if !meets_reserve_ratio(reserve - amount, supply, storage::get_min_reserve_ratio(env))? {
+        return Err(StablecoinError::Undercollateralized);
+    }
+    storage::set_backing_reserve(env, reserve - amount);
+    Ok(())
+}
+
+/// Record the backing configured at genesis: the attested reserve, then the backing mode.
+/// Nothing has been minted yet, so strict mode can always be turned on here.
+pub fn apply_genesis_backing(env: &Env, backing: &GenesisBacking) -> Result<(), StablecoinError> {
+    if backing.reserve < 0 {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    if backing.reserve > 0 {
+        deposit_backing(env, backing.reserve)?;
+    }
+    update_strict_backing(env, backing.strict)
+}
+
+/// Switch strict backing on or off. Strict backing can only be enabled while the
+/// attested reserves already cover the supply, so no unbacked tokens exist under it.
+pub fn update_strict_backing(env: &Env, strict: bool) -> Result<(), StablecoinError> {
+    if strict && storage::get_backing_reserve(env) < Base::total_supply(env) {
+        return Err(StablecoinError::Undercollateralized);
+    }
+    storage::set_strict_backing(env, strict);
+    Ok(())
+}
+
+/// Set the minimum reserve ratio in basis points; 0 stops enforcing it. A ratio can only
+/// be set while the attested reserves already meet it.
+pub fn configure_min_reserve_ratio(env: &Env, ratio_bps: u32) -> Result<(), StablecoinError> {
+    if !meets_reserve_ratio(storage::get_backing_reserve(env), Base::total_supply(env), ratio_bps)? {
+        return Err(StablecoinError::Undercollateralized);
+    }
+    storage::set_min_reserve_ratio(env, ratio_bps);
+    Ok(())
+}
+
+/// Attested reserves as a share of total supply in basis points; `i128::MAX` with no supply
+pub fn current_reserve_ratio_bps(env: &Env) -> i128 {
+    let supply = Base::total_supply(env);
+    if supply == 0 {
+        return i128::MAX;
+    }
+    storage::get_backing_reserve(env)
+        .checked_mul(BPS_DENOMINATOR)
+        .map_or(i128::MAX, |scaled| scaled / supply)
+}
+
+/// Whether `reserve * 10000 >= supply * ratio_bps`, i.e. the reserves meet the ratio
+fn meets_reserve_ratio(reserve: i128, supply: i128, ratio_bps: u32) -> Result<bool, StablecoinError> {
+    if ratio_bps == 0 {
+        return Ok(true);
+    }
+    let required = supply
+        .checked_mul(ratio_bps as i128)
+        .ok_or(StablecoinError::AmountTooLarge)?;
+    let held = reserve
+        .checked_mul(BPS_DENOMINATOR)
+        .ok_or(StablecoinError::AmountTooLarge)?;
+    Ok(held >= required)
+}
+
+/// Validate that minting `mint_amount` keeps the supply covered by attested reserves
+/// under strict backing, and within the minimum reserve ratio when one is set. In
+/// permissive mode unbacked (e.g. treasury pre-) mints are allowed.
+pub fn validate_backing(env: &Env, mint_amount: i128) -> Result<(), StablecoinError> {
+    let strict = storage::get_strict_backing(env);
+    let ratio_bps = storage::get_min_reserve_ratio(env);
+    if !strict && ratio_bps == 0 {
+        return Ok(());
+    }
+    
+    let new_supply = Base::total_supply(env)
+        .checked_add(mint_amount)
+        .ok_or(StablecoinError::AmountTooLarge)?;
+    let reserve = storage::get_backing_reserve(env);
+    if strict && new_supply > reserve {
+        return Err(StablecoinError::Undercollateralized);
+    }
+    if !meets_reserve_ratio(reserve, new_supply, ratio_bps)? {
+        return Err(StablecoinError::Undercollateralized);
+    }
+    Ok(())
+}
+
+/// ==================== EXTERNAL HOOKS ====================
+
+/// Invoke `fn_name` on an external contract without letting it abort this transaction:
+/// a panic, error or unexpected return value is caught. Strict hooks report the failure
+/// as `HookFailed`; best-effort hooks swallow it.
+pub fn call_hook(env: &Env, contract: &Address, fn_name: &str, args: Vec<Val>, strict: bool) -> Result<(), StablecoinError> {
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        contract,
+        &Symbol::new(env, fn_name),
+        args,
+    );
+    
+    match result {
+        Ok(Ok(())) => Ok(()),
+        _ if strict => Err(StablecoinError::HookFailed),
+        _ => Ok(()),
+    }
+}
+
+/// Call the configured post-upgrade hook's `on_upgrade(new_wasm_hash)` so a one-time
+/// migration runs in the same transaction as the upgrade. The call is always strict:
+/// a failed hook reports `HookFailed` and the upgrade reverts with it.
+pub fn run_post_upgrade_hook(env: &Env, new_wasm_hash: &BytesN<32>) -> Result<(), StablecoinError> {
+    let Some(hook) = storage::get_post_upgrade_hook(env) else {
+        return Ok(());
+    };
+    
+    call_hook(env, &hook, POST_UPGRADE_HOOK, vec![env, new_wasm_hash.into_val(env)], true)
+}
+
+/// ==================== BATCH PREVIEW ====================
+
+/// Check every constraint a batch transfer is subject to without mutating state, returning
+/// the first failure: pause state, batch size, duplicate recipients, per-recipient validation
+/// and the sender's spendable balance against the batch total
+pub fn preview_batch_transfer(env: &Env, from: &Address, recipients: &Vec<(Address, i128)>) -> Result<(), StablecoinError> {
+    // Pause state
+    if pausable::paused(env) {
+        return Err(StablecoinError::Paused);
+    }
+    ensure_method_not_paused(env, "batch_transfer")?;
+    
+    validate_batch_size(env, recipients.len())?;
+    
+    let mut seen: Vec<Address> = Vec::new(env);
+    let mut total: i128 = 0;
+    for (to, amount) in recipients.iter() {
+        if seen.contains(&to) {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        
+        // Per-recipient addresses, amount and compliance; balance is checked in aggregate below
+        validate_transfer_comprehensive(env, from, &to, amount)?;
+        
+        total = total.checked_add(amount).ok_or(StablecoinError::AmountTooLarge)?;
+        seen.push_back(to);
+    }
+    
+    if total > spendable_balance(env, from) {
+        return Err(StablecoinError::InsufficientBalance);
+    }
+    
+    Ok(())
+}
+
+/// Check a mint to `to` without performing it, returning the total supply it would produce.
+/// Covers pause state, the supply cap and compliance on the recipient; the caller's role
+/// and minter allowance are not checked since no minter is involved.
+pub fn preview_mint(env: &Env, to: &Address, amount: i128) -> Result<i128, StablecoinError> {
+    ensure_not_paused_for_role(env, MINTER_ROLE)?;
+    ensure_method_not_paused(env, "mint")?;
+    
+    validate_mint_comprehensive(env, to, amount)?;
+    
+    Ok(Base::total_supply(env) + amount)
+}
+
+/// ==================== MINTER ALLOWANCES ====================
+
+/// Debit `amount` from a minter's allowance; minters without an allowance are unlimited
+pub fn consume_minter_allowance(env: &Env, minter: &Address, amount: i128) -> Result<(), StablecoinError> {
+    let Some(remaining) = storage::get_minter_allowance(env, minter) else {
+        return Ok(());
+    };
+    
+    if amount > remaining {
+        return Err(StablecoinError::InsufficientAllowance);
+    }
+    storage::set_minter_allowance(env, minter, Some(remaining - amount));
+    Ok(())
+}
+
+/// Validate that a minter's allowance covers `amount` without debiting it
+pub fn validate_minter_allowance(env: &Env, minter: &Address, amount: i128) -> Result<(), StablecoinError> {
+    match storage::get_minter_allowance(env, minter) {
+        Some(remaining) if amount > remaining => Err(StablecoinError::InsufficientAllowance),
+        _ => Ok(()),
+    }
+}
+
+/// Run every check a single mint is subject to and, only if all pass, debit the
+/// per-ledger flow and the minter's allowance. On error no state has been touched,
+/// so a caller can skip the entry and carry on.
+pub fn admit_mint(env: &Env, minter: &Address, to: &Address, amount: i128) -> Result<(), StablecoinError> {
+    validate_mint_comprehensive(env, to, amount)?;
+    validate_minter_allowance(env, minter, amount)?;
+    consume_mint_flow(env, amount)?;
+    consume_minter_allowance(env, minter, amount)
+}
+
+/// Neutralize a compromised minter and hand its role and remaining allowance to a
+/// replacement: revoke the role, zero the allowance and denylist the compromised key
+pub fn rotate_minter(env: &Env, admin: &Address, compromised: &Address, replacement: &Address) -> Result<(), StablecoinError> {
+    let minter_role = Symbol::new(env, MINTER_ROLE);
+    
+    if compromised == replacement || access_control::has_role(env, compromised, &minter_role).is_none() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    validate_role_grantee(env, replacement)?;
+    
+    // Neutralize the compromised key
+    let allowance = storage::get_minter_allowance(env, compromised);
+    access_control::revoke_role_no_auth(env, admin, compromised, &minter_role);
+    storage::set_minter_allowance(env, compromised, Some(0));
+    if !is_locally_denylisted(env, compromised) {
+        denylist_add(env, compromised)?;
+    }
+    
+    // Activate the replacement, adding the compromised key's remaining allowance to any cap
+    // it already has. Unlimited carries nothing over: a new minter keeps an unset cap unset,
+    // and an existing uncapped minter stays uncapped.
+    let existing_minter = access_control::has_role(env, replacement, &minter_role).is_some();
+    let cap = match (allowance, storage::get_minter_allowance(env, replacement)) {
+        (Some(carried), Some(existing)) => Some(existing.saturating_add(carried)),
+        (Some(carried), None) if !existing_minter => Some(carried),
+        (_, existing) => existing,
+    };
+    if !existing_minter {
+        access_control::grant_role_no_auth(env, admin, replacement, &minter_role);
+    }
+    storage::set_minter_allowance(env, replacement, cap);
+    Ok(())
+}
+
+/// Revoke `role` from `account`. Revoking the last minter, pauser or upgrader would leave
+/// the contract unable to mint, pause or upgrade, so it is rejected unless `force` is set.
+pub fn revoke_role(env: &Env, admin: &Address, account: &Address, role: &Symbol, force: bool) -> Result<(), StablecoinError> {
+    validate_role_symbol(env, role)?;
+    if access_control::has_role(env, account, role).is_none() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let critical = [MINTER_ROLE, PAUSER_ROLE, UPGRADER_ROLE]
+        .iter()
+        .any(|name| *role == Symbol::new(env, name));
+    if critical && !force && access_control::get_role_member_count(env, role) <= 1 {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    access_control::revoke_role_no_auth(env, admin, account, role);
+    Ok(())
+}
+
+/// ==================== FLOW CAPS ====================
+
+/// Configure the protocol-wide per-ledger mint and burn caps; 0 disables a cap
+pub fn configure_flow_caps(env: &Env, mint_cap: i128, burn_cap: i128) -> Result<(), StablecoinError> {
+    if mint_cap < 0 || burn_cap < 0 {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_flow_caps(env, mint_cap, burn_cap);
+    Ok(())
+}
+
+/// Get this ledger's flow so far, starting from zero whenever the ledger has moved on
+pub fn current_ledger_flow(env: &Env) -> LedgerFlow {
+    let ledger = env.ledger().sequence();
+    match storage::get_ledger_flow(env) {
+        Some(flow) if flow.ledger == ledger => flow,
+        _ => LedgerFlow { ledger, minted: 0, burned: 0 },
+    }
+}
+
+/// Room left under a per-ledger cap, or `None` when the cap is disabled
+fn flow_headroom(cap: i128, used: i128) -> Option<i128> {
+    if cap > 0 {
+        Some((cap - used).max(0))
+    } else {
+        None
+    }
+}
+
+/// Collect the limits that apply to `caller`: the per-operation limit, the minter allowance
+/// and per-ledger mint headroom for minters, the per-ledger burn headroom and what the
+/// caller's own balance allows them to move out
+pub fn effective_limits(env: &Env, caller: &Address) -> EffectiveLimits {
+    let (mint_cap, burn_cap) = storage::get_flow_caps(env);
+    let flow = current_ledger_flow(env);
+    
+    let is_minter = access_control::has_role(env, caller, &Symbol::new(env, MINTER_ROLE)).is_some();
+    let (mint_allowance, mint_headroom) = if is_minter {
+        (storage::get_minter_allowance(env, caller), flow_headroom(mint_cap, flow.minted))
+    } else {
+        (Some(0), Some(0))
+    };
+    
+    let outflow_headroom = spendable_balance(env, caller);
+    
+    EffectiveLimits {
+        max_single_operation: if ENABLE_OPERATION_LIMITS { Some(MAX_SINGLE_OPERATION) } else { None },
+        compliance_exempt: is_limit_exempt(env, caller),
+        mint_allowance,
+        mint_headroom,
+        burn_headroom: flow_headroom(burn_cap, flow.burned),
+        outflow_headroom,
+    }
+}
+
+/// The `(min, max)` amounts `caller` can pass to `op` ("mint", "transfer" or "burn") right
+/// now, given the amount range, precision step, role, allowance, per-ledger headroom, supply
+/// and balance limits. A `max` below `min` means the operation cannot currently succeed.
+pub fn operation_bounds(env: &Env, op: &Symbol, caller: &Address) -> Result<(i128, i128), StablecoinError> {
+    let limits = effective_limits(env, caller);
+    let mut max = limits.max_single_operation.unwrap_or(i128::MAX);
+    let mut clamp = |bound: Option<i128>| {
+        if let Some(bound) = bound {
+            max = max.min(bound);
+        }
+    };
+    
+    if *op == Symbol::new(env, "mint") {
+        let supply = Base::total_supply(env);
+        clamp(limits.mint_allowance);
+        clamp(limits.mint_headroom);
+        if ENABLE_SUPPLY_LIMITS {
+            clamp(Some(storage::get_max_supply(env) - supply));
+        }
+        if storage::get_strict_backing(env) {
+            clamp(Some(storage::get_backing_reserve(env) - supply));
+        }
+        let ratio_bps = storage::get_min_reserve_ratio(env);
+        if ratio_bps > 0 {
+            let covered = storage::get_backing_reserve(env).saturating_mul(BPS_DENOMINATOR) / ratio_bps as i128;
+            clamp(Some(covered - supply));
+        }
+        if storage::get_supply_frozen(env) {
+            clamp(Some(0));
+        }
+    } else if *op == Symbol::new(env, "transfer") {
+        clamp(Some(limits.outflow_headroom));
+    } else if *op == Symbol::new(env, "burn") {
+        clamp(limits.burn_headroom);
+        clamp(Some(spendable_balance(env, caller)));
+        if storage::get_supply_frozen(env)
+            || (storage::get_role_gated_burns(env)
+                && access_control::has_role(env, caller, &Symbol::new(env, BURNER_ROLE)).is_none())
+        {
+            clamp(Some(0));
+        }
+    } else {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    // Both bounds must be multiples of the precision step
+    let step = storage::get_precision_step(env);
+    let (mut min, mut max) = (MIN_AMOUNT, max.max(0));
+    if step > 0 {
+        min = (MIN_AMOUNT + step - 1) / step * step;
+        max -= max % step;
+    }
+    Ok((min, max))
+}
+
+/// Count `amount` against this ledger's global mint cap
+pub fn consume_mint_flow(env: &Env, amount: i128) -> Result<(), StablecoinError> {
+    let (mint_cap, _) = storage::get_flow_caps(env);
+    let mut flow = current_ledger_flow(env);
+    flow.minted = flow.minted.checked_add(amount).ok_or(StablecoinError::AmountTooLarge)?;
+    
+    if mint_cap > 0 && flow.minted > mint_cap {
+        return Err(StablecoinError::RateLimitExceeded);
+    }
+    storage::set_ledger_flow(env, &flow);
+    Ok(())
+}
+
+/// Count `amount` against this ledger's global burn cap
+pub fn consume_burn_flow(env: &Env, amount: i128) -> Result<(), StablecoinError> {
+    let (_, burn_cap) = storage::get_flow_caps(env);
+    let mut flow = current_ledger_flow(env);
+    flow.burned = flow.burned.checked_add(amount).ok_or(StablecoinError::AmountTooLarge)?;
+    
+    if burn_cap > 0 && flow.burned > burn_cap {
+        return Err(StablecoinError::RateLimitExceeded);
+    }
+    storage::set_ledger_flow(env, &flow);
+    Ok(())
+}
+
+/// Get the transfer volume in the current window of `VOLUME_WINDOW_LEDGERS` ledgers and the
+/// window's start ledger, starting from zero whenever a new window has begun
+pub fn current_transfer_volume(env: &Env) -> (i128, u32) {
+    let ledger = env.ledger().sequence();
+    let window_start = ledger - ledger % VOLUME_WINDOW_LEDGERS;
+    match storage::get_transfer_volume(env) {
+        Some((volume, start)) if start == window_start => (volume, start),
+        _ => (0, window_start),
+    }
+}
+
+/// Add a transfer to the current window's volume
+fn record_transfer_volume(env: &Env, amount: i128) {
+    let (volume, window_start) = current_transfer_volume(env);
+    storage::set_transfer_volume(env, volume.saturating_add(amount), window_start);
+}
+
+/// ==================== ESCROW ====================
+
+/// Move `amount` from the depositor into contract-held escrow for `beneficiary`,
+/// returning the new escrow's id
+pub fn open_escrow(env: &Env, from: &Address, beneficiary: &Address, amount: i128, release_ledger: u32) -> Result<u64, StablecoinError> {
+    validate_contract_initialized(env)?;
+    validate_address_comprehensive(env, from)?;
+    validate_address_comprehensive(env, beneficiary)?;
+    validate_amount_range(amount)?;
+    
+    if release_ledger <= env.ledger().sequence() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    // Depositor must be able to send the amount; the beneficiary must be able to receive
+    validate_balance(env, from, amount)?;
+    validate_account_restrictions(env, from, amount)?;
+    validate_not_denylisted(env, beneficiary)?;
+    
+    let escrow_account = env.current_contract_address();
+    Base::transfer(env, from, &escrow_account, amount);
+    note_credit(env, &escrow_account, amount);
+    note_debit(env, from, amount);
+    
+    let id = storage::get_next_escrow_id(env);
+    storage::set_escrow(env, id, &Escrow {
+        depositor: from.clone(),
+        beneficiary: beneficiary.clone(),
+        amount,
+        release_ledger,
+    });
+    storage::set_next_escrow_id(env, id + 1);
+    storage::set_total_escrowed(env, storage::get_total_escrowed(env) + amount);
+    Ok(id)
+}
+
+/// Pay an escrow out to its beneficiary once its release ledger has passed
+pub fn release_escrow(env: &Env, id: u64) -> Result<Escrow, StablecoinError> {
+    let escrow = storage::get_escrow(env, id).ok_or(StablecoinError::InvalidParameters)?;
+    
+    if env.ledger().sequence() < escrow.release_ledger {
+        return Err(StablecoinError::TimelockNotExpired);
+    }
+    validate_not_denylisted(env, &escrow.beneficiary)?;
+    
+    pay_out_escrow(env, id, &escrow, &escrow.beneficiary);
+    Ok(escrow)
+}
+
+/// Return an escrow to its depositor before its release ledger (depositor only)
+pub fn refund_escrow(env: &Env, id: u64) -> Result<Escrow, StablecoinError> {
+    let escrow = storage::get_escrow(env, id).ok_or(StablecoinError::InvalidParameters)?;
+    escrow.depositor.require_auth();
+    
+    if env.ledger().sequence() >= escrow.release_ledger {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    pay_out_escrow(env, id, &escrow, &escrow.depositor);
+    Ok(escrow)
+}
+
+fn pay_out_escrow(env: &Env, id: u64, escrow: &Escrow, to: &Address) {
+    let escrow_account = env.current_contract_address();
+    Base::update(env, Some(&escrow_account), Some(to), escrow.amount);
+    note_credit(env, to, escrow.amount);
+    note_debit(env, &escrow_account, escrow.amount);
+    storage::remove_escrow(env, id);
+    storage::set_total_escrowed(env, storage::get_total_escrowed(env) - escrow.amount);
+}
+
+/// ==================== TRANSFER FEES ====================
+
+/// Validate and store a transfer fee configuration; `bps == 0` disables fees
+pub fn configure_fees(env: &Env, bps: u32, collector: &Address, max_fee_absolute: i128) -> Result<(), StablecoinError> {
+    if bps > MAX_FEE_BPS || max_fee_absolute < 0 {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    if bps == 0 {
+        storage::set_fee_config(env, None);
+        return Ok(());
+    }
+    
+    validate_address_comprehensive(env, collector)?;
+    storage::set_fee_config(env, Some(&FeeConfig { bps, collector: collector.clone(), max_fee_absolute }));
+    Ok(())
+}
+
+/// Validate and store a tiered fee schedule; an empty schedule returns to the flat rate.
+/// Thresholds must be positive and strictly ascending, and each rate within MAX_FEE_BPS.
+pub fn configure_fee_tiers(env: &Env, tiers: &Vec<(i128, u32)>) -> Result<(), StablecoinError> {
+    if tiers.len() > MAX_FEE_TIERS {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let mut previous = 0;
+    for (threshold, bps) in tiers.iter() {
+        if threshold <= previous || bps > MAX_FEE_BPS {
+            return Err(StablecoinError::InvalidParameters);
+        }
+        previous = threshold;
+    }
+    
+    storage::set_fee_tiers(env, tiers);
+    Ok(())
+}
+
+/// The fee rate for a transfer of `amount`: the rate of the highest tier whose threshold
+/// `amount` reaches, or the flat rate below the first tier
+fn fee_bps_for(env: &Env, config: &FeeConfig, amount: i128) -> u32 {
+    storage::get_fee_tiers(env)
+        .iter()
+        .take_while(|(threshold, _)| amount >= *threshold)
+        .last()
+        .map_or(config.bps, |(_, bps)| bps)
+}
+
+/// Split `amount` into `(net, fee)` where `fee = min(amount * bps / 10000, max_fee_absolute)`
+/// (no cap when `max_fee_absolute` is 0) and `bps` comes from the amount's tier, if a
+/// tiered schedule is set. `net + fee == amount` always holds.
+pub fn quote_fee(env: &Env, amount: i128) -> (i128, i128) {
+    let Some(config) = storage::get_fee_config(env) else {
+        return (amount, 0);
+    };
+    
+    let bps = fee_bps_for(env, &config, amount);
+    let mut fee = amount.saturating_mul(bps as i128) / BPS_DENOMINATOR;
+    if config.max_fee_absolute > 0 {
+        fee = fee.min(config.max_fee_absolute);
+    }
+    (amount - fee, fee)
+}
+
+/// Split a transfer of `amount` from `from` to `to` into `(net, fee)`, honoring the
+/// collector exemption: transfers to or from the collector are free.
+pub fn quote_transfer(env: &Env, from: &Address, to: &Address, amount: i128) -> (i128, i128) {
+    match storage::get_fee_config(env) {
+        Some(config) if *from != config.collector && *to != config.collector => quote_fee(env, amount),
+        _ => (amount, 0),
+    }
+}
+
+/// Carve the transfer fee out of `amount` once it has reached `to`, moving it to the
+/// collector or burning it, per the fee disposition. Returns the net amount `to` keeps.
+pub fn charge_transfer_fee(env: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+    // Every transfer path settles its fee here, so the volume metric is kept here too
+    record_transfer_volume(env, amount);
+    
+    let (net, fee) = quote_transfer(env, from, to, amount);
+    if let (true, Some(config)) = (fee > 0, storage::get_fee_config(env)) {
+        // Fees are taken from the recipient, never minted; the rent share, if any, goes to
+        // the contract's own reserve
+        let reserve_cut = rent_reserve_cut(env, fee);
+        let supply_before = Base::total_supply(env);
+        if reserve_cut > 0 {
+            let contract = env.current_contract_address();
+            Base::update(env, Some(to), Some(&contract), reserve_cut);
+            storage::set_rent_reserve(env, storage::get_rent_reserve(env) + reserve_cut);
+            note_credit(env, &contract, reserve_cut);
+            
+            events::publish(
+                env,
+                (Symbol::new(env, RENT_RESERVE_EVENT), from),
+                reserve_cut
+            );
+        }
+        
+        // A supply freeze blocks every burn, so fees are routed for its duration
+        let burned = fee - reserve_cut;
+        if storage::get_fee_disposition(env) == FeeDisposition::Burn && !storage::get_supply_frozen(env) {
+            Base::update(env, Some(to), None, burned);
+            storage::set_total_burned(env, storage::get_total_burned(env) + burned);
+            debug_assert_eq!(Base::total_supply(env), supply_before - burned, "fee burning must only remove the fee");
+            
+            events::publish(
+                env,
+                (Symbol::new(env, FEE_BURNED_EVENT), from),
+                burned
+            );
+        } else {
+            Base::update(env, Some(to), Some(&config.collector), burned);
+            debug_assert_eq!(Base::total_supply(env), supply_before, "fee routing must conserve total supply");
+            note_credit(env, &config.collector, burned);
+            
+            events::publish(
+                env,
+                (Symbol::new(env, FEE_EVENT), from, &config.collector),
+                burned
+            );
+        }
+    }
+    net
+}
+
+/// ==================== TTL MAINTENANCE ====================
+
+/// Validate an automatic TTL maintenance configuration; `None` (disabled) is always valid
+pub fn validate_ttl_maintenance(env: &Env, config: Option<&TtlMaintenance>) -> Result<(), StablecoinError> {
+    if let Some(config) = config {
+        if config.every_ops == 0
+            || config.extend_to == 0
+            || config.extend_to > env.storage().max_ttl()
+            || config.fee_share_bps as i128 > BPS_DENOMINATOR
+        {
+            return Err(StablecoinError::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
+/// Validate and store the automatic TTL maintenance configuration; `None` disables it.
+/// The operation count restarts from the current operation.
+pub fn update_ttl_maintenance(env: &Env, config: Option<TtlMaintenance>) -> Result<(), StablecoinError> {
+    validate_ttl_maintenance(env, config.as_ref())?;
+    
+    storage::set_ttl_maintenance(env, config.as_ref());
+    storage::set_last_ttl_extension_op(env, storage::get_op_seq(env));
+    Ok(())
+}
+
+/// Portion of a transfer fee set aside for rent
+fn rent_reserve_cut(env: &Env, fee: i128) -> i128 {
+    match storage::get_ttl_maintenance(env) {
+        Some(config) => fee.saturating_mul(config.fee_share_bps as i128) / BPS_DENOMINATOR,
+        None => 0,
+    }
+}
+
+/// Extend the instance storage, code and shared persistent entry TTLs once `every_ops`
+/// operations have passed since the last extension, so an active contract keeps itself
+/// alive without per-call overhead
+fn maybe_extend_ttl(env: &Env, op_seq: u64) {
+    let Some(config) = storage::get_ttl_maintenance(env) else {
+        return;
+    };
+    
+    if op_seq < storage::get_last_ttl_extension_op(env).saturating_add(config.every_ops as u64) {
+        return;
+    }
+    
+    env.storage().instance().extend_ttl(config.extend_to, config.extend_to);
+    storage::extend_shared_persistent_ttl(env, config.extend_to);
+    storage::set_last_ttl_extension_op(env, op_seq);
+}
+
+/// Pay `amount` out of the rent reserve to `to`, e.g. to reimburse whoever paid the rent
+pub fn withdraw_rent_reserve(env: &Env, to: &Address, amount: i128) -> Result<(), StablecoinError> {
+    validate_address_comprehensive(env, to)?;
+    validate_not_denylisted(env, to)?;
+    if storage::is_frozen(env, to) {
+        return Err(StablecoinError::AccountFrozen);
+    }
+    if amount <= 0 {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    
+    let reserve = storage::get_rent_reserve(env);
+    if amount > reserve {
+        return Err(StablecoinError::InsufficientBalance);
+    }
+    
+    let contract = env.current_contract_address();
+    Base::update(env, Some(&contract), Some(to), amount);
+    storage::set_rent_reserve(env, reserve - amount);
+    note_credit(env, to, amount);
+    note_debit(env, &contract, amount);
+    Ok(())
+}
+
+/// ==================== REDEMPTION SETTLEMENT ====================
+
+/// Call the configured settlement contract's `settle_redemption(from, amount)` after a burn.
+/// In strict mode a failed call is reported so the burn reverts; otherwise it is ignored.
+pub fn settle_redemption(env: &Env, from: &Address, amount: i128) -> Result<(), StablecoinError> {
+    let Some(config) = storage::get_settlement(env) else {
+        return Ok(());
+    };
+    
+    call_hook(
+        env,
+        &config.contract,
+        SETTLEMENT_HOOK,
+        vec![env, from.into_val(env), amount.into_val(env)],
+        config.strict,
+    )
+    .map_err(|_| StablecoinError::SettlementFailed)
+}
+
+/// ==================== ACCOUNT RESTRICTIONS ====================
+
+/// Lock part of an account's balance until `release_ledger`
+pub fn add_lock(env: &Env, account: &Address, amount: i128, release_ledger: u32) -> Result<(), StablecoinError> {
+    if amount <= 0 {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    
+    if release_ledger <= env.ledger().sequence() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    // Released locks still occupy a slot until consolidated
+    let mut locks = storage::get_locks(env, account);
+    if locks.len() >= storage::get_max_locks_per_account(env) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    locks.push_back(BalanceLock { amount, release_ledger });
+    storage::set_locks(env, account, &locks);
+    storage::set_total_locked(env, storage::get_total_locked(env).saturating_add(amount));
+    Ok(())
+}
+
+/// Hold `amount` just received by `account` for the holding period as a lock tranche, so
+/// only the received tokens are held. Released tranches are dropped first and receipts
+/// releasing on the same ledger share a tranche; with every slot taken the receipt joins
+/// the latest-releasing lock. The contract itself and the fee collector are never held.
+fn hold_received(env: &Env, account: &Address, amount: i128) {
+    let period = storage::get_hold_period_ledgers(env);
+    if period == 0 || amount <= 0 || *account == env.current_contract_address() {
+        return;
+    }
+    if storage::get_fee_config(env).is_some_and(|config| config.collector == *account) {
+        return;
+    }
+    
+    consolidate_locks(env, account);
+    let release_ledger = env.ledger().sequence().saturating_add(period);
+    let mut locks = storage::get_locks(env, account);
+    
+    let shared = locks.iter().position(|lock| lock.release_ledger == release_ledger);
+    let latest = locks.iter().enumerate().max_by_key(|(_, lock)| lock.release_ledger).map(|(i, _)| i);
+    match (shared, latest) {
+        (Some(i), _) => {
+            let mut lock = locks.get_unchecked(i as u32);
+            lock.amount = lock.amount.saturating_add(amount);
+            locks.set(i as u32, lock);
+        }
+        (None, Some(i)) if locks.len() >= storage::get_max_locks_per_account(env) => {
+            let mut lock = locks.get_unchecked(i as u32);
+            lock.amount = lock.amount.saturating_add(amount);
+            lock.release_ledger = lock.release_ledger.max(release_ledger);
+            locks.set(i as u32, lock);
+        }
+        _ => locks.push_back(BalanceLock { amount, release_ledger }),
+    }
+    
+    storage::set_locks(env, account, &locks);
+    storage::set_total_locked(env, storage::get_total_locked(env).saturating_add(amount));
+}
+
+/// Drop an account's released locks to free their slots; returns how many were removed
+pub fn consolidate_locks(env: &Env, account: &Address) -> u32 {
+    let current_ledger = env.ledger().sequence();
+    let locks = storage::get_locks(env, account);
+    
+    let mut active = Vec::new(env);
+    let mut released = 0i128;
+    for lock in locks.iter() {
+        if lock.release_ledger > current_ledger {
+            active.push_back(lock);
+        } else {
+            released = released.saturating_add(lock.amount);
+        }
+    }
+    
+    let removed = locks.len() - active.len();
+    if removed > 0 {
+        storage::set_locks(env, account, &active);
+        storage::set_total_locked(env, storage::get_total_locked(env).saturating_sub(released));
+    }
+    removed
+}
+
+/// Sum of an account's locks that have not yet been released, plus any unvested
+/// portion of a genesis treasury mint
+pub fn locked_balance(env: &Env, account: &Address) -> i128 {
+    let current_ledger = env.ledger().sequence();
+    
+    let locked = storage::get_locks(env, account)
+        .iter()
+        .filter(|lock| lock.release_ledger > current_ledger)
+        .fold(0i128, |total, lock| total.saturating_add(lock.amount));
+    
+    match storage::get_vesting(env, account) {
+        Some(schedule) => locked.saturating_add(schedule.total - vested_amount(env, account)),
+        None => locked,
+    }
+}
+
+/// Amount of an account's genesis treasury mint that has vested so far; 0 without a schedule
+pub fn vested_amount(env: &Env, account: &Address) -> i128 {
+    let Some(schedule) = storage::get_vesting(env, account) else {
+        return 0;
+    };
+    
+    let now = env.ledger().sequence();
+    if now < schedule.cliff_ledger {
+        return 0;
+    }
+    if now >= schedule.end_ledger {
+        return schedule.total;
+    }
+    
+    let elapsed = (now - schedule.start_ledger) as i128;
+    let duration = (schedule.end_ledger - schedule.start_ledger) as i128;
+    schedule.total * elapsed / duration
+}
+
+/// Mint the genesis supply to a treasury, vesting it over `duration_ledgers` from now with
+/// nothing transferable before `cliff_ledgers` have passed
+pub fn mint_vested_treasury(
+    env: &Env,
+    treasury: &Address,
+    amount: i128,
+    cliff_ledgers: u32,
+    duration_ledgers: u32,
+) -> Result<(), StablecoinError> {
+    if cliff_ledgers > duration_ledgers {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    validate_mint_comprehensive(env, treasury, amount)?;
+    
+    let start_ledger = env.ledger().sequence();
+    storage::set_vesting(env, treasury, &VestingSchedule {
+        total: amount,
+        start_ledger,
+        cliff_ledger: start_ledger.saturating_add(cliff_ledgers),
+        end_ledger: start_ledger.saturating_add(duration_ledgers),
+    });
+    
+    Base::mint(env, treasury, amount);
+    note_mint(env, treasury, amount);
+    
+    events::publish(
+        env,
+        (Symbol::new(env, MINT_EVENT), treasury),
+        amount
+    );
+    Ok(())
+}
+
+/// Amount an account can actually send right now after every restriction
+pub fn spendable_balance(env: &Env, account: &Address) -> i128 {
+    if storage::is_frozen(env, account) || is_denylisted_cached(env, account) {
+        return 0;
+    }
+    
+    let balance = Base::balance(env, account);
+    balance.saturating_sub(locked_balance(env, account)).max(0)
+}
+
+/// Estimate the storage entries kept for an account: the custom entries plus the
+/// token balance and one `Base` allowance entry per indexed spender. Diagnostic only.
+pub fn account_storage_footprint(env: &Env, account: &Address) -> u32 {
+    let mut count = storage::account_entry_count(env, account);
+    if Base::balance(env, account) > 0 {
+        count += 1;
+    }
+    count + storage::get_spenders(env, account).len()
+}
+
+/// Collect every compliance restriction on an account into a single report
+pub fn account_compliance(env: &Env, account: &Address) -> AccountCompliance {
+    AccountCompliance {
+        frozen: storage::is_frozen(env, account),
+        denylisted: is_denylisted_cached(env, account),
+        allowlisted: !storage::get_allowlist_mode(env) || storage::is_allowlisted(env, account),
+        outgoing_restricted: !storage::get_allowed_counterparties(env, account).is_empty(),
+        locked: locked_balance(env, account),
+        spendable: spendable_balance(env, account),
+    }
+}
+
+/// Validate that `from` may transfer to `to` as far as the pair is concerned: address
+/// checks, denylist and freeze state, the recipient allowlist and the sender's
+/// counterparty restrictions. Balances and amounts are not considered.
+pub fn validate_pair(env: &Env, from: &Address, to: &Address) -> Result<(), StablecoinError> {
+    validate_pair_with(env, from, to, is_denylisted)
+}
+
+/// The `can_transact` form of `validate_pair`: the same rules, but oracle answers are only
+/// read from the cache, so the query never calls the oracle or writes to storage
+pub fn validate_pair_cached(env: &Env, from: &Address, to: &Address) -> Result<(), StablecoinError> {
+    validate_pair_with(env, from, to, is_denylisted_cached)
+}
+
+/// Apply the pair rules with the given denylist lookup
+fn validate_pair_with(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    denylisted: fn(&Env, &Address) -> bool,
+) -> Result<(), StablecoinError> {
+    validate_address_comprehensive(env, from)?;
+    validate_address_comprehensive(env, to)?;
+    validate_transfer_addresses(env, from, to)?;
+    
+    if denylisted(env, from) {
+        return Err(StablecoinError::AccountDenylisted);
+    }
+    if storage::is_frozen(env, from) {
+        return Err(StablecoinError::AccountFrozen);
+    }
+    if denylisted(env, to) {
+        return Err(StablecoinError::AccountDenylisted);
+    }
+    validate_allowlisted(env, to)?;
+    
+    validate_counterparty(env, from, to)
+}
+
+/// Validate that an account is allowed to move `amount` out of its balance
+pub fn validate_account_restrictions(env: &Env, account: &Address, amount: i128) -> Result<(), StablecoinError> {
+    validate_not_denylisted(env, account)?;
+    
+    if storage::is_frozen(env, account) {
+        return Err(StablecoinError::AccountFrozen);
+    }
+    
+    if amount > spendable_balance(env, account) {
+        return Err(StablecoinError::InsufficientBalance);
+    }
+    
+    Ok(())
+}
+
+/// ==================== FROZEN SUPPLY ====================
+
+/// Freeze an account, moving its current balance into the frozen supply total
+pub fn freeze(env: &Env, account: &Address) {
+    if storage::is_frozen(env, account) {
+        return;
+    }
+    storage::set_frozen(env, account, true);
+    adjust_frozen_supply(env, Base::balance(env, account));
+}
+
+/// Unfreeze an account, moving its current balance back out of the frozen supply total
+pub fn unfreeze(env: &Env, account: &Address) {
+    if !storage::is_frozen(env, account) {
+        return;
+    }
+    storage::set_frozen(env, account, false);
+    adjust_frozen_supply(env, -Base::balance(env, account));
+}
+
+/// Account for tokens credited to an account (mint or incoming transfer). Frozen
+/// accounts may still receive, so their frozen total has to grow with them.
+pub fn note_credit(env: &Env, to: &Address, amount: i128) {
+    if storage::is_frozen(env, to) {
+        adjust_frozen_supply(env, amount);
+    }
+    if storage::is_supply_excluded(env, to) {
+        adjust_excluded_supply(env, amount);
+    }
+    hold_received(env, to, amount);
+    track_holder(env, to);
+    check_balance_alert(env, to);
+}
+
+/// Account for tokens debited from an account (transfer, burn or clawback)
+pub fn note_debit(env: &Env, from: &Address, amount: i128) {
+    if storage::is_supply_excluded(env, from) {
+        adjust_excluded_supply(env, -amount);
+    }
+    track_holder(env, from);
+    check_balance_alert(env, from);
+}
+
+/// Configure a low balance alert for an account; a zero threshold removes it. The alert
+/// starts armed only if the balance is currently at or above the threshold.
+pub fn configure_balance_alert(env: &Env, account: &Address, threshold: i128) -> Result<(), StablecoinError> {
+    if threshold < 0 {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    if threshold == 0 {
+        storage::set_balance_alert(env, account, None);
+        return Ok(());
+    }
+    
+    let armed = Base::balance(env, account) >= threshold;
+    storage::set_balance_alert(env, account, Some(&BalanceAlert { threshold, armed }));
+    Ok(())
+}
+
+/// Fire an armed alert once the balance drops below its threshold, and re-arm a fired
+/// alert once the balance is back at or above it
+fn check_balance_alert(env: &Env, account: &Address) {
+    let Some(mut alert) = storage::get_balance_alert(env, account) else {
+        return;
+    };
+    
+    let balance = Base::balance(env, account);
+    let below = balance < alert.threshold;
+    if below != alert.armed {
+        return;
+    }
+    
+    alert.armed = !below;
+    storage::set_balance_alert(env, account, Some(&alert));
+    if below {
+        events::publish(
+            env,
+            (Symbol::new(env, LOW_BALANCE_EVENT), account),
+            LowBalance { account: account.clone(), balance }
+        );
+    }
+}
+
+/// Publish the finalized-burn event for a burn of `amount` from `from` initiated by `by`.
+/// Burns initiated by the admin, a burner or compliance are protocol burns, including
+/// reductions of their own (treasury) balance; every other burn is a user burn.
+pub fn publish_finalized_burn(env: &Env, from: &Address, by: &Address, amount: i128) {
+    let protocol = access_control::get_admin(env).as_ref() == Some(by)
+        || access_control::has_role(env, by, &Symbol::new(env, BURNER_ROLE)).is_some()
+        || access_control::has_role(env, by, &Symbol::new(env, COMPLIANCE_ROLE)).is_some();
+    
+    if protocol {
+        events::publish(
+            env,
+            (Symbol::new(env, PROTOCOL_BURN_EVENT), from),
+            ProtocolBurn { amount, by: by.clone() }
+        );
+    } else {
+        events::publish(
+            env,
+            (Symbol::new(env, USER_BURN_EVENT), from),
+            UserBurn { from: from.clone(), amount }
+        );
+    }
+}
+
+/// Bring an account's holder mark in line with its balance, adjusting the active
+/// holders total on any change. Idempotent, so it is also safe for backfills.
+pub fn track_holder(env: &Env, account: &Address) -> bool {
+    let holds = Base::balance(env, account) > 0;
+    if holds == storage::is_holder(env, account) {
+        return false;
+    }
+    
+    storage::set_holder(env, account, holds);
+    let count = storage::get_active_holders(env);
+    storage::set_active_holders(env, if holds { count + 1 } else { count.saturating_sub(1) });
+    true
+}
+
+/// Add accounts holding a balance from before holder tracking to the holder index,
+/// returning how many were newly counted
+pub fn backfill_holder_index(env: &Env, accounts: &Vec<Address>) -> u32 {
+    let mut added = 0;
+    for account in accounts.iter() {
+        if Base::balance(env, &account) > 0 && track_holder(env, &account) {
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Account for newly minted tokens
+pub fn note_mint(env: &Env, to: &Address, amount: i128) {
+    note_credit(env, to, amount);
+    record_recent_op(env, MINT_EVENT, None, Some(to), amount);
+    storage::set_total_minted(env, storage::get_total_minted(env) + amount);
+    if storage::get_mint_cooldown_ledgers(env) > 0 {
+        storage::set_last_mint_ledger(env, to, env.ledger().sequence());
+    }
+}
+
+/// Account for burned tokens
+pub fn note_burn(env: &Env, from: &Address, amount: i128) {
+    note_debit(env, from, amount);
+    record_recent_op(env, BURN_EVENT, Some(from), None, amount);
+    storage::set_total_burned(env, storage::get_total_burned(env) + amount);
+}
+
+/// Claw back tokens from a frozen account, burning them without the holder's authorization
+pub fn clawback_frozen(env: &Env, caller: &Address, from: &Address, amount: i128) -> Result<(), StablecoinError> {
+    validate_amount_for(env, caller, amount)?;
+    validate_supply_not_frozen(env)?;
+    
+    if !storage::is_frozen(env, from) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    validate_balance(env, from, amount)?;
+    
+    Base::update(env, Some(from), None, amount);
+    adjust_frozen_supply(env, -amount);
+    note_burn(env, from, amount);
+    Ok(())
+}
+
+/// Report (active_supply, frozen_supply)
+pub fn supply_breakdown(env: &Env) -> (i128, i128) {
+    let frozen = storage::get_frozen_supply(env);
+    (Base::total_supply(env) - frozen, frozen)
+}
+
+fn adjust_frozen_supply(env: &Env, delta: i128) {
+    if delta != 0 {
+        storage::set_frozen_supply(env, storage::get_frozen_supply(env) + delta);
+    }
+}
+
+/// Exclude an account's holdings from the circulating supply, or include them again,
+/// moving its current balance into or out of the excluded total
+pub fn set_supply_excluded(env: &Env, account: &Address, excluded: bool) {
+    if storage::is_supply_excluded(env, account) == excluded {
+        return;
+    }
+    storage::set_supply_excluded(env, account, excluded);
+    let balance = Base::balance(env, account);
+    adjust_excluded_supply(env, if excluded { balance } else { -balance });
+}
+
+/// Total supply less the balances of excluded accounts (treasury, sinks, escrow)
+pub fn circulating_supply(env: &Env) -> i128 {
+    Base::total_supply(env) - storage::get_excluded_supply(env)
+}
+
+fn adjust_excluded_supply(env: &Env, delta: i128) {
+    if delta != 0 {
+        storage::set_excluded_supply(env, storage::get_excluded_supply(env) + delta);
+    }
+}
+
+/// ==================== ADMIN RECOVERY ====================
+
+/// Configure the recovery address and the timelock it must wait out
+pub fn configure_recovery(env: &Env, recovery: &Address, delay_ledgers: u32) -> Result<(), StablecoinError> {
+    validate_address_comprehensive(env, recovery)?;
+    
+    if delay_ledgers < MIN_RECOVERY_DELAY_LEDGERS {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    storage::set_recovery_config(env, recovery, delay_ledgers);
+    Ok(())
+}
+
+/// Add an address allowed to initiate recovery
+pub fn add_guardian(env: &Env, guardian: &Address) -> Result<(), StablecoinError> {
+    let mut guardians = storage::get_guardians(env);
+    
+    if guardians.contains(guardian) || guardians.len() >= MAX_GUARDIANS {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    guardians.push_back(guardian.clone());
+    storage::set_guardians(env, &guardians);
+    Ok(())
+}
+
+/// Remove an address allowed to initiate recovery
+pub fn remove_guardian(env: &Env, guardian: &Address) -> Result<(), StablecoinError> {
+    let mut guardians = storage::get_guardians(env);
+    
+    let index = guardians
+        .first_index_of(guardian)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    
+    guardians.remove(index);
+    storage::set_guardians(env, &guardians);
+    Ok(())
+}
+
+/// Start the recovery timelock, returning the ledger at which it becomes claimable
+pub fn initiate_recovery(env: &Env, guardian: &Address) -> Result<u32, StablecoinError> {
+    guardian.require_auth();
+    
+    if !storage::get_guardians(env).contains(guardian) {
+        return Err(StablecoinError::Unauthorized);
+    }
+    
+    let (_, delay_ledgers) = storage::get_recovery_config(env)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    
+    if storage::get_recovery_claimable_at(env).is_some() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let claimable_at = env.ledger().sequence().saturating_add(delay_ledgers);
+    storage::set_recovery_claimable_at(env, Some(claimable_at));
+    Ok(claimable_at)
+}
+
+/// Validate that no admin recovery is pending when privileged operations are restricted
+/// during recovery. A pending recovery signals a possibly compromised admin.
+pub fn validate_no_pending_recovery(env: &Env) -> Result<(), StablecoinError> {
+    if storage::get_restrict_during_recovery(env) && storage::get_recovery_claimable_at(env).is_some() {
+        return Err(StablecoinError::RecoveryPending);
+    }
+    Ok(())
+}
+
+/// Hand the admin role to the recovery address once the timelock has expired
+pub fn claim_recovered_admin(env: &Env, recovery: &Address) -> Result<(), StablecoinError> {
+    recovery.require_auth();
+    
+    let (configured, _) = storage::get_recovery_config(env)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    
+    if configured != *recovery {
+        return Err(StablecoinError::Unauthorized);
+    }
+    
+    let claimable_at = storage::get_recovery_claimable_at(env)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    
+    if env.ledger().sequence() < claimable_at {
+        return Err(StablecoinError::TimelockNotExpired);
+    }
+    
+    // The previous admin cannot sign the library's two-step transfer, so its entry is
+    // vacated and the recovery address seated through `set_admin`; any pending
+    // nomination dies with the old admin
+    env.storage().instance().remove(&AccessControlStorageKey::Admin);
+    access_control::set_admin(env, recovery);
+    storage::set_pending_admin(env, None);
+    storage::set_recovery_claimable_at(env, None);
+    Ok(())
+}
+
+/// Start a two-step admin transfer through the access control library, which requires the
+/// current admin's authorization. The nomination stays open as long as storage allows;
+/// a new one replaces it.
+pub fn nominate_admin(env: &Env, new_admin: &Address) {
+    access_control::transfer_admin_role(env, new_admin, env.ledger().max_live_until_ledger());
+    storage::set_pending_admin(env, Some(new_admin));
+}
+
+/// Complete a two-step admin transfer: the nominated admin accepts the role through the
+/// access control library, which authenticates them
+pub fn accept_pending_admin(env: &Env, new_admin: &Address) -> Result<(), StablecoinError> {
+    if storage::get_pending_admin(env).as_ref() != Some(new_admin) {
+        return Err(StablecoinError::Unauthorized);
+    }
+    
+    access_control::accept_admin_transfer(env);
+    storage::set_pending_admin(env, None);
+    Ok(())
+}
+
+/// Check whether `account` is the current admin
+pub fn is_admin(env: &Env, account: &Address) -> bool {
+    access_control::get_admin(env).as_ref() == Some(account)
+}
+
+/// ==================== COUNTERPARTY RESTRICTIONS ====================
+
+/// Approve a counterparty the account may transfer to
+pub fn add_allowed_counterparty(env: &Env, account: &Address, counterparty: &Address) -> Result<(), StablecoinError> {
+    let mut counterparties = storage::get_allowed_counterparties(env, account);
+    
+    if counterparties.contains(counterparty) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    if counterparties.len() >= MAX_ALLOWED_COUNTERPARTIES {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    counterparties.push_back(counterparty.clone());
+    storage::set_allowed_counterparties(env, account, &counterparties);
+    Ok(())
+}
+
+/// Revoke a previously approved counterparty
+pub fn remove_allowed_counterparty(env: &Env, account: &Address, counterparty: &Address) -> Result<(), StablecoinError> {
+    let mut counterparties = storage::get_allowed_counterparties(env, account);
+    
+    let index = counterparties
+        .first_index_of(counterparty)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    
+    counterparties.remove(index);
+    storage::set_allowed_counterparties(env, account, &counterparties);
+    Ok(())
+}
+
+/// Validate that `to` is an allowed counterparty of `from`. An empty list means unrestricted.
+pub fn validate_counterparty(env: &Env, from: &Address, to: &Address) -> Result<(), StablecoinError> {
+    let counterparties = storage::get_allowed_counterparties(env, from);
+    
+    if !counterparties.is_empty() && !counterparties.contains(to) {
+        return Err(StablecoinError::CounterpartyNotAllowed);
+    }
+    
+    Ok(())
+}
+
+/// ==================== DENYLIST ====================
+
+/// Check whether an account is denylisted, either locally or by the sanctions oracle
+pub fn is_denylisted(env: &Env, account: &Address) -> bool {
+    is_locally_denylisted(env, account) || is_sanctioned(env, account)
+}
+
+/// Check whether an account is on this contract's own denylist
+pub fn is_locally_denylisted(env: &Env, account: &Address) -> bool {
+    storage::get_denylist_position(env, account).is_some()
+}
+
+/// Ask the configured sanctions oracle whether an account is sanctioned, reusing an answer
+/// fetched within the last SANCTIONS_CACHE_TTL_LEDGERS. A failing oracle falls back to the
+/// last answer still held for the account, or sanctions it if there is none. Failures are
+/// not cached: a refused call rolls back its writes, so the oracle is simply asked again.
+pub fn is_sanctioned(env: &Env, account: &Address) -> bool {
+    let Some(oracle) = storage::get_denylist_oracle(env) else {
+        return false;
+    };
+    
+    let cached = storage::get_sanctions_cache(env, &oracle, account);
+    if let Some((sanctioned, fresh_until)) = cached {
+        if env.ledger().sequence() < fresh_until {
+            return sanctioned;
+        }
+    }
+    
+    let result = env.try_invoke_contract::<bool, soroban_sdk::Error>(
+        &oracle,
+        &Symbol::new(env, SANCTIONS_HOOK),
+        vec![env, account.into_val(env)],
+    );
+    match result {
+        Ok(Ok(sanctioned)) => {
+            storage::set_sanctions_cache(env, &oracle, account, sanctioned, SANCTIONS_CACHE_TTL_LEDGERS);
+            sanctioned
+        }
+        _ => cached.map(|(sanctioned, _)| sanctioned).unwrap_or(true),
+    }
+}
+
+/// Check the denylist without calling out: local entries plus any oracle answer still
+/// held. Read paths use this so they never invoke the oracle per account.
+pub fn is_denylisted_cached(env: &Env, account: &Address) -> bool {
+    if is_locally_denylisted(env, account) {
+        return true;
+    }
+    storage::get_denylist_oracle(env)
+        .and_then(|oracle| storage::get_sanctions_cache(env, &oracle, account))
+        .is_some_and(|(sanctioned, _)| sanctioned)
+}
+
+/// Validate that an account is not denylisted
+pub fn validate_not_denylisted(env: &Env, account: &Address) -> Result<(), StablecoinError> {
+    if is_denylisted(env, account) {
+        return Err(StablecoinError::AccountDenylisted);
+    }
+    
+    Ok(())
+}
+
+/// Validate that a recipient is allowlisted when allowlist mode is on
+pub fn validate_allowlisted(env: &Env, account: &Address) -> Result<(), StablecoinError> {
+    if storage::get_allowlist_mode(env) && !storage::is_allowlisted(env, account) {
+        return Err(StablecoinError::RecipientNotAllowlisted);
+    }
+    Ok(())
+}
+
+/// Add an account to the denylist, appending it to the dense index
+pub fn denylist_add(env: &Env, account: &Address) -> Result<(), StablecoinError> {
+    if is_locally_denylisted(env, account) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let count = storage::get_denylist_count(env);
+    storage::set_denylist_entry(env, count, account);
+    storage::set_denylist_position(env, account, count);
+    storage::set_denylist_count(env, count + 1);
+    Ok(())
+}
+
+/// Map a compliance action symbol to the event it emits, rejecting unknown actions
+pub fn compliance_action_event(env: &Env, action: &Symbol) -> Result<&'static str, StablecoinError> {
+    let events = [
+        (FREEZE_ACTION, FREEZE_EVENT),
+        (UNFREEZE_ACTION, UNFREEZE_EVENT),
+        (DENYLIST_ACTION, DENYLIST_ADD_EVENT),
+        (ALLOW_ACTION, DENYLIST_REMOVE_EVENT),
+    ];
+    for (name, event) in events {
+        if *action == Symbol::new(env, name) {
+            return Ok(event);
+        }
+    }
+    Err(StablecoinError::InvalidParameters)
+}
+
+/// Apply a batch of compliance actions, emitting one event per action. Every action
+/// symbol is checked before anything is applied, so an unknown one rejects the batch.
+pub fn apply_compliance_actions(env: &Env, actions: &Vec<(Address, Symbol)>) -> Result<(), StablecoinError> {
+    for (_, action) in actions.iter() {
+        compliance_action_event(env, &action)?;
+    }
+    
+    for (account, action) in actions.iter() {
+        let event = compliance_action_event(env, &action)?;
+        match event {
+            FREEZE_EVENT => freeze(env, &account),
+            UNFREEZE_EVENT => unfreeze(env, &account),
+            DENYLIST_ADD_EVENT => denylist_add(env, &account)?,
+            _ => denylist_remove(env, &account)?,
+        }
+        
+        events::publish(env, (Symbol::new(env, event), &account), ());
+    }
+    Ok(())
+}
+
+/// Remove an account from the denylist, moving the last entry into its slot
+/// so the index stays dense
+pub fn denylist_remove(env: &Env, account: &Address) -> Result<(), StablecoinError> {
+    let position = storage::get_denylist_position(env, account)
+        .ok_or(StablecoinError::InvalidParameters)?;
+    let last = storage::get_denylist_count(env) - 1;
+    
+    if position != last {
+        let moved = storage::get_denylist_entry(env, last)
+            .ok_or(StablecoinError::InvalidParameters)?;
+        storage::set_denylist_entry(env, position, &moved);
+        storage::set_denylist_position(env, &moved, position);
+    }
+    
+    storage::remove_denylist_entry(env, last);
+    storage::remove_denylist_position(env, account);
+    storage::set_denylist_count(env, last);
+    Ok(())
+}
+
+/// Read up to `limit` denylisted accounts starting at index position `start`
+pub fn read_denylist(env: &Env, start: u32, limit: u32) -> Vec<Address> {
+    let count = storage::get_denylist_count(env);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+    
+    let mut accounts = Vec::new(env);
+    for position in start..end {
+        if let Some(account) = storage::get_denylist_entry(env, position) {
+            accounts.push_back(account);
+        }
+    }
+    accounts
+}
+
+/// ==================== ACCOUNT RECOVERY ====================
+
+/// Migrate a holder who lost their keys from `old` to `new`: the whole balance, its locks
+/// and the live allowances `old` granted move across, and `old` is denylisted. `new` must
+/// be free of compliance flags and hold nothing yet; a frozen `old` or one with a treasury
+/// vesting schedule cannot be migrated. Returns the balance moved.
+pub fn recover_account(env: &Env, old: &Address, new: &Address) -> Result<i128, StablecoinError> {
+    if old == new {
+        return Err(StablecoinError::SelfTransfer);
+    }
+    validate_address_comprehensive(env, new)?;
+    validate_role_grantee(env, new)?;
+    validate_allowlisted(env, new)?;
+    if storage::is_frozen(env, old) {
+        return Err(StablecoinError::AccountFrozen);
+    }
+    if storage::get_vesting(env, old).is_some() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    // No merging: the new address must be unused
+    if Base::balance(env, new) != 0
+        || !storage::get_locks(env, new).is_empty()
+        || !storage::get_spenders(env, new).is_empty()
+    {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    // Balance
+    let balance = Base::balance(env, old);
+    if balance > 0 {
+        Base::update(env, Some(old), Some(new), balance);
+        note_credit(env, new, balance);
+        note_debit(env, old, balance);
+    }
+    
+    // Recovered tokens are not a new receipt, so drop any hold the move placed on `new`
+    let held = storage::get_locks(env, new).iter().fold(0i128, |total, lock| total.saturating_add(lock.amount));
+    storage::set_total_locked(env, storage::get_total_locked(env).saturating_sub(held));
+    
+    // Locks keep their amounts and release ledgers, so the locked total is unchanged
+    let locks = storage::get_locks(env, old);
+    storage::set_locks(env, new, &locks);
+    storage::set_locks(env, old, &Vec::new(env));
+    
+    // Allowances granted by `old`; lapsed ones, and ones `new` could no longer grant
+    // (e.g. to a since-sanctioned spender), are dropped rather than carried over
+    let now = env.ledger().sequence();
+    for spender in storage::get_spenders(env, old).iter() {
+        let amount = Base::allowance(env, old, &spender);
+        let expiration = storage::get_allowance_expiration(env, old, &spender);
+        
+        track_spender(env, old, &spender, 0)?;
+        Base::set_allowance(env, old, &spender, 0, now);
+        count_allowance(env, old, &spender, 0);
+        storage::set_allowance_expiration(env, old, &spender, None);
+        
+        if let Some(expiration) = expiration {
+            if amount > 0 && expiration >= now && spender != *new {
+                // Validation runs before anything is written, so a refused allowance leaves no trace
+                let _ = write_unsigned_allowance(env, new, &spender, amount, expiration);
+            }
+        }
+    }
+    
+    if !is_locally_denylisted(env, old) {
+        denylist_add(env, old)?;
+    }
+    Ok(balance)
+}
+
+/// ==================== MINT LOG ====================
+
+/// Append a mint to the rolling mint log, evicting the oldest entry once the log is full
+pub fn record_mint(env: &Env, minter: &Address, to: &Address, amount: i128) {
+    let count = storage::get_mint_log_count(env);
+    let entry = MintLogEntry {
+        ledger: env.ledger().sequence(),
+        minter: minter.clone(),
+        to: to.clone(),
+        amount,
+    };
+    
+    storage::set_mint_log_slot(env, count % MINT_LOG_CAPACITY, &entry);
+    storage::set_mint_log_count(env, count.saturating_add(1));
+}
+
+/// Read up to `limit` mint log entries starting at sequence number `start`.
+/// Entries that have rolled out of the window are skipped.
+pub fn read_mint_log(env: &Env, start: u32, limit: u32) -> Vec<MintLogEntry> {
+    let count = storage::get_mint_log_count(env);
+    let oldest = count.saturating_sub(MINT_LOG_CAPACITY);
+    let first = start.max(oldest);
+    let end = first.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+    
+    let mut entries = Vec::new(env);
+    for index in first..end {
+        if let Some(entry) = storage::get_mint_log_slot(env, index % MINT_LOG_CAPACITY) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
+/// ==================== RECENT OPERATIONS ====================
+
+/// Append an operation summary to the recent operations ring, overwriting the oldest
+/// entry once RECENT_OPS_CAPACITY entries are held
+pub fn record_recent_op(env: &Env, op: &str, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+    let count = storage::get_recent_op_count(env);
+    let entry = OpSummary {
+        op: Symbol::new(env, op),
+        from: from.cloned(),
+        to: to.cloned(),
+        amount,
+        ledger: env.ledger().sequence(),
+    };
+    
+    storage::set_recent_op_slot(env, count % RECENT_OPS_CAPACITY, &entry);
+    storage::set_recent_op_count(env, count.saturating_add(1));
+}
+
+/// Read up to `n` of the most recent operations, newest first; `n` is capped at
+/// RECENT_OPS_CAPACITY
+pub fn recent_operations(env: &Env, n: u32) -> Vec<OpSummary> {
+    let count = storage::get_recent_op_count(env);
+    let take = n.min(RECENT_OPS_CAPACITY).min(count);
+    
+    let mut entries = Vec::new(env);
+    for index in (count - take..count).rev() {
+        if let Some(entry) = storage::get_recent_op_slot(env, index % RECENT_OPS_CAPACITY) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
+/// ==================== ALLOWANCE INDEX ====================
+
+/// Keep the owner's spender index in sync with a newly set allowance amount
+pub fn track_spender(env: &Env, owner: &Address, spender: &Address, amount: i128) -> Result<(), StablecoinError> {
+    let mut spenders = storage::get_spenders(env, owner);
+    let index = spenders.first_index_of(spender);
+    
+    match (index, amount > 0) {
+        (None, true) => {
+            if spenders.len() >= MAX_SPENDERS_PER_OWNER {
+                prune_spenders(env, owner, &mut spenders);
+            }
+            if spenders.len() >= MAX_SPENDERS_PER_OWNER {
+                return Err(StablecoinError::InvalidParameters);
+            }
+            if spenders.is_empty() {
+                index_allowance_owner(env, owner);
+            }
+            spenders.push_back(spender.clone());
+            storage::set_spenders(env, owner, &spenders);
+        }
+        (Some(index), false) => {
+            spenders.remove(index);
+            storage::set_spenders(env, owner, &spenders);
+            if spenders.is_empty() {
+                unindex_allowance_owner(env, owner);
+            }
+        }
+        _ => {}
+    }
+    
+    Ok(())
+}
+
+/// Drop spenders whose allowance has expired or been spent from an owner's index, clearing
+/// their counted amount and expiration record so the slots can be reused
+fn prune_spenders(env: &Env, owner: &Address, spenders: &mut Vec<Address>) {
+    let mut live = Vec::new(env);
+    for spender in spenders.iter() {
+        if Base::allowance(env, owner, &spender) > 0 {
+            live.push_back(spender);
+        } else {
+            count_allowance(env, owner, &spender, 0);
+            storage::set_allowance_expiration(env, owner, &spender, None);
+        }
+    }
+    
+    if live.len() < spenders.len() {
+        storage::set_spenders(env, owner, &live);
+        *spenders = live;
+    }
+}
+
+/// Rebuild the spender index and outstanding-allowance count for the given (owner, spender)
+/// pairs from the allowances actually stored, e.g. for allowances written before the index
+/// existed. Stored allowances cannot be enumerated, so callers supply the pairs, one page
+/// at a time. Idempotent; returns how many pairs hold a live allowance.
+pub fn reindex_allowances(env: &Env, entries: &Vec<(Address, Address)>) -> Result<u32, StablecoinError> {
+    validate_batch_size(env, entries.len())?;
+    
+    let mut live = 0;
+    for (owner, spender) in entries.iter() {
+        let amount = Base::allowance(env, &owner, &spender);
+        track_spender(env, &owner, &spender, amount)?;
+        count_allowance(env, &owner, &spender, amount);
+        if amount > 0 {
+            live += 1;
+        } else {
+            storage::set_allowance_expiration(env, &owner, &spender, None);
+        }
+    }
+    Ok(live)
+}
+
+/// Add an owner to the dense index of owners with indexed spenders
+fn index_allowance_owner(env: &Env, owner: &Address) {
+    if storage::get_allowance_owner_position(env, owner).is_some() {
+        return;
+    }
+    
+    let count = storage::get_allowance_owner_count(env);
+    storage::set_allowance_owner_entry(env, count, owner);
+    storage::set_allowance_owner_position(env, owner, count);
+    storage::set_allowance_owner_count(env, count + 1);
+}
+
+/// Remove an owner from the owner index, moving the last entry into its slot
+fn unindex_allowance_owner(env: &Env, owner: &Address) {
+    let Some(position) = storage::get_allowance_owner_position(env, owner) else {
+        return;
+    };
+    let last = storage::get_allowance_owner_count(env) - 1;
+    
+    if position != last {
+        if let Some(moved) = storage::get_allowance_owner_entry(env, last) {
+            storage::set_allowance_owner_entry(env, position, &moved);
+            storage::set_allowance_owner_position(env, &moved, position);
+        }
+    }
+    
+    storage::remove_allowance_owner_entry(env, last);
+    storage::remove_allowance_owner_position(env, owner);
+    storage::set_allowance_owner_count(env, last);
+}
+
+/// Zero live allowances, keeping a snapshot for `restore_allowances`. Runs under an
+/// emergency pause, so it never fails: at most MAX_ALLOWANCE_SNAPSHOT allowances are held
+/// (entries of an earlier snapshot included), at most that many owners are scanned, and
+/// any allowances past the cap stay live but unusable while the contract is paused.
+/// Returns how many allowances were zeroed.
+pub fn freeze_allowances(env: &Env) -> u32 {
+    let now = env.ledger().sequence();
+    let mut snapshot = storage::get_allowance_snapshot(env).unwrap_or_else(|| Vec::new(env));
+    let held = snapshot.len();
+    
+    let owners = storage::get_allowance_owner_count(env).min(MAX_ALLOWANCE_SNAPSHOT);
+    'owners: for position in 0..owners {
+        let Some(owner) = storage::get_allowance_owner_entry(env, position) else {
+            continue;
+        };
+        for spender in storage::get_spenders(env, &owner).iter() {
+            if snapshot.len() >= MAX_ALLOWANCE_SNAPSHOT {
+                break 'owners;
+            }
+            let amount = Base::allowance(env, &owner, &spender);
+            if amount <= 0 {
+                continue;
+            }
+            let expiration_ledger = storage::get_allowance_expiration(env, &owner, &spender).unwrap_or(now);
+            Base::set_allowance(env, &owner, &spender, 0, now);
+            count_allowance(env, &owner, &spender, 0);
+            snapshot.push_back(AllowanceSnapshotEntry { owner: owner.clone(), spender, amount, expiration_ledger });
+        }
+    }
+    
+    storage::set_allowance_snapshot(env, Some(&snapshot));
+    snapshot.len() - held
+}
+
+/// Restore the allowances zeroed by `freeze_allowances` and drop the snapshot. Allowances
+/// that expired in the meantime or whose spender has since been flagged stay zeroed.
+/// Returns how many allowances were restored.
+pub fn restore_allowances(env: &Env) -> Result<u32, StablecoinError> {
+    let snapshot = storage::get_allowance_snapshot(env).ok_or(StablecoinError::InvalidParameters)?;
+    
+    let now = env.ledger().sequence();
+    let mut restored = 0;
+    for entry in snapshot.iter() {
+        if entry.expiration_ledger < now || validate_spender_compliance(env, &entry.spender).is_err() {
+            continue;
+        }
+        track_spender(env, &entry.owner, &entry.spender, entry.amount)?;
+        Base::set_allowance(env, &entry.owner, &entry.spender, entry.amount, entry.expiration_ledger);
+        count_allowance(env, &entry.owner, &entry.spender, entry.amount);
+        restored += 1;
+    }
+    
+    storage::set_allowance_snapshot(env, None);
+    Ok(restored)
+}
+
+/// Record an allowance's expiration so expired and insufficient allowances can be told apart
+pub fn track_allowance_expiration(env: &Env, owner: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+    let expiration = if amount > 0 { Some(expiration_ledger) } else { None };
+    storage::set_allowance_expiration(env, owner, spender, expiration);
+}
+
+/// Write an allowance through the owner's authorization, keeping the spender and expiration
+/// indexes in sync. New or raised allowances to flagged spenders are rejected, as are
+/// self-approvals: an owner can always transfer directly, so they would only waste storage.
+pub fn write_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
+    prepare_allowance(env, owner, spender, amount, expiration_ledger)?;
+    Base::approve(env, owner, spender, amount, expiration_ledger);
+    count_allowance(env, owner, spender, amount);
+    Ok(())
+}
+
+/// Write an allowance for a managed account without the owner's authorization. The
+/// caller must already have been authorized as the admin or compliance role, and the
+/// owner must be registered as managed.
+pub fn write_managed_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
+    if !storage::is_managed_account(env, owner) {
+        return Err(StablecoinError::Unauthorized);
+    }
+    write_unsigned_allowance(env, owner, spender, amount, expiration_ledger)
+}
+
+/// Write an allowance without the owner's authorization, for callers that have already
+/// established their own authority over the owner's account
+fn write_unsigned_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
+    prepare_allowance(env, owner, spender, amount, expiration_ledger)?;
+    Base::set_allowance(env, owner, spender, amount, expiration_ledger);
+    count_allowance(env, owner, spender, amount);
+    Ok(())
+}
+
+/// Validate an allowance and update the spender and expiration indexes ahead of writing it
+fn prepare_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
+    if owner == spender {
+        return Err(StablecoinError::SelfTransfer);
+    }
+    
+    // Negative allowances are invalid; zero clears one where the policy allows it
+    if amount < 0 || (amount == 0 && !allows_zero_amount("approve")) {
+        return Err(StablecoinError::InvalidAmount);
+    }
+    
+    // Bounded approvals only, when a horizon is configured
+    validate_allowance_horizon(env, amount, expiration_ledger)?;
+    
+    // Sanctioned spenders cannot be granted new allowances (revoking is always allowed)
+    if amount > 0 {
+        validate_spender_compliance(env, spender)?;
+    }
+    
+    // Keep the spender index in sync before writing the allowance
+    track_spender(env, owner, spender, amount)?;
+    track_allowance_expiration(env, owner, spender, amount, expiration_ledger);
+    Ok(())
+}
+
+/// Verify that the token's total supply equals the tracked total minted less total burned
+pub fn reconcile_supply(env: &Env) -> Result<bool, StablecoinError> {
+    let tracked = storage::get_total_minted(env) - storage::get_total_burned(env);
+    if Base::total_supply(env) != tracked {
+        return Err(StablecoinError::SupplyMismatch);
+    }
+    Ok(true)
+}
+
+/// Replace the amount an allowance contributes to the outstanding-allowances total.
+/// Allowances count as written; one that lapses stays counted until rewritten or spent.
+pub fn count_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let counted = storage::get_counted_allowance(env, owner, spender);
+    storage::set_counted_allowance(env, owner, spender, amount);
+    storage::set_outstanding_allowances(env, storage::get_outstanding_allowances(env) - counted + amount);
+}
+
+/// Account for `amount` pulled through an allowance by `transfer_from` or `burn_from`.
+/// An allowance spent down to zero is dropped from the spender index and its
+/// expiration record removed, so enumeration stays clean and storage is reclaimed.
+pub fn note_allowance_spent(env: &Env, owner: &Address, spender: &Address, amount: i128) {
+    let counted = storage::get_counted_allowance(env, owner, spender);
+    let remaining = (counted - amount).max(0);
+    count_allowance(env, owner, spender, remaining);
+    
+    if remaining == 0 && Base::allowance(env, owner, spender) == 0 {
+        // Removing a spender never hits the index cap, so this cannot fail
+        let _ = track_spender(env, owner, spender, 0);
+        storage::set_allowance_expiration(env, owner, spender, None);
+    }
+}
+
+/// Token statistics, maintained incrementally as balances and allowances change
+pub fn token_stats(env: &Env) -> TokenStats {
+    TokenStats {
+        total_supply: Base::total_supply(env),
+        total_minted: storage::get_total_minted(env),
+        total_burned: storage::get_total_burned(env),
+        active_holders: storage::get_active_holders(env),
+        total_outstanding_allowances: storage::get_outstanding_allowances(env),
+    }
+}
+
+/// Validate that a spender's allowance covers `amount`, distinguishing expiry from insufficiency
+pub fn validate_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128) -> Result<(), StablecoinError> {
+    if Base::allowance(env, owner, spender) >= amount {
+        return Ok(());
+    }
+    
+    match storage::get_allowance_expiration(env, owner, spender) {
+        Some(expiration) if expiration < env.ledger().sequence() => {
+            if in_allowance_grace(env, owner, spender, amount) {
+                return Ok(());
+            }
+            Err(StablecoinError::AllowanceExpired)
+        }
+        _ => Err(StablecoinError::InsufficientAllowance),
+    }
+}
+
+/// Set how many ledgers past its expiration an allowance stays usable by `transfer_from`
+pub fn update_allowance_grace(env: &Env, ledgers: u32) -> Result<(), StablecoinError> {
+    if ledgers > MAX_ALLOWANCE_GRACE_LEDGERS {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_allowance_grace_ledgers(env, ledgers);
+    Ok(())
+}
+
+/// Update the default allowance lifetime; it must be non-zero and within the network's maximum TTL
+pub fn update_default_allowance_ttl(env: &Env, ledgers: u32) -> Result<(), StablecoinError> {
+    let max = storage::get_max_allowance_ttl(env);
+    if ledgers == 0 || ledgers > env.storage().max_ttl() || (max > 0 && ledgers > max) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_default_allowance_ttl(env, ledgers);
+    Ok(())
+}
+
+/// Update the allowance expiration horizon; 0 disables it. A non-zero horizon cannot be
+/// shorter than the default allowance lifetime, so `approve_for` keeps working.
+pub fn update_max_allowance_ttl(env: &Env, ledgers: u32) -> Result<(), StablecoinError> {
+    if ledgers > 0 && ledgers < storage::get_default_allowance_ttl(env) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_max_allowance_ttl(env, ledgers);
+    Ok(())
+}
+
+/// Validate that a new allowance does not expire beyond the configured horizon
+pub fn validate_allowance_horizon(env: &Env, amount: i128, expiration_ledger: u32) -> Result<(), StablecoinError> {
+    let max = storage::get_max_allowance_ttl(env);
+    if max > 0 && amount > 0 && expiration_ledger > env.ledger().sequence().saturating_add(max) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// The expiration ledger an approval made now should use: the current ledger plus the default TTL
+pub fn recommended_allowance_expiration(env: &Env) -> u32 {
+    env.ledger().sequence().saturating_add(storage::get_default_allowance_ttl(env))
+}
+
+/// Whether an expired allowance is still inside the grace window and covers `amount`.
+/// The amount honored is what remained of the allowance when it lapsed.
+pub fn in_allowance_grace(env: &Env, owner: &Address, spender: &Address, amount: i128) -> bool {
+    let grace = storage::get_allowance_grace_ledgers(env);
+    if grace == 0 {
+        return false;
+    }
+    
+    match storage::get_allowance_expiration(env, owner, spender) {
+        Some(expiration) => {
+            let now = env.ledger().sequence();
+            expiration < now
+                && now <= expiration.saturating_add(grace)
+                && storage::get_counted_allowance(env, owner, spender) >= amount
+        }
+        None => false,
+    }
+}
+
+/// Validate that `spender` may pull from `from` without an allowance: it must hold the
+/// spender role and `from` must have opted in to it
+pub fn validate_pull(env: &Env, spender: &Address, from: &Address) -> Result<(), StablecoinError> {
+    if access_control::has_role(env, spender, &Symbol::new(env, SPENDER_ROLE)).is_none()
+        || !storage::is_spender_opted_in(env, from, spender)
+    {
+        return Err(StablecoinError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Move tokens on the strength of an allowance inside its grace window. The lapsed
+/// allowance is debited through the counted remainder, which the spend helpers track.
+/// The caller must already have authenticated the spender.
+pub fn transfer_within_grace(env: &Env, from: &Address, to: &Address, amount: i128) {
+    Base::update(env, Some(from), Some(to), amount);
+}
+
+/// Validate that a spender has no active compliance flags (denylisted or frozen)
+pub fn validate_spender_compliance(env: &Env, spender: &Address) -> Result<(), StablecoinError> {
+    validate_not_denylisted(env, spender)?;
+    if storage::is_frozen(env, spender) {
+        return Err(StablecoinError::AccountFrozen);
+    }
+    Ok(())
+}
+
+/// Zero the allowances a flagged spender holds from each of `owners`, returning how many were revoked
+pub fn revoke_flagged_spender(env: &Env, spender: &Address, owners: &Vec<Address>) -> Result<u32, StablecoinError> {
+    if validate_spender_compliance(env, spender).is_ok() {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    
+    let mut revoked = 0;
+    for owner in owners.iter() {
+        if Base::allowance(env, &owner, spender) > 0 {
+            Base::set_allowance(env, &owner, spender, 0, env.ledger().sequence());
+            count_allowance(env, &owner, spender, 0);
+            track_spender(env, &owner, spender, 0)?;
+            track_allowance_expiration(env, &owner, spender, 0, 0);
+            revoked += 1;
+        }
+    }
+    Ok(revoked)
+}
+
+/// Validate that `spender` can move `amount` from `from` to `to` right now
+pub fn validate_spend(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), StablecoinError> {
+    // Pause state
+    if pausable::paused(env) {
+        return Err(StablecoinError::Paused);
+    }
+    ensure_method_not_paused(env, "transfer_from")?;
+    
+    // Compliance state of the spender
+    validate_spender_compliance(env, spender)?;
+    
+    // Addresses, amount, balance and compliance state of both parties
+    validate_transfer_comprehensive(env, from, to, amount)?;
+    
+    // Allowance sufficiency and expiry
+    validate_allowance(env, from, spender, amount)?;
+    
+    Ok(())
+}
+
+/// Get every live, non-zero allowance an owner has granted
+pub fn live_allowances(env: &Env, owner: &Address) -> Vec<(Address, i128)> {
+    let mut allowances = Vec::new(env);
+    
+    for spender in storage::get_spenders(env, owner).iter() {
+        let amount = Base::allowance(env, owner, &spender);
+        if amount > 0 {
+            allowances.push_back((spender, amount));
+        }
+    }
+    allowances
+}
+
+/// The most `spender` could pull from `from` right now: the live allowance capped by
+/// the owner's spendable balance, or zero if the spender itself is flagged
+pub fn max_transferable_from(env: &Env, spender: &Address, from: &Address) -> i128 {
+    if storage::is_frozen(env, spender) || is_denylisted_cached(env, spender) {
+        return 0;
+    }
+    Base::allowance(env, from, spender).min(spendable_balance(env, from))
+}
+
+/// Sum every live allowance an owner has granted, saturating at `i128::MAX`
+pub fn total_approved(env: &Env, owner: &Address) -> i128 {
+    live_allowances(env, owner)
+        .iter()
+        .fold(0i128, |total, (_, amount)| total.saturating_add(amount))
+}
+
+/// Validate a token metadata URI: non-empty and within MAX_TOKEN_URI_LENGTH bytes
+pub fn validate_token_uri(uri: &String) -> Result<(), StablecoinError> {
+    if uri.is_empty() || uri.len() > MAX_TOKEN_URI_LENGTH {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Validate a redemption memo: non-empty and within MAX_MEMO_LENGTH bytes
+pub fn validate_memo(memo: &String) -> Result<(), StablecoinError> {
+    if memo.is_empty() || memo.len() > MAX_MEMO_LENGTH {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Split a non-negative amount held in base units with `decimals` into whole major units
+/// and the remaining base units, e.g. 123456 with 2 gives (1234, 56)
+pub fn split_major_units(amount: i128, decimals: u32) -> (i128, u64) {
+    let scale = 10i128.pow(decimals);
+    (amount / scale, (amount % scale) as u64)
+}
+
+/// Format an amount held in base units with `decimals` as a decimal string floored
+/// to `display_decimals` places, e.g. 123456789 with 7 and 2 gives "12.34"
+pub fn format_display_amount(env: &Env, amount: i128, decimals: u32, display_decimals: u32) -> String {
+    let display_decimals = display_decimals.min(decimals) as usize;
+    let (whole, fraction) = split_major_units(amount.saturating_abs(), decimals);
+    let mut value = whole.unsigned_abs() * 10u128.pow(display_decimals as u32)
+        + (fraction / 10u64.pow(decimals - display_decimals as u32)) as u128;
+    
+    // Write digits right to left, padding so there is always a whole part
+    let mut buf = [0u8; 64];
+    let mut pos = buf.len();
+    let mut written = 0;
+    while value > 0 || written <= display_decimals {
+        if written == display_decimals && display_decimals > 0 {
+            pos -= 1;
+            buf[pos] = b'.';
+        }
+        pos -= 1;
+        buf[pos] = b'0' + (value % 10) as u8;
+        value /= 10;
+        written += 1;
+    }
+    if amount < 0 && buf[pos..].iter().any(|c| (b'1'..=b'9').contains(c)) {
+        pos -= 1;
+        buf[pos] = b'-';
+    }
+    
+    String::from_bytes(env, &buf[pos..])
+}
+
+/// Display an amount using the token decimals, the configured denomination floor
+/// and the currency code, so every memo-carrying event renders amounts alike
+pub fn display_amount(env: &Env, amount: i128) -> DisplayAmount {
+    let decimals = Base::decimals(env);
+    let display_decimals = storage::get_display_decimals(env).unwrap_or(decimals);
+    DisplayAmount {
+        text: format_display_amount(env, amount, decimals, display_decimals),
+        currency: storage::get_currency(env),
+    }
+}
+
+/// Update the decimals shown in display amounts; they cannot exceed the token decimals
+pub fn update_display_decimals(env: &Env, decimals: u32) -> Result<(), StablecoinError> {
+    if decimals > Base::decimals(env) {
+        return Err(StablecoinError::InvalidParameters);
+    }
+    storage::set_display_decimals(env, decimals);
+    Ok(())
+}
+
+/// Advance the operation sequence for a state-changing call, returning the new value.
+/// A call that later fails is rolled back along with its increment, so the sequence
+/// only ever counts operations that took effect. Call it before anything that publishes
+/// an event, so every event of the call carries the call's own sequence number.
+pub fn record_operation(env: &Env) -> u64 {
+    let op_seq = storage::get_op_seq(env) + 1;
+    storage::set_op_seq(env, op_seq);
+    storage::set_last_activity_ledger(env, env.ledger().sequence());
+    maybe_extend_ttl(env, op_seq);
+    op_seq
+}
+
+/// Ledgers elapsed since initialization and since the last state-changing operation
+pub fn contract_age(env: &Env) -> (u32, u32) {
+    let now = env.ledger().sequence();
+    (
+        now.saturating_sub(storage::get_init_ledger(env)),
+        now.saturating_sub(storage::get_last_activity_ledger(env)),
+    )
+}